@@ -1,6 +1,19 @@
+/// Benchmarks sampling, arithmetic, serialization and MSMs of `$Group`.
+///
+/// Passing `short_weierstrass` after `$Group`, which must then be a
+/// `short_weierstrass::Projective`, also benchmarks [`msm_batch_affine`]
+/// alongside the default MSM.
+///
+/// [`msm_batch_affine`]: ark_ec::scalar_mul::variable_base::msm_batch_affine
 #[macro_export]
 macro_rules! ec_bench {
     ($curve_name:expr, $Group:ident) => {
+        $crate::ec_bench!(@group $curve_name, $Group, []);
+    };
+    ($curve_name:expr, $Group:ident, short_weierstrass) => {
+        $crate::ec_bench!(@group $curve_name, $Group, [msm_batch_affine]);
+    };
+    (@group $curve_name:expr, $Group:ident, [$($msm_batch_affine:ident)?]) => {
         $crate::paste! {
             mod [<$Group:lower>] {
                 use ark_ec::PrimeGroup;
@@ -240,6 +253,32 @@ macro_rules! ec_bench {
                         b.iter(|| <$Group as VariableBaseMSM>::msm_bigint(&v, &s))
                     });
 
+                    $(
+                        c.bench_function(&format!("MSM-random-batch-affine for {name}"), |b| {
+                            let s: Vec<_> = (0..SAMPLES)
+                                .map(|_| Scalar::rand(&mut rng).into_bigint())
+                                .collect();
+                            b.iter(|| ark_ec::scalar_mul::variable_base::$msm_batch_affine(&v, &s))
+                        });
+                    )?
+
+                    {
+                        const VECTORS: usize = 8;
+                        const SIZE: usize = SAMPLES >> 4;
+                        let s: Vec<Vec<_>> = (0..VECTORS)
+                            .map(|_| (0..SIZE).map(|_| Scalar::rand(&mut rng)).collect())
+                            .collect();
+                        c.bench_function(&format!("MSM-batch-{VECTORS}x{SIZE} for {name}"), |b| {
+                            b.iter(|| <$Group as VariableBaseMSM>::msm_batch(&v[..SIZE], &s))
+                        });
+                        c.bench_function(&format!("MSM-separate-{VECTORS}x{SIZE} for {name}"), |b| {
+                            b.iter(|| {
+                                s.iter()
+                                    .map(|s| <$Group as VariableBaseMSM>::msm_unchecked(&v[..SIZE], s))
+                                    .collect::<Vec<_>>()
+                            })
+                        });
+                    }
 
                     c.bench_function(&format!("MSM-bool for {name}"), |b| {
                         let s: Vec<_> = (0..SAMPLES)
@@ -377,3 +416,58 @@ macro_rules! ec_bench {
         }
     };
 }
+
+/// Benchmarks [`msm_glv`] against [`VariableBaseMSM::msm_bigint`] for a range
+/// of sizes. `$Group` must be a `short_weierstrass::Projective` whose config
+/// implements [`GLVConfig`].
+///
+/// [`msm_glv`]: ark_ec::scalar_mul::variable_base::msm_glv
+/// [`VariableBaseMSM::msm_bigint`]: ark_ec::scalar_mul::variable_base::VariableBaseMSM::msm_bigint
+/// [`GLVConfig`]: ark_ec::scalar_mul::glv::GLVConfig
+#[macro_export]
+macro_rules! glv_msm_bench {
+    ($curve_name:expr, $Group:ident) => {
+        $crate::paste! {
+            mod [<$Group:lower _glv_msm>] {
+                use super::*;
+
+                fn msm(c: &mut $crate::criterion::Criterion) {
+                    use ark_ec::{
+                        scalar_mul::variable_base::{msm_glv, VariableBaseMSM},
+                        CurveGroup, PrimeGroup,
+                    };
+                    use ark_ff::PrimeField;
+                    use ark_std::UniformRand;
+                    use $crate::criterion::BenchmarkId;
+
+                    type Scalar = <$Group as PrimeGroup>::ScalarField;
+                    const LOG_SIZES: core::ops::RangeInclusive<usize> = 10..=18;
+
+                    let name = format!("{}::{}", $curve_name, stringify!($Group));
+                    let mut c = c.benchmark_group(format!("GLV MSM for {name}"));
+                    c.sample_size(10);
+                    let mut rng = ark_std::test_rng();
+
+                    let max_size = 1 << LOG_SIZES.end();
+                    let v: Vec<_> = (0..max_size)
+                        .map(|_| <$Group>::rand(&mut rng))
+                        .collect();
+                    let v = <$Group>::normalize_batch(&v);
+                    let s: Vec<_> = (0..max_size).map(|_| Scalar::rand(&mut rng)).collect();
+                    let s_bigint: Vec<_> = s.iter().map(|s| s.into_bigint()).collect();
+
+                    for size in LOG_SIZES.map(|log_size| 1 << log_size) {
+                        c.bench_with_input(BenchmarkId::new("msm_bigint", size), &size, |b, &size| {
+                            b.iter(|| <$Group as VariableBaseMSM>::msm_bigint(&v[..size], &s_bigint[..size]))
+                        });
+                        c.bench_with_input(BenchmarkId::new("msm_glv", size), &size, |b, &size| {
+                            b.iter(|| msm_glv::<<$Group as CurveGroup>::Config>(&v[..size], &s[..size]))
+                        });
+                    }
+                }
+
+                $crate::criterion_group!(benches, msm);
+            }
+        }
+    };
+}
@@ -19,8 +19,8 @@ macro_rules! bench {
         G2BaseField = $FqExt:ident,
         TargetField = $FqTarget:ident,
     ) => {
-        $crate::ec_bench!($name, $G1);
-        $crate::ec_bench!($name, $G2);
+        $crate::ec_bench!($name, $G1, short_weierstrass);
+        $crate::ec_bench!($name, $G2, short_weierstrass);
         $crate::f_bench!(prime, $name, $Fr);
         $crate::f_bench!(prime, $name, $Fq);
         $crate::f_bench!(extension, $name, $FqExt);
@@ -153,6 +153,11 @@ impl SWCurveConfig for Config {
             G1_SERIALIZED_SIZE * 2
         }
     }
+
+    #[inline]
+    fn msm(bases: &[G1Affine], scalars: &[Self::ScalarField]) -> Result<G1Projective, usize> {
+        GLVConfig::glv_msm(bases, scalars)
+    }
 }
 
 impl GLVConfig for Config {
@@ -170,6 +175,10 @@ impl GLVConfig for Config {
         (true, BigInt!("228988810152649578064853576960394133503")),
     ];
 
+    /// `msm_glv` was measured to be faster than the generic MSM up to this
+    /// size.
+    const GLV_MSM_MAX_SIZE: usize = 1 << 12;
+
     fn endomorphism(p: &G1Projective) -> G1Projective {
         let mut res = (*p).clone();
         res.x *= Self::ENDO_COEFFS[0];
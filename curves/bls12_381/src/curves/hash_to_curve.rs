@@ -0,0 +1,44 @@
+//! The hash-to-curve suites for BLS12-381 defined in
+//! [RFC 9380, Section 8.8](https://www.rfc-editor.org/rfc/rfc9380.html#section-8.8).
+//!
+//! The `_RO_` suites implement `hash_to_curve`, and the `_NU_` suites
+//! implement the cheaper `encode_to_curve`. The domain separation tag passed
+//! to [`HashToCurve::new`](ark_ec::hashing::HashToCurve::new) should end with
+//! the corresponding suite ID.
+
+use ark_ec::hashing::{
+    curve_maps::wb::WBMap,
+    map_to_curve_hasher::{MapToCurveBasedEncoder, MapToCurveBasedHasher},
+};
+use ark_ff::field_hashers::DefaultFieldHasher;
+use sha2::Sha256;
+
+use crate::{g1, g2, G1Projective, G2Projective};
+
+/// The suite ID of [`G1XmdSha256SswuRo`].
+pub const G1_XMD_SHA256_SSWU_RO_ID: &str = "BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+/// The suite ID of [`G1XmdSha256SswuNu`].
+pub const G1_XMD_SHA256_SSWU_NU_ID: &str = "BLS12381G1_XMD:SHA-256_SSWU_NU_";
+
+/// The suite ID of [`G2XmdSha256SswuRo`].
+pub const G2_XMD_SHA256_SSWU_RO_ID: &str = "BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+/// The suite ID of [`G2XmdSha256SswuNu`].
+pub const G2_XMD_SHA256_SSWU_NU_ID: &str = "BLS12381G2_XMD:SHA-256_SSWU_NU_";
+
+/// The `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite.
+pub type G1XmdSha256SswuRo =
+    MapToCurveBasedHasher<G1Projective, DefaultFieldHasher<Sha256, 128>, WBMap<g1::Config>>;
+
+/// The `BLS12381G1_XMD:SHA-256_SSWU_NU_` suite.
+pub type G1XmdSha256SswuNu =
+    MapToCurveBasedEncoder<G1Projective, DefaultFieldHasher<Sha256, 128>, WBMap<g1::Config>>;
+
+/// The `BLS12381G2_XMD:SHA-256_SSWU_RO_` suite.
+pub type G2XmdSha256SswuRo =
+    MapToCurveBasedHasher<G2Projective, DefaultFieldHasher<Sha256, 128>, WBMap<g2::Config>>;
+
+/// The `BLS12381G2_XMD:SHA-256_SSWU_NU_` suite.
+pub type G2XmdSha256SswuNu =
+    MapToCurveBasedEncoder<G2Projective, DefaultFieldHasher<Sha256, 128>, WBMap<g2::Config>>;
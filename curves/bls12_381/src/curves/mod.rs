@@ -4,6 +4,8 @@ use crate::{Fq, Fq12Config, Fq2Config, Fq6Config};
 
 pub mod g1;
 pub mod g2;
+#[cfg(feature = "hash_to_curve")]
+pub mod hash_to_curve;
 pub(crate) mod util;
 
 mod g1_swu_iso;
@@ -14,6 +14,30 @@ test_group!(pairing_output; ark_ec::pairing::PairingOutput<Bls12_381>; msm);
 test_pairing!(pairing; crate::Bls12_381);
 test_h2c!(g1_h2c; "./src/curves/tests"; "BLS12381G1"; crate::g1::Config; crate::Fq; crate::Fq; 1);
 test_h2c!(g2_hc2; "./src/curves/tests"; "BLS12381G2"; crate::g2::Config; crate::Fq2; crate::Fq; 2);
+#[cfg(feature = "hash_to_curve")]
+test_h2c!(g1_h2c_ro; "./src/curves/tests"; suite = crate::hash_to_curve::G1_XMD_SHA256_SSWU_RO_ID;
+    hasher = crate::hash_to_curve::G1XmdSha256SswuRo;
+    field_hasher = ark_ff::field_hashers::DefaultFieldHasher<sha2::Sha256, 128>;
+    field = crate::Fq; count = 2;
+    point = |x, y| crate::G1Affine::new(x, y));
+#[cfg(feature = "hash_to_curve")]
+test_h2c!(g1_h2c_nu; "./src/curves/tests"; suite = crate::hash_to_curve::G1_XMD_SHA256_SSWU_NU_ID;
+    hasher = crate::hash_to_curve::G1XmdSha256SswuNu;
+    field_hasher = ark_ff::field_hashers::DefaultFieldHasher<sha2::Sha256, 128>;
+    field = crate::Fq; count = 1;
+    point = |x, y| crate::G1Affine::new(x, y));
+#[cfg(feature = "hash_to_curve")]
+test_h2c!(g2_h2c_ro; "./src/curves/tests"; suite = crate::hash_to_curve::G2_XMD_SHA256_SSWU_RO_ID;
+    hasher = crate::hash_to_curve::G2XmdSha256SswuRo;
+    field_hasher = ark_ff::field_hashers::DefaultFieldHasher<sha2::Sha256, 128>;
+    field = crate::Fq2; count = 2;
+    point = |x, y| crate::G2Affine::new(x, y));
+#[cfg(feature = "hash_to_curve")]
+test_h2c!(g2_h2c_nu; "./src/curves/tests"; suite = crate::hash_to_curve::G2_XMD_SHA256_SSWU_NU_ID;
+    hasher = crate::hash_to_curve::G2XmdSha256SswuNu;
+    field_hasher = ark_ff::field_hashers::DefaultFieldHasher<sha2::Sha256, 128>;
+    field = crate::Fq2; count = 1;
+    point = |x, y| crate::G2Affine::new(x, y));
 
 #[test]
 fn test_g1_endomorphism_beta() {
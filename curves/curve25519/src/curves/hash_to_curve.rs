@@ -0,0 +1,40 @@
+//! The hash-to-curve suites for curve25519 defined in
+//! [RFC 9380, Section 8.5](https://www.rfc-editor.org/rfc/rfc9380.html#section-8.5).
+//!
+//! The `_RO_` suite implements `hash_to_curve`, and the `_NU_` suite
+//! implements the cheaper `encode_to_curve`. The domain separation tag passed
+//! to [`HashToCurve::new`](ark_ec::hashing::HashToCurve::new) should end with
+//! the corresponding suite ID.
+//!
+//! The outputs are points of the twisted Edwards model of this crate. The
+//! Montgomery coordinates `(s, t)` used by the RFC are `s = (1 + y) / (1 - y)`
+//! and `t = s / x`.
+
+use ark_ec::hashing::{
+    curve_maps::elligator2::Elligator2Map,
+    map_to_curve_hasher::{MapToCurveBasedEncoder, MapToCurveBasedHasher},
+};
+use ark_ff::field_hashers::DefaultFieldHasher;
+use sha2::Sha512;
+
+use crate::{Curve25519Config, EdwardsProjective};
+
+/// The suite ID of [`XmdSha512Ell2Ro`].
+pub const XMD_SHA512_ELL2_RO_ID: &str = "curve25519_XMD:SHA-512_ELL2_RO_";
+
+/// The suite ID of [`XmdSha512Ell2Nu`].
+pub const XMD_SHA512_ELL2_NU_ID: &str = "curve25519_XMD:SHA-512_ELL2_NU_";
+
+/// The `curve25519_XMD:SHA-512_ELL2_RO_` suite.
+pub type XmdSha512Ell2Ro = MapToCurveBasedHasher<
+    EdwardsProjective,
+    DefaultFieldHasher<Sha512, 128>,
+    Elligator2Map<Curve25519Config>,
+>;
+
+/// The `curve25519_XMD:SHA-512_ELL2_NU_` suite.
+pub type XmdSha512Ell2Nu = MapToCurveBasedEncoder<
+    EdwardsProjective,
+    DefaultFieldHasher<Sha512, 128>,
+    Elligator2Map<Curve25519Config>,
+>;
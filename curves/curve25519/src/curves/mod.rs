@@ -1,10 +1,14 @@
 use crate::{Fq, Fr};
 use ark_ec::{
+    hashing::curve_maps::elligator2::Elligator2Config,
     models::CurveConfig,
     twisted_edwards::{Affine, MontCurveConfig, MontgomeryAffine, Projective, TECurveConfig},
 };
 use ark_ff::MontFp;
 
+#[cfg(feature = "hash_to_curve")]
+pub mod hash_to_curve;
+
 #[cfg(test)]
 mod tests;
 
@@ -59,6 +63,17 @@ impl MontCurveConfig for Curve25519Config {
     type TECurveConfig = Curve25519Config;
 }
 
+impl Elligator2Config for Curve25519Config {
+    /// Z = 2, as in the curve25519 suites of RFC 9380.
+    const Z: Fq = MontFp!("2");
+
+    /// This must be equal to 1/(MontCurveConfig::COEFF_B)^2;
+    const ONE_OVER_COEFF_B_SQUARE: Fq = MontFp!("1");
+
+    /// This must be equal to MontCurveConfig::COEFF_A/MontCurveConfig::COEFF_B;
+    const COEFF_A_OVER_COEFF_B: Fq = MontFp!("486662");
+}
+
 /// GENERATOR_X =
 /// 19682211724289367445990778417013818358151178695569199618971391691394964886553
 pub const GENERATOR_X: Fq =
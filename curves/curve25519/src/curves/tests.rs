@@ -2,3 +2,18 @@ use crate::*;
 use ark_algebra_test_templates::*;
 
 test_group!(te; EdwardsProjective; te);
+
+// The vectors are in Montgomery coordinates `(s, t)`, which correspond to the
+// twisted Edwards point `(s / t, (s - 1) / (s + 1))`.
+#[cfg(feature = "hash_to_curve")]
+test_h2c!(h2c_ro; "./src/curves/tests"; suite = crate::hash_to_curve::XMD_SHA512_ELL2_RO_ID;
+    hasher = crate::hash_to_curve::XmdSha512Ell2Ro;
+    field_hasher = ark_ff::field_hashers::DefaultFieldHasher<sha2::Sha512, 128>;
+    field = crate::Fq; count = 2;
+    point = |s, t| crate::EdwardsAffine::new(s / t, (s - crate::Fq::from(1u8)) / (s + crate::Fq::from(1u8))));
+#[cfg(feature = "hash_to_curve")]
+test_h2c!(h2c_nu; "./src/curves/tests"; suite = crate::hash_to_curve::XMD_SHA512_ELL2_NU_ID;
+    hasher = crate::hash_to_curve::XmdSha512Ell2Nu;
+    field_hasher = ark_ff::field_hashers::DefaultFieldHasher<sha2::Sha512, 128>;
+    field = crate::Fq; count = 1;
+    point = |s, t| crate::EdwardsAffine::new(s / t, (s - crate::Fq::from(1u8)) / (s + crate::Fq::from(1u8))));
@@ -0,0 +1,61 @@
+//! The hash-to-curve suites for edwards25519 defined in
+//! [RFC 9380, Section 8.5](https://www.rfc-editor.org/rfc/rfc9380.html#section-8.5).
+//!
+//! The `_RO_` suite implements `hash_to_curve`, and the `_NU_` suite
+//! implements the cheaper `encode_to_curve`. The domain separation tag passed
+//! to [`HashToCurve::new`](ark_ec::hashing::HashToCurve::new) should end with
+//! the corresponding suite ID.
+
+use ark_curve25519::{Curve25519Config, EdwardsProjective as Curve25519Projective};
+use ark_ec::hashing::{
+    curve_maps::elligator2::Elligator2Map,
+    map_to_curve_hasher::{MapToCurve, MapToCurveBasedEncoder, MapToCurveBasedHasher},
+    HashToCurveError,
+};
+use ark_ff::{field_hashers::DefaultFieldHasher, MontFp};
+use sha2::Sha512;
+
+use crate::{EdwardsAffine, EdwardsProjective, Fq};
+
+/// The suite ID of [`XmdSha512Ell2Ro`].
+pub const XMD_SHA512_ELL2_RO_ID: &str = "edwards25519_XMD:SHA-512_ELL2_RO_";
+
+/// The suite ID of [`XmdSha512Ell2Nu`].
+pub const XMD_SHA512_ELL2_NU_ID: &str = "edwards25519_XMD:SHA-512_ELL2_NU_";
+
+/// `sqrt(-486664)`, chosen such that `sgn0` of it is 0.
+const SQRT_MINUS_486664: Fq =
+    MontFp!("6853475219497561581579357271197624642482790079785650197046958215289687604742");
+
+/// The Elligator 2 map to edwards25519 of RFC 9380, Appendix D.
+///
+/// This is the Elligator 2 map to curve25519 composed with the rational map
+/// `(x, y) = (sqrt(-486664) * s / t, (s - 1) / (s + 1))` to edwards25519.
+pub struct Edwards25519Map;
+
+impl MapToCurve<EdwardsProjective> for Edwards25519Map {
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        Elligator2Map::<Curve25519Config>::check_parameters()
+    }
+
+    fn map_to_curve(element: Fq) -> Result<EdwardsAffine, HashToCurveError> {
+        // The twisted Edwards model of curve25519 already is
+        // `(v, w) = (s / t, (s - 1) / (s + 1))`, so only `v` needs rescaling.
+        let point =
+            <Elligator2Map<Curve25519Config> as MapToCurve<Curve25519Projective>>::map_to_curve(
+                element,
+            )?;
+        Ok(EdwardsAffine::new_unchecked(
+            SQRT_MINUS_486664 * point.x,
+            point.y,
+        ))
+    }
+}
+
+/// The `edwards25519_XMD:SHA-512_ELL2_RO_` suite.
+pub type XmdSha512Ell2Ro =
+    MapToCurveBasedHasher<EdwardsProjective, DefaultFieldHasher<Sha512, 128>, Edwards25519Map>;
+
+/// The `edwards25519_XMD:SHA-512_ELL2_NU_` suite.
+pub type XmdSha512Ell2Nu =
+    MapToCurveBasedEncoder<EdwardsProjective, DefaultFieldHasher<Sha512, 128>, Edwards25519Map>;
@@ -5,6 +5,9 @@ use ark_ec::{
 };
 use ark_ff::MontFp;
 
+#[cfg(feature = "hash_to_curve")]
+pub mod hash_to_curve;
+
 #[cfg(test)]
 mod tests;
 
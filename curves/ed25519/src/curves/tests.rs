@@ -2,3 +2,16 @@ use crate::*;
 use ark_algebra_test_templates::*;
 
 test_group!(te; EdwardsProjective; te);
+
+#[cfg(feature = "hash_to_curve")]
+test_h2c!(h2c_ro; "./src/curves/tests"; suite = crate::hash_to_curve::XMD_SHA512_ELL2_RO_ID;
+    hasher = crate::hash_to_curve::XmdSha512Ell2Ro;
+    field_hasher = ark_ff::field_hashers::DefaultFieldHasher<sha2::Sha512, 128>;
+    field = crate::Fq; count = 2;
+    point = |x, y| crate::EdwardsAffine::new(x, y));
+#[cfg(feature = "hash_to_curve")]
+test_h2c!(h2c_nu; "./src/curves/tests"; suite = crate::hash_to_curve::XMD_SHA512_ELL2_NU_ID;
+    hasher = crate::hash_to_curve::XmdSha512Ell2Nu;
+    field_hasher = ark_ff::field_hashers::DefaultFieldHasher<sha2::Sha512, 128>;
+    field = crate::Fq; count = 1;
+    point = |x, y| crate::EdwardsAffine::new(x, y));
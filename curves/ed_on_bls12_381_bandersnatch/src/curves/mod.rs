@@ -1,10 +1,12 @@
 use ark_ec::{
     hashing::curve_maps::elligator2::Elligator2Config,
     models::CurveConfig,
+    scalar_mul::glv::{GLVConfig, TEGLVConfig},
     short_weierstrass::{self, SWCurveConfig},
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
+    CurveGroup,
 };
-use ark_ff::{AdditiveGroup, MontFp};
+use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
 use crate::{Fq, Fr};
 
@@ -145,6 +147,103 @@ impl SWCurveConfig for BandersnatchConfig {
     type ZeroFlag = ();
 }
 
+// Bandersnatch has an endomorphism of degree 2 with eigenvalue `LAMBDA`,
+// where `LAMBDA^2 = -2 mod r`; see <https://eprint.iacr.org/2021/1152>.
+// Since the cofactor is 4, it only acts as `LAMBDA` on the prime-order
+// subgroup, so GLV is not used for the default scalar multiplication, which
+// must also handle points outside of that subgroup.
+//
+// The lattice basis for the scalar decomposition is shared by both forms. It
+// is the LLL reduction of `[[r, 0], [-LAMBDA, 1]]`, with its rows ordered so
+// that its determinant is `r`.
+const SCALAR_DECOMP_COEFFS: [(bool, <Fr as PrimeField>::BigInt); 4] = [
+    (false, BigInt!("113482231691339203864511368254957623327")),
+    (false, BigInt!("10741319382058138887739339959866629956")),
+    (true, BigInt!("21482638764116277775478679919733259912")),
+    (false, BigInt!("113482231691339203864511368254957623327")),
+];
+
+impl TEGLVConfig for BandersnatchConfig {
+    /// ENDO_COEFFS = [b, c], where the endomorphism is
+    /// `(x, y, z) -> (f(y) h(y), g(y) x y, h(y) x y)`, with
+    /// `f(y) = c (z^2 - y^2)`, `g(y) = b (y^2 + b z^2)` and `h(y) = y^2 - b z^2`.
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[
+        MontFp!("37446463827641770816307242315180085052603635617490163568005256780843403514036"),
+        MontFp!("49199877423542878313146170939139662862850515542392585932876811575731455068989"),
+    ];
+
+    const LAMBDA: Self::ScalarField =
+        MontFp!("8913659658109529928382530854484400854125314752504019737736543920008458395397");
+
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] =
+        SCALAR_DECOMP_COEFFS;
+
+    fn endomorphism(p: &EdwardsProjective) -> EdwardsProjective {
+        // The points with `x = 0` are the identity, which is fixed, and the
+        // point of order two `(0, -1)`, which is in the kernel.
+        if p.x.is_zero() {
+            return EdwardsProjective::zero();
+        }
+        let [b, c] = [
+            <Self as TEGLVConfig>::ENDO_COEFFS[0],
+            <Self as TEGLVConfig>::ENDO_COEFFS[1],
+        ];
+        let y2 = p.y.square();
+        let bz2 = b * p.z.square();
+        let f = c * (p.z.square() - y2);
+        let g = b * (y2 + bz2);
+        let h = y2 - bz2;
+        let xy = p.x * p.y;
+        EdwardsProjective::new_unchecked(f * h, g * xy, f * g, h * xy)
+    }
+
+    fn endomorphism_affine(p: &EdwardsAffine) -> EdwardsAffine {
+        <Self as TEGLVConfig>::endomorphism(&(*p).into()).into_affine()
+    }
+}
+
+impl GLVConfig for BandersnatchConfig {
+    /// ENDO_COEFFS = [x0, t, u^2, u^3], where the endomorphism is the
+    /// 2-isogeny with kernel `(x0, 0)`, given by Vélu's formulae
+    /// `(x, y) -> (x + t / (x - x0), y (1 - t / (x - x0)^2))`,
+    /// composed with the isomorphism `(x, y) -> (u^2 x, u^3 y)`.
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[
+        MontFp!("42460977304182762931716743824405123254375045638571669698531889431804823178961"),
+        MontFp!("35484827650731063748396669747216844996598387089274032563585525486049249153249"),
+        MontFp!("26217937587563095239723870254092982918845276250263818911301829349969290592256"),
+        MontFp!("8089994378958280414021351509578931277711957629993758335181991576135134307306"),
+    ];
+
+    const LAMBDA: Self::ScalarField =
+        MontFp!("8913659658109529928382530854484400854125314752504019737736543920008458395397");
+
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] =
+        SCALAR_DECOMP_COEFFS;
+
+    fn endomorphism(p: &SWProjective) -> SWProjective {
+        // In Jacobian coordinates, with `d = x - x0 z^2`, the image is
+        // `(u^2 (x d + t z^4) d, u^3 y (d^2 - t z^4) d, z d)`.
+        // The identity and the kernel point `(x0, 0)` are both mapped to a
+        // point with `z = 0`, i.e. to the identity.
+        let [x0, t, u2, u3] = [
+            <Self as GLVConfig>::ENDO_COEFFS[0],
+            <Self as GLVConfig>::ENDO_COEFFS[1],
+            <Self as GLVConfig>::ENDO_COEFFS[2],
+            <Self as GLVConfig>::ENDO_COEFFS[3],
+        ];
+        let z2 = p.z.square();
+        let d = p.x - x0 * z2;
+        let tz4 = t * z2.square();
+        let x = u2 * (p.x * d + tz4) * d;
+        let y = u3 * p.y * (d.square() - tz4) * d;
+        SWProjective::new_unchecked(x, y, p.z * d)
+    }
+
+    fn endomorphism_affine(p: &SWAffine) -> SWAffine {
+        <Self as GLVConfig>::endomorphism(&(*p).into()).into_affine()
+    }
+}
+
 // Elligator hash to curve Bandersnatch
 // sage: find_z_ell2(GF(52435875175126190479447740508185965837690552500527637822603658699938581184513))
 // 5
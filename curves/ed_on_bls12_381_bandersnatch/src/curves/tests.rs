@@ -2,3 +2,5 @@ use crate::*;
 use ark_algebra_test_templates::*;
 
 test_group!(te; EdwardsProjective; te);
+test_group!(te_glv; EdwardsProjective; te_glv);
+test_group!(sw_glv; SWProjective; glv);
@@ -4,9 +4,11 @@
 use crate::{fq::Fq, fr::Fr};
 use ark_ec::{
     models::CurveConfig,
-    short_weierstrass::{self as sw, SWCurveConfig},
+    scalar_mul::glv::GLVConfig,
+    short_weierstrass::{self as sw, CompleteSWCurveConfig, SWCurveConfig},
+    CurveCycle,
 };
-use ark_ff::{AdditiveGroup, Field, MontFp, Zero};
+use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
 #[cfg(test)]
 mod tests;
@@ -27,6 +29,8 @@ impl CurveConfig for GrumpkinConfig {
 
 pub type Affine = sw::Affine<GrumpkinConfig>;
 pub type Projective = sw::Projective<GrumpkinConfig>;
+pub type CompleteAffine = sw::complete::Affine<GrumpkinConfig>;
+pub type CompleteProjective = sw::complete::Projective<GrumpkinConfig>;
 
 impl SWCurveConfig for GrumpkinConfig {
     /// COEFF_A = 0
@@ -48,6 +52,54 @@ impl SWCurveConfig for GrumpkinConfig {
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn mul_projective(p: &Projective, scalar: &[u64]) -> Projective {
+        let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+        GLVConfig::glv_mul_projective(*p, s)
+    }
+
+    #[inline]
+    fn mul_affine(base: &Affine, scalar: &[u64]) -> Projective {
+        let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+        GLVConfig::glv_mul_projective((*base).into(), s)
+    }
+}
+
+impl CompleteSWCurveConfig for GrumpkinConfig {}
+
+impl GLVConfig for GrumpkinConfig {
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
+        "4407920970296243842393367215006156084916469457145843978461"
+    )];
+
+    const LAMBDA: Self::ScalarField =
+        MontFp!("2203960485148121921418603742825762020974279258880205651966");
+
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (true, BigInt!("9931322734385697762")),
+        (false, BigInt!("147946756881789319000765030803803410729")),
+        (true, BigInt!("147946756881789319010696353538189108491")),
+        (true, BigInt!("9931322734385697762")),
+    ];
+
+    fn endomorphism(p: &Projective) -> Projective {
+        // Endomorphism of the points on the curve.
+        // endomorphism_p(x,y) = (BETA * x, y)
+        // where BETA is a non-trivial cubic root of unity in Fq.
+        let mut res = (*p).clone();
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+
+    fn endomorphism_affine(p: &Affine) -> Affine {
+        // Endomorphism of the points on the curve.
+        // endomorphism_p(x,y) = (BETA * x, y)
+        // where BETA is a non-trivial cubic root of unity in Fq.
+        let mut res = (*p).clone();
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
 }
 
 /// G_GENERATOR_X = 1
@@ -56,3 +108,13 @@ pub const G_GENERATOR_X: Fq = MontFp!("1");
 /// G_GENERATOR_Y = sqrt(-16)
 pub const G_GENERATOR_Y: Fq =
     MontFp!("17631683881184975370165255887551781615748388533673675138860");
+
+/// The cycle formed by BN254 and Grumpkin: the base field of each curve is the
+/// scalar field of the other.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Bn254GrumpkinCycle;
+
+impl CurveCycle for Bn254GrumpkinCycle {
+    type E1 = ark_bn254::G1Projective;
+    type E2 = Projective;
+}
@@ -1,6 +1,7 @@
 use ark_ec::{
     models::mnt6::{MNT6Config, MNT6},
     short_weierstrass::SWCurveConfig,
+    CurveCycle, PairingFriendlyCycle,
 };
 use ark_ff::{biginteger::BigInteger320, AdditiveGroup, BigInt, Field, Fp3};
 
@@ -47,3 +48,19 @@ impl MNT6Config for Config {
     type G1Config = self::g1::Config;
     type G2Config = self::g2::Config;
 }
+
+/// The cycle formed by the G1 groups of MNT4-298 and MNT6-298: the base field
+/// of each curve is the scalar field of the other, and both curves are
+/// pairing-friendly.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct MNT298Cycle;
+
+impl CurveCycle for MNT298Cycle {
+    type E1 = ark_mnt4_298::G1Projective;
+    type E2 = G1Projective;
+}
+
+impl PairingFriendlyCycle for MNT298Cycle {
+    type Engine1 = ark_mnt4_298::MNT4_298;
+    type Engine2 = MNT6_298;
+}
@@ -1,6 +1,9 @@
-use ark_ec::models::{
-    mnt6::{MNT6Config, MNT6},
-    short_weierstrass::SWCurveConfig,
+use ark_ec::{
+    models::{
+        mnt6::{MNT6Config, MNT6},
+        short_weierstrass::SWCurveConfig,
+    },
+    CurveCycle, PairingFriendlyCycle,
 };
 use ark_ff::{biginteger::BigInteger768, AdditiveGroup, BigInt, Field, Fp3};
 
@@ -60,3 +63,19 @@ impl MNT6Config for Config {
     type G1Config = self::g1::Config;
     type G2Config = self::g2::Config;
 }
+
+/// The cycle formed by the G1 groups of MNT4-753 and MNT6-753: the base field
+/// of each curve is the scalar field of the other, and both curves are
+/// pairing-friendly.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct MNT753Cycle;
+
+impl CurveCycle for MNT753Cycle {
+    type E1 = ark_mnt4_753::G1Projective;
+    type E2 = G1Projective;
+}
+
+impl PairingFriendlyCycle for MNT753Cycle {
+    type Engine1 = ark_mnt4_753::MNT4_753;
+    type Engine2 = MNT6_753;
+}
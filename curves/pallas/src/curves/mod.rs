@@ -1,7 +1,7 @@
 use ark_ec::{
     models::CurveConfig,
     scalar_mul::glv::GLVConfig,
-    short_weierstrass::{self as sw, SWCurveConfig},
+    short_weierstrass::{self as sw, CompleteSWCurveConfig, SWCurveConfig},
 };
 use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
@@ -26,6 +26,8 @@ impl CurveConfig for PallasConfig {
 
 pub type Affine = sw::Affine<PallasConfig>;
 pub type Projective = sw::Projective<PallasConfig>;
+pub type CompleteAffine = sw::complete::Affine<PallasConfig>;
+pub type CompleteProjective = sw::complete::Projective<PallasConfig>;
 
 impl SWCurveConfig for PallasConfig {
     /// COEFF_A = 0
@@ -50,6 +52,8 @@ impl SWCurveConfig for PallasConfig {
     }
 }
 
+impl CompleteSWCurveConfig for PallasConfig {}
+
 impl GLVConfig for PallasConfig {
     const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
         "20444556541222657078399132219657928148671392403212669005631716460534733845831"
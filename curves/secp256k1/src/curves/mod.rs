@@ -1,8 +1,9 @@
 use ark_ec::{
     models::CurveConfig,
-    short_weierstrass::{self as sw, SWCurveConfig},
+    scalar_mul::glv::GLVConfig,
+    short_weierstrass::{self as sw, CompleteSWCurveConfig, SWCurveConfig},
 };
-use ark_ff::{AdditiveGroup, Field, MontFp, Zero};
+use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
 use crate::{fq::Fq, fr::Fr};
 
@@ -11,6 +12,8 @@ mod tests;
 
 pub type Affine = sw::Affine<Config>;
 pub type Projective = sw::Projective<Config>;
+pub type CompleteAffine = sw::complete::Affine<Config>;
+pub type CompleteProjective = sw::complete::Projective<Config>;
 
 #[derive(Copy, Clone, Default, PartialEq, Eq)]
 pub struct Config;
@@ -47,6 +50,59 @@ impl SWCurveConfig for Config {
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn mul_projective(p: &Projective, scalar: &[u64]) -> Projective {
+        let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+        GLVConfig::glv_mul_projective(*p, s)
+    }
+
+    #[inline]
+    fn mul_affine(base: &Affine, scalar: &[u64]) -> Projective {
+        let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+        GLVConfig::glv_mul_projective((*base).into(), s)
+    }
+
+    #[inline]
+    fn msm(bases: &[Affine], scalars: &[Self::ScalarField]) -> Result<Projective, usize> {
+        GLVConfig::glv_msm(bases, scalars)
+    }
+}
+
+impl CompleteSWCurveConfig for Config {}
+
+impl GLVConfig for Config {
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
+        "55594575648329892869085402983802832744385952214688224221778511981742606582254"
+    )];
+
+    const LAMBDA: Self::ScalarField =
+        MontFp!("37718080363155996902926221483475020450927657555482586988616620542887997980018");
+
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (true, BigInt!("64502973549206556628585045361533709077")),
+        (false, BigInt!("303414439467246543595250775667605759171")),
+        (true, BigInt!("367917413016453100223835821029139468248")),
+        (true, BigInt!("64502973549206556628585045361533709077")),
+    ];
+
+    fn endomorphism(p: &Projective) -> Projective {
+        // Endomorphism of the points on the curve.
+        // endomorphism_p(x,y) = (BETA * x, y)
+        // where BETA is a non-trivial cubic root of unity in Fq.
+        let mut res = (*p).clone();
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+
+    fn endomorphism_affine(p: &Affine) -> Affine {
+        // Endomorphism of the points on the curve.
+        // endomorphism_p(x,y) = (BETA * x, y)
+        // where BETA is a non-trivial cubic root of unity in Fq.
+        let mut res = (*p).clone();
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
 }
 
 /// G_GENERATOR_X =
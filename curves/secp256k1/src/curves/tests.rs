@@ -1,4 +1,6 @@
-use crate::Projective;
+use crate::{CompleteProjective, Projective};
 use ark_algebra_test_templates::*;
 
 test_group!(g1; Projective; sw);
+test_group!(g1_complete; CompleteProjective; complete);
+test_group!(g1_glv; Projective; glv);
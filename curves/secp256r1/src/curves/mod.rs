@@ -1,6 +1,6 @@
 use ark_ec::{
     models::CurveConfig,
-    short_weierstrass::{self as sw, SWCurveConfig},
+    short_weierstrass::{self as sw, CompleteSWCurveConfig, SWCurveConfig},
 };
 use ark_ff::{Field, MontFp};
 
@@ -11,6 +11,8 @@ mod tests;
 
 pub type Affine = sw::Affine<Config>;
 pub type Projective = sw::Projective<Config>;
+pub type CompleteAffine = sw::complete::Affine<Config>;
+pub type CompleteProjective = sw::complete::Projective<Config>;
 
 #[derive(Copy, Clone, Default, PartialEq, Eq)]
 pub struct Config;
@@ -45,6 +47,8 @@ impl SWCurveConfig for Config {
     type ZeroFlag = ();
 }
 
+impl CompleteSWCurveConfig for Config {}
+
 /// G_GENERATOR_X =
 /// 0x6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296
 pub const G_GENERATOR_X: Fq =
@@ -1,6 +1,6 @@
 use ark_ec::{
     models::CurveConfig,
-    short_weierstrass::{self as sw, SWCurveConfig},
+    short_weierstrass::{self as sw, CompleteSWCurveConfig, SWCurveConfig},
 };
 use ark_ff::{Field, MontFp};
 
@@ -11,6 +11,8 @@ mod tests;
 
 pub type Affine = sw::Affine<Config>;
 pub type Projective = sw::Projective<Config>;
+pub type CompleteAffine = sw::complete::Affine<Config>;
+pub type CompleteProjective = sw::complete::Projective<Config>;
 
 #[derive(Copy, Clone, Default, PartialEq, Eq)]
 pub struct Config;
@@ -45,6 +47,8 @@ impl SWCurveConfig for Config {
     const GENERATOR: Affine = Affine::new_unchecked(G_GENERATOR_X, G_GENERATOR_Y);
 }
 
+impl CompleteSWCurveConfig for Config {}
+
 /// G_GENERATOR_X =
 /// 26247035095799689268623156744566981891852923491109213387815615900925518854738050089022388053975719786650872476732087
 pub const G_GENERATOR_X: Fq =
@@ -1,8 +1,10 @@
 use ark_ec::{
     models::CurveConfig,
+    scalar_mul::glv::GLVConfig,
     short_weierstrass::{self as sw, SWCurveConfig},
+    CurveCycle,
 };
-use ark_ff::{AdditiveGroup, Field, MontFp, Zero};
+use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
 use crate::{fq::Fq, fr::Fr};
 
@@ -46,6 +48,52 @@ impl SWCurveConfig for Config {
     fn mul_by_a(_: Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn mul_projective(p: &Projective, scalar: &[u64]) -> Projective {
+        let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+        GLVConfig::glv_mul_projective(*p, s)
+    }
+
+    #[inline]
+    fn mul_affine(base: &Affine, scalar: &[u64]) -> Projective {
+        let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+        GLVConfig::glv_mul_projective((*base).into(), s)
+    }
+}
+
+impl GLVConfig for Config {
+    const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
+        "37718080363155996902926221483475020450927657555482586988616620542887997980018"
+    )];
+
+    const LAMBDA: Self::ScalarField =
+        MontFp!("55594575648329892869085402983802832744385952214688224221778511981742606582254");
+
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (true, BigInt!("64502973549206556628585045361533709078")),
+        (false, BigInt!("303414439467246543595250775667605759171")),
+        (true, BigInt!("367917413016453100223835821029139468249")),
+        (true, BigInt!("64502973549206556628585045361533709078")),
+    ];
+
+    fn endomorphism(p: &Projective) -> Projective {
+        // Endomorphism of the points on the curve.
+        // endomorphism_p(x,y) = (BETA * x, y)
+        // where BETA is a non-trivial cubic root of unity in Fq.
+        let mut res = (*p).clone();
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+
+    fn endomorphism_affine(p: &Affine) -> Affine {
+        // Endomorphism of the points on the curve.
+        // endomorphism_p(x,y) = (BETA * x, y)
+        // where BETA is a non-trivial cubic root of unity in Fq.
+        let mut res = (*p).clone();
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
 }
 
 /// G_GENERATOR_X =
@@ -57,3 +105,13 @@ pub const G_GENERATOR_X: Fq =
 /// 28941648020349172432234515805717979317553499307621291159490218670604692907903
 pub const G_GENERATOR_Y: Fq =
     MontFp!("28941648020349172432234515805717979317553499307621291159490218670604692907903");
+
+/// The cycle formed by secp256k1 and secq256k1: the base field of each curve is
+/// the scalar field of the other.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct Secp256k1Secq256k1Cycle;
+
+impl CurveCycle for Secp256k1Secq256k1Cycle {
+    type E1 = ark_secp256k1::Projective;
+    type E2 = Projective;
+}
@@ -2,3 +2,5 @@ use crate::Projective;
 use ark_algebra_test_templates::*;
 
 test_group!(g1; Projective; sw);
+test_group!(g1_glv; Projective; glv);
+test_cycle!(cycle; crate::Secp256k1Secq256k1Cycle);
@@ -2,7 +2,8 @@ use crate::{fq::Fq, fr::Fr};
 use ark_ec::{
     models::CurveConfig,
     scalar_mul::glv::GLVConfig,
-    short_weierstrass::{self as sw, SWCurveConfig},
+    short_weierstrass::{self as sw, CompleteSWCurveConfig, SWCurveConfig},
+    CurveCycle,
 };
 use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
@@ -25,6 +26,8 @@ impl CurveConfig for VestaConfig {
 
 pub type Affine = sw::Affine<VestaConfig>;
 pub type Projective = sw::Projective<VestaConfig>;
+pub type CompleteAffine = sw::complete::Affine<VestaConfig>;
+pub type CompleteProjective = sw::complete::Projective<VestaConfig>;
 
 impl SWCurveConfig for VestaConfig {
     /// COEFF_A = 0
@@ -48,6 +51,8 @@ impl SWCurveConfig for VestaConfig {
     }
 }
 
+impl CompleteSWCurveConfig for VestaConfig {}
+
 impl GLVConfig for VestaConfig {
     const ENDO_COEFFS: &'static [Self::BaseField] = &[MontFp!(
         "26005156700822196841419187675678338661165322343552424574062261873906994770353"
@@ -89,3 +94,13 @@ pub const G_GENERATOR_X: Fq = MontFp!("-1");
 /// G_GENERATOR_Y = 2
 /// Encoded in Montgomery form, so the value here is 2R mod p.
 pub const G_GENERATOR_Y: Fq = MontFp!("2");
+
+/// The cycle formed by Pallas and Vesta: the base field of each curve is the
+/// scalar field of the other.
+#[derive(Copy, Clone, Default, PartialEq, Eq)]
+pub struct PallasVestaCycle;
+
+impl CurveCycle for PallasVestaCycle {
+    type E1 = ark_pallas::Projective;
+    type E2 = Projective;
+}
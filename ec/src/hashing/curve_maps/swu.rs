@@ -164,13 +164,14 @@ mod test {
         hashing::{map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve},
         CurveConfig,
     };
-    use ark_ff::field_hashers::DefaultFieldHasher;
+    use ark_ff::field_hashers::{DefaultFieldHasher, XofFieldHasher};
     use ark_std::vec::*;
 
     use super::*;
     use ark_ff::{fields::Fp64, MontBackend, MontFp};
     use hashbrown::HashMap;
     use sha2::Sha256;
+    use sha3::Shake128;
 
     #[derive(ark_ff::MontConfig)]
     #[modulus = "127"]
@@ -262,6 +263,27 @@ mod test {
         );
     }
 
+    /// Same as above, but hashing to the field with an extendable-output
+    /// function.
+    #[test]
+    fn hash_arbitrary_string_to_curve_swu_xof() {
+        let test_swu_to_curve_hasher = MapToCurveBasedHasher::<
+            Projective<TestSWUMapToCurveConfig>,
+            XofFieldHasher<Shake128, 128>,
+            SWUMap<TestSWUMapToCurveConfig>,
+        >::new(&[1])
+        .unwrap();
+
+        let hash_result = test_swu_to_curve_hasher
+            .hash(b"if you stick a Babel fish in your ear you can instantly understand anything said to you in any form of language.")
+            .expect("fail to hash the string to curve");
+
+        assert!(
+            hash_result.is_on_curve(),
+            "hash results into a point off the curve"
+        );
+    }
+
     /// Use a simple SWU compatible curve and map the whole field to it. We observe
     /// the map behaviour. Specifically, the map should be non-constant, all
     /// elements should be mapped to curve successfully. everything can be mapped
@@ -72,3 +72,57 @@ where
         Ok(rand_subgroup_elem)
     }
 }
+
+/// A helper struct used to construct elements on an elliptic curve from
+/// arbitrary messages, using the nonuniform encoding `encode_to_curve` of
+/// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html#section-3).
+///
+/// Unlike [`MapToCurveBasedHasher`], the message is hashed to a single field
+/// element, which is mapped to the curve once. This is cheaper, but the
+/// output distribution is not indistinguishable from uniform, so this should
+/// only be used where a specification calls for an `_NU_` suite.
+pub struct MapToCurveBasedEncoder<T, H2F, M2C>
+where
+    T: CurveGroup,
+    H2F: HashToField<T::BaseField>,
+    M2C: MapToCurve<T>,
+{
+    field_hasher: H2F,
+    _phantom: PhantomData<(T, M2C)>,
+}
+
+impl<T, H2F, M2C> HashToCurve<T> for MapToCurveBasedEncoder<T, H2F, M2C>
+where
+    T: CurveGroup,
+    H2F: HashToField<T::BaseField>,
+    M2C: MapToCurve<T>,
+{
+    fn new(domain: &[u8]) -> Result<Self, HashToCurveError> {
+        #[cfg(test)]
+        M2C::check_parameters()?;
+        Ok(Self {
+            field_hasher: H2F::new(domain),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Produce an encoding of the message, using the hash to field and map to
+    /// curve traits, as in the IETF hash to curve's specification of the
+    /// nonuniform encoding (encode_to_curve).
+    /// See <https://www.rfc-editor.org/rfc/rfc9380.html#section-3>
+    fn hash(&self, msg: &[u8]) -> Result<T::Affine, HashToCurveError> {
+        // IETF spec of encode_to_curve, from hash_to_field and map_to_curve
+        // sub-components
+        // 1. u = hash_to_field(msg, 1)
+        // 2. Q = map_to_curve(u[0])
+        // 3. P = clear_cofactor(Q)
+        // 4. return P
+
+        let [rand_field_elem] = self.field_hasher.hash_to_field::<1>(msg);
+
+        let rand_curve_elem = M2C::map_to_curve(rand_field_elem)?;
+        let rand_subgroup_elem = rand_curve_elem.clear_cofactor();
+
+        Ok(rand_subgroup_elem)
+    }
+}
@@ -23,6 +23,8 @@ use ark_std::{
     vec::*,
 };
 pub use scalar_mul::{variable_base::VariableBaseMSM, ScalarMul};
+#[cfg(feature = "subtle")]
+use subtle::{ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 use zeroize::Zeroize;
 
 pub use ark_ff::AdditiveGroup;
@@ -108,6 +110,25 @@ pub trait CurveGroup:
     }
 }
 
+/// A [`CurveGroup`] whose elements support constant-time equality, selection
+/// and negation, together with constant-time scalar multiplication.
+#[cfg(feature = "subtle")]
+pub trait CtCurveGroup:
+    CurveGroup + ConstantTimeEq + ConditionallySelectable + ConditionallyNegatable
+{
+    /// Performs scalar multiplication of this element, such that the
+    /// sequence of group operations and table lookups does not depend on the
+    /// value of `scalar`.
+    ///
+    /// Unlike [`PrimeGroup::mul_bigint`], this processes the full bit width
+    /// of the scalar field, so it is slower and should only be used for
+    /// secret scalars.
+    #[must_use]
+    fn mul_ct(&self, scalar: &Self::ScalarField) -> Self {
+        scalar_mul::fixed_window::mul_ct(self, scalar.into_bigint())
+    }
+}
+
 /// The canonical representation of an elliptic curve group element.
 /// This should represent the affine coordinates of the point corresponding
 /// to this group element.
@@ -223,6 +244,19 @@ pub trait AffineRepr:
     }
 }
 
+/// An [`AffineRepr`] whose elements support constant-time equality, selection
+/// and negation, and can be sampled from bytes in constant time.
+#[cfg(feature = "subtle")]
+pub trait CtAffine:
+    AffineRepr + ConstantTimeEq + ConditionallySelectable + ConditionallyNegatable
+{
+    /// Same as [`AffineRepr::from_random_bytes`], but recovering the point
+    /// from its coordinate in time that does not depend on `bytes`.
+    /// Whether the bytes encode a valid point is returned as part of the
+    /// [`subtle::CtOption`].
+    fn ct_from_random_bytes(bytes: &[u8]) -> subtle::CtOption<Self>;
+}
+
 /// Wrapper trait representing a cycle of elliptic curves (E1, E2) such that
 /// the base field of E1 is the scalar field of E2, and the scalar field of E1
 /// is the base field of E2.
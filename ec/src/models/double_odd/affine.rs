@@ -13,12 +13,18 @@ use ark_std::{
     vec::Vec,
 };
 
+#[cfg(feature = "subtle")]
+use ark_ff::CtField;
 use ark_ff::{fields::Field, AdditiveGroup, One, PrimeField, ToConstraintField, UniformRand};
 
 use educe::Educe;
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption};
 use zeroize::Zeroize;
 
 use super::{DOCurveConfig, Projective};
+#[cfg(feature = "subtle")]
+use crate::CtAffine;
 use crate::{AffineRepr, CurveGroup};
 
 /// Affine coordinates for a point on an elliptic curve in double-odd
@@ -122,6 +128,26 @@ impl<P: DOCurveConfig> Affine<P> {
         }
     }
 
+    /// Constant-time version of [`Affine::get_es_from_u_unchecked`].
+    #[cfg(feature = "subtle")]
+    pub fn ct_get_es_from_u_unchecked(u: P::BaseField) -> (P::BaseField, P::BaseField, Choice)
+    where
+        P::BaseField: CtField,
+    {
+        let e = (P::get_c() * u.square().square() - (P::COEFF_A * u.square()).double()
+            + P::BaseField::ONE)
+            .ct_sqrt();
+        let is_some = e.is_some();
+        let e = e.unwrap_or(P::BaseField::ZERO);
+        let neg_e = -e;
+        let e_is_larger = e.ct_gt(&neg_e);
+        (
+            P::BaseField::conditional_select(&e, &neg_e, e_is_larger),
+            P::BaseField::conditional_select(&neg_e, &e, e_is_larger),
+            is_some,
+        )
+    }
+
     /// Checks if `self` is a valid point on the curve,
     /// using the curve equation `e**2 = (a-4*b)*u**4 - 2a*u**2 + 1`
     pub fn is_on_curve(&self) -> bool {
@@ -216,6 +242,59 @@ impl<P: DOCurveConfig> Neg for Affine<P> {
     }
 }
 
+impl<P: DOCurveConfig> Neg for &Affine<P> {
+    type Output = Affine<P>;
+
+    #[inline]
+    fn neg(self) -> Affine<P> {
+        -*self
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: DOCurveConfig> CtAffine for Affine<P>
+where
+    P::BaseField: CtField,
+{
+    fn ct_from_random_bytes(bytes: &[u8]) -> CtOption<Self> {
+        let parsed = P::BaseField::from_random_bytes(bytes);
+        let is_parsed = Choice::from(u8::from(parsed.is_some()));
+        let u = parsed.unwrap_or(P::BaseField::ZERO);
+
+        let (_, larger, is_on_curve) = Self::ct_get_es_from_u_unchecked(u);
+        CtOption::new(Self::new_unchecked(larger, u), is_parsed & is_on_curve)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: DOCurveConfig> ConstantTimeEq for Affine<P>
+where
+    P::BaseField: CtField,
+{
+    /// Same as `PartialEq`: `(e, u)` and `(-e, -u)` represent the same
+    /// group element.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let self_is_zero = self.u.ct_eq(&P::BaseField::ZERO);
+        let other_is_zero = other.u.ct_eq(&P::BaseField::ZERO);
+        let coords_eq = (self.e.ct_eq(&other.e) & self.u.ct_eq(&other.u))
+            | (self.e.ct_eq(&-other.e) & self.u.ct_eq(&-other.u));
+        (self_is_zero & other_is_zero) | (!self_is_zero & !other_is_zero & coords_eq)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: DOCurveConfig> ConditionallySelectable for Affine<P>
+where
+    P::BaseField: CtField,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new_unchecked(
+            P::BaseField::conditional_select(&a.e, &b.e, choice),
+            P::BaseField::conditional_select(&a.u, &b.u, choice),
+        )
+    }
+}
+
 impl<P: DOCurveConfig, T: Borrow<Self>> Add<T> for Affine<P> {
     type Output = Projective<P>;
     /// Using Algorithm 3 from <https://doubleodd.group/doubleodd-jq.pdf>,
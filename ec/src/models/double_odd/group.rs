@@ -16,14 +16,20 @@ use ark_std::{
 };
 use educe::Educe;
 
+#[cfg(feature = "subtle")]
+use ark_ff::CtField;
 use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
 
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::Zeroize;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 use super::{Affine, DOCurveConfig};
+#[cfg(feature = "subtle")]
+use crate::CtCurveGroup;
 use crate::{
     scalar_mul::{variable_base::VariableBaseMSM, ScalarMul},
     AffineRepr, CurveGroup, PrimeGroup,
@@ -254,6 +260,55 @@ impl<P: DOCurveConfig> Neg for Projective<P> {
     }
 }
 
+impl<P: DOCurveConfig> Neg for &Projective<P> {
+    type Output = Projective<P>;
+
+    #[inline]
+    fn neg(self) -> Projective<P> {
+        -*self
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: DOCurveConfig> CtCurveGroup for Projective<P>
+where
+    P::BaseField: CtField,
+{
+    #[inline]
+    fn mul_ct(&self, scalar: &P::ScalarField) -> Self {
+        P::mul_projective_ct(self, scalar.into_bigint().as_ref())
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: DOCurveConfig> ConstantTimeEq for Projective<P>
+where
+    P::BaseField: CtField,
+{
+    /// Same as `PartialEq`, without branching on the coordinates.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let self_is_zero = self.u.ct_eq(&P::BaseField::ZERO);
+        let other_is_zero = other.u.ct_eq(&P::BaseField::ZERO);
+        let coords_eq = (self.e * other.u).ct_eq(&(other.e * self.u));
+        (self_is_zero & other_is_zero) | (!self_is_zero & !other_is_zero & coords_eq)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: DOCurveConfig> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: CtField,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new_unchecked(
+            P::BaseField::conditional_select(&a.e, &b.e, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+            P::BaseField::conditional_select(&a.u, &b.u, choice),
+            P::BaseField::conditional_select(&a.t, &b.t, choice),
+        )
+    }
+}
+
 impl<P: DOCurveConfig, T: Borrow<Affine<P>>> AddAssign<T> for Projective<P> {
     /// Using Algorithm 3 from <https://doubleodd.group/doubleodd-jq.pdf>,
     /// simplified because the second point is affine
@@ -3,6 +3,8 @@ use ark_serialize::{
 };
 use ark_std::io::{Read, Write};
 
+#[cfg(feature = "subtle")]
+use ark_ff::CtField;
 use ark_ff::{AdditiveGroup, Field, Zero};
 
 mod affine;
@@ -52,6 +54,17 @@ pub trait DOCurveConfig: super::CurveConfig {
         res
     }
 
+    /// Default implementation of constant-time group multiplication for
+    /// projective coordinates, using the fixed-window method of
+    /// [`crate::scalar_mul::fixed_window::mul_ct`].
+    #[cfg(feature = "subtle")]
+    fn mul_projective_ct(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self>
+    where
+        Self::BaseField: CtField,
+    {
+        crate::scalar_mul::fixed_window::mul_ct(base, scalar)
+    }
+
     fn msm(
         bases: &[Affine<Self>],
         scalars: &[Self::ScalarField],
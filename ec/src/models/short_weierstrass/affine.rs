@@ -16,11 +16,19 @@ use ark_std::{
 
 use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
 
+#[cfg(feature = "subtle")]
+use ark_ff::CtField;
 use educe::Educe;
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption};
 use zeroize::Zeroize;
 
+#[cfg(feature = "subtle")]
+use super::CtZeroFlag;
 use super::{bucket::Bucket, Projective, SWCurveConfig, SWFlags, ZeroFlag};
 use crate::AffineRepr;
+#[cfg(feature = "subtle")]
+use crate::CtAffine;
 
 /// Affine coordinates for a point on an elliptic curve in short Weierstrass
 /// form, over the base field `P::BaseField`.
@@ -142,6 +150,28 @@ impl<P: SWCurveConfig> Affine<P> {
         }
     }
 
+    /// Constant-time version of [`Affine::get_ys_from_x_unchecked`].
+    #[cfg(feature = "subtle")]
+    pub fn ct_get_ys_from_x_unchecked(x: P::BaseField) -> (P::BaseField, P::BaseField, Choice)
+    where
+        P::BaseField: CtField,
+    {
+        let mut x3_plus_ax_plus_b = P::add_b(x.square() * x);
+        if !P::COEFF_A.is_zero() {
+            x3_plus_ax_plus_b += P::mul_by_a(x)
+        };
+        let y = x3_plus_ax_plus_b.ct_sqrt();
+        let is_some = y.is_some();
+        let y = y.unwrap_or(P::BaseField::ZERO);
+        let neg_y = -y;
+        let y_is_larger = y.ct_gt(&neg_y);
+        (
+            P::BaseField::conditional_select(&y, &neg_y, y_is_larger),
+            P::BaseField::conditional_select(&neg_y, &y, y_is_larger),
+            is_some,
+        )
+    }
+
     /// Checks if `self` is a valid point on the curve.
     pub fn is_on_curve(&self) -> bool {
         if self.is_zero() {
@@ -305,6 +335,68 @@ impl<P: SWCurveConfig> Neg for Affine<P> {
     }
 }
 
+impl<P: SWCurveConfig> Neg for &Affine<P> {
+    type Output = Affine<P>;
+
+    #[inline]
+    fn neg(self) -> Affine<P> {
+        -*self
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: SWCurveConfig> CtAffine for Affine<P>
+where
+    P::BaseField: CtField,
+    P::ZeroFlag: CtZeroFlag<P>,
+{
+    fn ct_from_random_bytes(bytes: &[u8]) -> CtOption<Self> {
+        let parsed = P::BaseField::from_random_bytes_with_flags::<SWFlags>(bytes);
+        let is_parsed = Choice::from(u8::from(parsed.is_some()));
+        let (x, flags) = parsed.unwrap_or((P::BaseField::ZERO, SWFlags::PointAtInfinity));
+        let is_infinity = Choice::from(u8::from(flags.is_infinity()));
+        let greatest = Choice::from(u8::from(flags.is_positive().unwrap_or(false)));
+
+        let (smaller, larger, is_on_curve) = Self::ct_get_ys_from_x_unchecked(x);
+        let y = P::BaseField::conditional_select(&smaller, &larger, greatest);
+        let point =
+            Self::conditional_select(&Self::new_unchecked(x, y), &Self::identity(), is_infinity);
+        // As in `from_random_bytes`, the infinity flag is only accepted
+        // together with a zero x-coordinate.
+        let is_valid = (is_infinity & x.ct_eq(&P::BaseField::ZERO)) | (!is_infinity & is_on_curve);
+        CtOption::new(point, is_parsed & is_valid)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: SWCurveConfig> ConstantTimeEq for Affine<P>
+where
+    P::BaseField: CtField,
+    P::ZeroFlag: CtZeroFlag<P>,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let self_is_zero = P::ZeroFlag::ct_is_zero(self);
+        let other_is_zero = P::ZeroFlag::ct_is_zero(other);
+        let coords_eq = self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y);
+        (self_is_zero & other_is_zero) | (!self_is_zero & !other_is_zero & coords_eq)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: SWCurveConfig> ConditionallySelectable for Affine<P>
+where
+    P::BaseField: CtField,
+    P::ZeroFlag: CtZeroFlag<P>,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: P::BaseField::conditional_select(&a.x, &b.x, choice),
+            y: P::BaseField::conditional_select(&a.y, &b.y, choice),
+            infinity: P::ZeroFlag::conditional_select(&a.infinity, &b.infinity, choice),
+        }
+    }
+}
+
 impl<P: SWCurveConfig, T: Borrow<Self>> Add<T> for Affine<P> {
     type Output = Projective<P>;
     fn add(self, other: T) -> Projective<P> {
@@ -0,0 +1,345 @@
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use ark_std::{
+    borrow::Borrow,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    io::{Read, Write},
+    ops::{Add, Mul, Neg, Sub},
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+    vec::*,
+    Zero,
+};
+
+use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
+
+#[cfg(feature = "subtle")]
+use ark_ff::CtField;
+use educe::Educe;
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
+
+use super::{CompleteSWCurveConfig, Projective};
+use crate::{short_weierstrass::Affine as SWAffine, AffineRepr};
+#[cfg(feature = "subtle")]
+use crate::{short_weierstrass::CtZeroFlag, CtAffine};
+
+/// Affine coordinates for a point on an elliptic curve in short Weierstrass
+/// form, over the base field `P::BaseField`, whose group operations use the
+/// complete formulae of [`Projective`].
+///
+/// This wraps [`short_weierstrass::Affine`](SWAffine), so that converting
+/// between the two is free.
+#[derive(Educe)]
+#[educe(Copy, Clone, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct Affine<P: CompleteSWCurveConfig>(pub SWAffine<P>);
+
+impl<P: CompleteSWCurveConfig> PartialEq<Projective<P>> for Affine<P> {
+    fn eq(&self, other: &Projective<P>) -> bool {
+        self.into_group() == *other
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Display for Affine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Debug for Affine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Affine<P> {
+    /// Constructs a group element from x and y coordinates.
+    /// Performs checks to ensure that the point is on the curve and is in the right subgroup.
+    pub fn new(x: P::BaseField, y: P::BaseField) -> Self {
+        Self(SWAffine::new(x, y))
+    }
+
+    /// Constructs a group element from x and y coordinates.
+    ///
+    /// # Warning
+    ///
+    /// Does *not* perform any checks to ensure the point is in the curve or
+    /// is in the right subgroup.
+    pub const fn new_unchecked(x: P::BaseField, y: P::BaseField) -> Self {
+        Self(SWAffine::new_unchecked(x, y))
+    }
+
+    pub const fn identity() -> Self {
+        Self(SWAffine::identity())
+    }
+
+    /// Checks if `self` is a valid point on the curve.
+    pub fn is_on_curve(&self) -> bool {
+        self.0.is_on_curve()
+    }
+
+    /// Checks if `self` is in the subgroup having order that equaling that of
+    /// `P::ScalarField`.
+    pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+        self.0.is_in_correct_subgroup_assuming_on_curve()
+    }
+}
+
+impl<P: CompleteSWCurveConfig> From<SWAffine<P>> for Affine<P> {
+    #[inline]
+    fn from(p: SWAffine<P>) -> Self {
+        Self(p)
+    }
+}
+
+impl<P: CompleteSWCurveConfig> From<Affine<P>> for SWAffine<P> {
+    #[inline]
+    fn from(p: Affine<P>) -> Self {
+        p.0
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Zeroize for Affine<P> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Distribution<Affine<P>> for Standard {
+    /// Generates a uniformly random instance of the curve.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Affine<P> {
+        Affine(SWAffine::rand(rng))
+    }
+}
+
+impl<P: CompleteSWCurveConfig> AffineRepr for Affine<P> {
+    type Config = P;
+    type BaseField = P::BaseField;
+    type ScalarField = P::ScalarField;
+    type Group = Projective<P>;
+
+    const ZERO: Self = Self::identity();
+
+    fn xy(&self) -> Option<(Self::BaseField, Self::BaseField)> {
+        self.0.xy()
+    }
+
+    #[inline]
+    fn generator() -> Self {
+        Self(P::GENERATOR)
+    }
+
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        SWAffine::from_random_bytes(bytes).map(Self)
+    }
+
+    fn mul_bigint(&self, by: impl AsRef<[u64]>) -> Self::Group {
+        let mut res = Projective::zero();
+        for b in ark_ff::BitIteratorBE::without_leading_zeros(by) {
+            res.double_in_place();
+            if b {
+                res += self
+            }
+        }
+        res
+    }
+
+    /// Multiplies this element by the cofactor and output the
+    /// resulting projective element.
+    fn mul_by_cofactor_to_group(&self) -> Self::Group {
+        self.mul_bigint(P::COFACTOR)
+    }
+
+    /// Performs cofactor clearing.
+    /// The default method is simply to multiply by the cofactor.
+    /// Some curves can implement a more efficient algorithm.
+    fn clear_cofactor(&self) -> Self {
+        Self(P::clear_cofactor(&self.0))
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Neg for Affine<P> {
+    type Output = Self;
+
+    /// If `self.is_zero()`, returns `self` (`== Self::zero()`).
+    /// Else, returns `(x, -y)`, where `self = (x, y)`.
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Neg for &Affine<P> {
+    type Output = Affine<P>;
+
+    #[inline]
+    fn neg(self) -> Affine<P> {
+        -*self
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: CompleteSWCurveConfig> CtAffine for Affine<P>
+where
+    P::BaseField: CtField,
+    P::ZeroFlag: CtZeroFlag<P>,
+{
+    fn ct_from_random_bytes(bytes: &[u8]) -> CtOption<Self> {
+        SWAffine::ct_from_random_bytes(bytes).map(Self)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: CompleteSWCurveConfig> ConstantTimeEq for Affine<P>
+where
+    P::BaseField: CtField,
+    P::ZeroFlag: CtZeroFlag<P>,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: CompleteSWCurveConfig> ConditionallySelectable for Affine<P>
+where
+    P::BaseField: CtField,
+    P::ZeroFlag: CtZeroFlag<P>,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(SWAffine::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl<P: CompleteSWCurveConfig, T: Borrow<Self>> Add<T> for Affine<P> {
+    type Output = Projective<P>;
+    fn add(self, other: T) -> Projective<P> {
+        let mut copy = self.into_group();
+        copy += other.borrow();
+        copy
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Add<Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+    fn add(self, other: Projective<P>) -> Projective<P> {
+        other + self
+    }
+}
+
+impl<'a, P: CompleteSWCurveConfig> Add<&'a Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+    fn add(self, other: &'a Projective<P>) -> Projective<P> {
+        *other + self
+    }
+}
+
+impl<P: CompleteSWCurveConfig, T: Borrow<Self>> Sub<T> for Affine<P> {
+    type Output = Projective<P>;
+    fn sub(self, other: T) -> Projective<P> {
+        let mut copy = self.into_group();
+        copy -= other.borrow();
+        copy
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Sub<Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+    fn sub(self, other: Projective<P>) -> Projective<P> {
+        self + (-other)
+    }
+}
+
+impl<'a, P: CompleteSWCurveConfig> Sub<&'a Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+    fn sub(self, other: &'a Projective<P>) -> Projective<P> {
+        self + (-*other)
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Default for Affine<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<P: CompleteSWCurveConfig, T: Borrow<P::ScalarField>> Mul<T> for Affine<P> {
+    type Output = Projective<P>;
+
+    #[inline]
+    fn mul(self, other: T) -> Self::Output {
+        self.mul_bigint(other.borrow().into_bigint())
+    }
+}
+
+// The projective point X, Y, Z is represented in the affine
+// coordinates as X/Z, Y/Z.
+impl<P: CompleteSWCurveConfig> From<Projective<P>> for Affine<P> {
+    #[inline]
+    fn from(p: Projective<P>) -> Self {
+        if p.is_zero() {
+            Self::identity()
+        } else {
+            // Z is nonzero, so it must have an inverse in a field.
+            let zinv = p.z.inverse().unwrap();
+            Self::new_unchecked(p.x * &zinv, p.y * &zinv)
+        }
+    }
+}
+
+impl<P: CompleteSWCurveConfig> CanonicalSerialize for Affine<P> {
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: ark_serialize::Compress,
+    ) -> Result<(), SerializationError> {
+        P::serialize_with_mode(&self.0, writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        P::serialized_size(compress)
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Valid for Affine<P> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.0.check()
+    }
+}
+
+impl<P: CompleteSWCurveConfig> CanonicalDeserialize for Affine<P> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        P::deserialize_with_mode(reader, compress, validate).map(Self)
+    }
+}
+
+impl<M: CompleteSWCurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Affine<M>
+where
+    M::BaseField: ToConstraintField<ConstraintF>,
+{
+    #[inline]
+    fn to_field_elements(&self) -> Option<Vec<ConstraintF>> {
+        self.0.to_field_elements()
+    }
+}
@@ -0,0 +1,569 @@
+use super::{Affine, CompleteSWCurveConfig};
+#[cfg(feature = "subtle")]
+use crate::CtCurveGroup;
+use crate::{
+    scalar_mul::{variable_base::VariableBaseMSM, ScalarMul},
+    short_weierstrass::{Affine as SWAffine, Projective as SWProjective},
+    AffineRepr, CurveGroup, PrimeGroup,
+};
+#[cfg(feature = "subtle")]
+use ark_ff::CtField;
+use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
+};
+use ark_std::{
+    borrow::Borrow,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    io::{Read, Write},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+    vec::*,
+    One, Zero,
+};
+use educe::Educe;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use zeroize::Zeroize;
+
+/// Homogeneous projective coordinates for a point on an elliptic curve in
+/// short Weierstrass form, over the base field `P::BaseField`. This struct
+/// implements arithmetic via the complete formulae of
+/// <https://eprint.iacr.org/2015/1060>.
+#[derive(Educe)]
+#[educe(Copy, Clone)]
+#[must_use]
+pub struct Projective<P: CompleteSWCurveConfig> {
+    /// `X / Z` projection of the affine `X`
+    pub x: P::BaseField,
+    /// `Y / Z` projection of the affine `Y`
+    pub y: P::BaseField,
+    /// Projective multiplicative inverse. Will be `0` only at infinity.
+    pub z: P::BaseField,
+}
+
+impl<P: CompleteSWCurveConfig> Display for Projective<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", Affine::from(*self))
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Debug for Projective<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.is_zero() {
+            true => write!(f, "infinity"),
+            false => write!(f, "({}, {}, {})", self.x, self.y, self.z),
+        }
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Eq for Projective<P> {}
+impl<P: CompleteSWCurveConfig> PartialEq for Projective<P> {
+    /// The points (X, Y, Z) and (X', Y', Z') are equal when X * Z' = X' * Z
+    /// and Y * Z' = Y' * Z. This also holds for the identity, which is the
+    /// only point with Z = 0.
+    fn eq(&self, other: &Self) -> bool {
+        self.x * &other.z == other.x * &self.z && self.y * &other.z == other.y * &self.z
+    }
+}
+
+impl<P: CompleteSWCurveConfig> PartialEq<Affine<P>> for Projective<P> {
+    fn eq(&self, other: &Affine<P>) -> bool {
+        *self == other.into_group()
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Hash for Projective<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.into_affine().hash(state)
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Distribution<Projective<P>> for Standard {
+    /// Generates a uniformly random instance of the curve.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Projective<P> {
+        SWProjective::<P>::rand(rng).into()
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Default for Projective<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Projective<P> {
+    /// Constructs a new group element without checking whether the coordinates
+    /// specify a point in the subgroup.
+    pub const fn new_unchecked(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Constructs a new group element in a way while enforcing that points are in
+    /// the prime-order subgroup.
+    pub fn new(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
+        let p = Self::new_unchecked(x, y, z).into_affine();
+        assert!(p.is_on_curve());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+        p.into()
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Zeroize for Projective<P> {
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+        self.z.zeroize();
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Zero for Projective<P> {
+    /// Returns the point at infinity, which is `(0, 1, 0)`.
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    /// Checks whether `self.z.is_zero()`.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.z == P::BaseField::ZERO
+    }
+}
+
+impl<P: CompleteSWCurveConfig> AdditiveGroup for Projective<P> {
+    type Scalar = P::ScalarField;
+
+    const ZERO: Self =
+        Self::new_unchecked(P::BaseField::ZERO, P::BaseField::ONE, P::BaseField::ZERO);
+
+    /// Sets `self = 2 * self`, using Algorithm 3 of
+    /// <https://eprint.iacr.org/2015/1060>.
+    fn double_in_place(&mut self) -> &mut Self {
+        let (x, y, z) = (self.x, self.y, self.z);
+
+        let mut t0 = x.square();
+        let t1 = y.square();
+        let mut t2 = z.square();
+        let mut t3 = (x * &y).double();
+        let mut z3 = (x * &z).double();
+        let mut x3 = P::mul_by_a(z3);
+        let mut y3 = P::mul_by_b3(t2);
+        y3 += &x3;
+        x3 = t1 - &y3;
+        y3 += &t1;
+        y3 *= &x3;
+        x3 *= &t3;
+        z3 = P::mul_by_b3(z3);
+        t2 = P::mul_by_a(t2);
+        t3 = P::mul_by_a(t0 - &t2);
+        t3 += &z3;
+        z3 = t0.double();
+        t0 += &z3;
+        t0 += &t2;
+        t0 *= &t3;
+        y3 += &t0;
+        t2 = (y * &z).double();
+        t0 = t2 * &t3;
+        x3 -= &t0;
+        z3 = (t2 * &t1).double().double();
+
+        self.x = x3;
+        self.y = y3;
+        self.z = z3;
+        self
+    }
+}
+
+impl<P: CompleteSWCurveConfig> PrimeGroup for Projective<P> {
+    type ScalarField = P::ScalarField;
+
+    #[inline]
+    fn generator() -> Self {
+        Affine::generator().into()
+    }
+
+    #[inline]
+    fn mul_bigint(&self, other: impl AsRef<[u64]>) -> Self {
+        let mut res = Self::zero();
+        for b in ark_ff::BitIteratorBE::without_leading_zeros(other) {
+            res.double_in_place();
+            if b {
+                res += self;
+            }
+        }
+        res
+    }
+}
+
+impl<P: CompleteSWCurveConfig> CurveGroup for Projective<P> {
+    type Config = P;
+    type BaseField = P::BaseField;
+    type Affine = Affine<P>;
+    type FullGroup = Affine<P>;
+
+    /// Normalizes a slice of projective elements so that
+    /// conversion to affine is cheap.
+    ///
+    /// In more detail, this method converts a curve point in homogeneous
+    /// projective coordinates (x, y, z) into an equivalent representation
+    /// (x/z, y/z, 1).
+    ///
+    /// For `N = v.len()`, this costs 1 inversion + 5N field multiplications.
+    #[inline]
+    fn normalize_batch(v: &[Self]) -> Vec<Self::Affine> {
+        let mut z_s = v.iter().map(|g| g.z).collect::<Vec<_>>();
+        ark_ff::batch_inversion(&mut z_s);
+
+        // Perform affine transformations
+        ark_std::cfg_iter!(v)
+            .zip(z_s)
+            .map(|(g, z)| match g.is_zero() {
+                true => Affine::identity(),
+                false => Affine::new_unchecked(g.x * &z, g.y * &z),
+            })
+            .collect()
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Neg for Projective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.y = -self.y;
+        self
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Neg for &Projective<P> {
+    type Output = Projective<P>;
+
+    #[inline]
+    fn neg(self) -> Projective<P> {
+        -*self
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: CompleteSWCurveConfig> CtCurveGroup for Projective<P> where P::BaseField: CtField {}
+
+#[cfg(feature = "subtle")]
+impl<P: CompleteSWCurveConfig> ConstantTimeEq for Projective<P>
+where
+    P::BaseField: CtField,
+{
+    /// Same as `PartialEq`, without branching on the coordinates.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        (self.x * &other.z).ct_eq(&(other.x * &self.z))
+            & (self.y * &other.z).ct_eq(&(other.y * &self.z))
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: CompleteSWCurveConfig> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: CtField,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new_unchecked(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
+impl<P: CompleteSWCurveConfig, T: Borrow<Affine<P>>> AddAssign<T> for Projective<P> {
+    /// Using Algorithm 2 of <https://eprint.iacr.org/2015/1060>.
+    ///
+    /// The mixed formulas are complete for all projective points, but not for
+    /// the affine identity, which has no representation with `Z = 1`. That
+    /// case is handled by keeping `self`, so unlike the addition of two
+    /// projective points, this branches on whether `other` is the identity.
+    fn add_assign(&mut self, other: T) {
+        let other = other.borrow();
+        let (x1, y1, z1) = (self.x, self.y, self.z);
+        let (x2, y2) = (other.0.x, other.0.y);
+
+        let mut t0 = x1 * &x2;
+        let mut t1 = y1 * &y2;
+        let mut t3 = (x2 + &y2) * &(x1 + &y1);
+        let mut t4 = t0 + &t1;
+        t3 -= &t4;
+        t4 = x2 * &z1 + &x1;
+        let t5 = y2 * &z1 + &y1;
+        let mut z3 = P::mul_by_a(t4);
+        let mut x3 = P::mul_by_b3(z1);
+        z3 += &x3;
+        x3 = t1 - &z3;
+        z3 += &t1;
+        let mut y3 = x3 * &z3;
+        t1 = t0.double() + &t0;
+        let mut t2 = P::mul_by_a(z1);
+        t4 = P::mul_by_b3(t4);
+        t1 += &t2;
+        t2 = P::mul_by_a(t0 - &t2);
+        t4 += &t2;
+        t0 = t1 * &t4;
+        y3 += &t0;
+        t0 = t5 * &t4;
+        x3 *= &t3;
+        x3 -= &t0;
+        t0 = t3 * &t1;
+        z3 *= &t5;
+        z3 += &t0;
+
+        if !other.is_zero() {
+            *self = Self::new_unchecked(x3, y3, z3);
+        }
+    }
+}
+
+impl<P: CompleteSWCurveConfig, T: Borrow<Affine<P>>> Add<T> for Projective<P> {
+    type Output = Self;
+    fn add(mut self, other: T) -> Self {
+        let other = other.borrow();
+        self += other;
+        self
+    }
+}
+
+impl<P: CompleteSWCurveConfig, T: Borrow<Affine<P>>> SubAssign<T> for Projective<P> {
+    fn sub_assign(&mut self, other: T) {
+        *self += -(*other.borrow());
+    }
+}
+
+impl<P: CompleteSWCurveConfig, T: Borrow<Affine<P>>> Sub<T> for Projective<P> {
+    type Output = Self;
+    fn sub(mut self, other: T) -> Self {
+        self -= other.borrow();
+        self
+    }
+}
+
+ark_ff::impl_additive_ops_from_ref!(Projective, CompleteSWCurveConfig);
+
+impl<'a, P: CompleteSWCurveConfig> Add<&'a Self> for Projective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &'a Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, P: CompleteSWCurveConfig> AddAssign<&'a Self> for Projective<P> {
+    /// Using Algorithm 1 of <https://eprint.iacr.org/2015/1060>.
+    fn add_assign(&mut self, other: &'a Self) {
+        let (x1, y1, z1) = (self.x, self.y, self.z);
+        let (x2, y2, z2) = (other.x, other.y, other.z);
+
+        let mut t0 = x1 * &x2;
+        let mut t1 = y1 * &y2;
+        let mut t2 = z1 * &z2;
+        let mut t3 = (x1 + &y1) * &(x2 + &y2);
+        let mut t4 = t0 + &t1;
+        t3 -= &t4;
+        t4 = (x1 + &z1) * &(x2 + &z2);
+        let mut t5 = t0 + &t2;
+        t4 -= &t5;
+        t5 = (y1 + &z1) * &(y2 + &z2);
+        let mut x3 = t1 + &t2;
+        t5 -= &x3;
+        let mut z3 = P::mul_by_a(t4);
+        x3 = P::mul_by_b3(t2);
+        z3 += &x3;
+        x3 = t1 - &z3;
+        z3 += &t1;
+        let mut y3 = x3 * &z3;
+        t1 = t0.double() + &t0;
+        t2 = P::mul_by_a(t2);
+        t4 = P::mul_by_b3(t4);
+        t1 += &t2;
+        t2 = P::mul_by_a(t0 - &t2);
+        t4 += &t2;
+        t0 = t1 * &t4;
+        y3 += &t0;
+        t0 = t5 * &t4;
+        x3 *= &t3;
+        x3 -= &t0;
+        t0 = t3 * &t1;
+        z3 *= &t5;
+        z3 += &t0;
+
+        self.x = x3;
+        self.y = y3;
+        self.z = z3;
+    }
+}
+
+impl<'a, P: CompleteSWCurveConfig> Sub<&'a Self> for Projective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &'a Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<'a, P: CompleteSWCurveConfig> SubAssign<&'a Self> for Projective<P> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self += &(-(*other));
+    }
+}
+
+impl<P: CompleteSWCurveConfig, T: Borrow<P::ScalarField>> MulAssign<T> for Projective<P> {
+    fn mul_assign(&mut self, other: T) {
+        *self = self.mul_bigint(other.borrow().into_bigint())
+    }
+}
+
+impl<P: CompleteSWCurveConfig, T: Borrow<P::ScalarField>> Mul<T> for Projective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: T) -> Self {
+        self *= other;
+        self
+    }
+}
+
+// The affine point X, Y is represented in homogeneous projective
+// coordinates with Z = 1.
+impl<P: CompleteSWCurveConfig> From<Affine<P>> for Projective<P> {
+    #[inline]
+    fn from(p: Affine<P>) -> Self {
+        p.xy().map_or_else(Self::zero, |(x, y)| Self {
+            x,
+            y,
+            z: P::BaseField::one(),
+        })
+    }
+}
+
+// The Jacobian point (X, Y, Z) corresponds to the affine point
+// (X/Z^2, Y/Z^3), which is (X * Z, Y, Z^3) in homogeneous coordinates.
+impl<P: CompleteSWCurveConfig> From<SWProjective<P>> for Projective<P> {
+    #[inline]
+    fn from(p: SWProjective<P>) -> Self {
+        if p.is_zero() {
+            Self::zero()
+        } else {
+            Self::new_unchecked(p.x * &p.z, p.y, p.z.square() * &p.z)
+        }
+    }
+}
+
+// The homogeneous point (X, Y, Z) corresponds to the affine point
+// (X/Z, Y/Z), which is (X * Z, Y * Z^2, Z) in Jacobian coordinates.
+impl<P: CompleteSWCurveConfig> From<Projective<P>> for SWProjective<P> {
+    #[inline]
+    fn from(p: Projective<P>) -> Self {
+        if p.is_zero() {
+            Self::zero()
+        } else {
+            Self::new_unchecked(p.x * &p.z, p.y * &p.z.square(), p.z)
+        }
+    }
+}
+
+impl<P: CompleteSWCurveConfig> From<SWAffine<P>> for Projective<P> {
+    #[inline]
+    fn from(p: SWAffine<P>) -> Self {
+        Affine::from(p).into()
+    }
+}
+
+impl<P: CompleteSWCurveConfig> From<Projective<P>> for SWAffine<P> {
+    #[inline]
+    fn from(p: Projective<P>) -> Self {
+        Affine::from(p).into()
+    }
+}
+
+impl<P: CompleteSWCurveConfig> CanonicalSerialize for Projective<P> {
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        let aff = Affine::from(*self);
+        aff.serialize_with_mode(writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        P::serialized_size(compress)
+    }
+}
+
+impl<P: CompleteSWCurveConfig> Valid for Projective<P> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.into_affine().check()
+    }
+
+    fn batch_check<'a>(
+        batch: impl Iterator<Item = &'a Self> + Send,
+    ) -> Result<(), SerializationError>
+    where
+        Self: 'a,
+    {
+        let batch = batch.copied().collect::<Vec<_>>();
+        let batch = Self::normalize_batch(&batch);
+        Affine::batch_check(batch.iter())
+    }
+}
+
+impl<P: CompleteSWCurveConfig> CanonicalDeserialize for Projective<P> {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let aff = Affine::<P>::deserialize_with_mode(reader, compress, validate)?;
+        Ok(aff.into())
+    }
+}
+
+impl<M: CompleteSWCurveConfig, ConstraintF: Field> ToConstraintField<ConstraintF> for Projective<M>
+where
+    M::BaseField: ToConstraintField<ConstraintF>,
+{
+    #[inline]
+    fn to_field_elements(&self) -> Option<Vec<ConstraintF>> {
+        Affine::from(*self).to_field_elements()
+    }
+}
+
+impl<P: CompleteSWCurveConfig> ScalarMul for Projective<P> {
+    type MulBase = Affine<P>;
+    const NEGATION_IS_CHEAP: bool = true;
+
+    fn batch_convert_to_mul_base(bases: &[Self]) -> Vec<Self::MulBase> {
+        Self::normalize_batch(bases)
+    }
+}
+
+impl<P: CompleteSWCurveConfig> VariableBaseMSM for Projective<P> {
+    type Bucket = Self;
+    const ZERO_BUCKET: Self = Self::ZERO;
+}
+
+impl<P: CompleteSWCurveConfig, T: Borrow<Affine<P>>> core::iter::Sum<T> for Projective<P> {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |sum, x| sum + x.borrow())
+    }
+}
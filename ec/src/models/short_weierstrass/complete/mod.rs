@@ -0,0 +1,40 @@
+//! Homogeneous projective coordinates for short Weierstrass curves, with the
+//! complete addition formulas of [Renes, Costello and Batina](https://eprint.iacr.org/2015/1060).
+//!
+//! Unlike the Jacobian formulas of [`super::Projective`], these formulas have
+//! no exceptional cases: the same sequence of field operations computes
+//! `P + Q` for all inputs, including the identity and `P = Q`. This makes
+//! them suitable for constant-time code and for computing witnesses that
+//! match in-circuit point additions, at the cost of a few extra field
+//! multiplications.
+//!
+//! The formulas are complete only on curves of odd order, so they are only
+//! available for configurations that implement [`CompleteSWCurveConfig`].
+
+use ark_ff::AdditiveGroup;
+
+use super::SWCurveConfig;
+
+mod affine;
+pub use affine::*;
+
+mod group;
+pub use group::*;
+
+/// A short Weierstrass curve whose group of points has odd order, such as a
+/// prime-order curve.
+///
+/// On such curves there is no point of order two, which is the condition
+/// under which the formulas of [`Projective`] are complete. Do *not*
+/// implement this trait for curves with an even cofactor.
+pub trait CompleteSWCurveConfig: SWCurveConfig {
+    /// Helper method for computing `elem * 3 * Self::COEFF_B`.
+    ///
+    /// The default implementation should be overridden only if
+    /// the product can be computed faster, e.g. if `3 * COEFF_B` is small.
+    #[inline(always)]
+    fn mul_by_b3(elem: Self::BaseField) -> Self::BaseField {
+        let elem_b = elem * Self::COEFF_B;
+        elem_b.double() + elem_b
+    }
+}
@@ -1,8 +1,12 @@
 use super::{bucket::Bucket, Affine, SWCurveConfig};
+#[cfg(feature = "subtle")]
+use crate::CtCurveGroup;
 use crate::{
     scalar_mul::{variable_base::VariableBaseMSM, ScalarMul},
     AffineRepr, CurveGroup, PrimeGroup,
 };
+#[cfg(feature = "subtle")]
+use ark_ff::CtField;
 use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
 use ark_serialize::{
     CanonicalDeserialize, CanonicalSerialize, Compress, SerializationError, Valid, Validate,
@@ -23,6 +27,8 @@ use ark_std::{
 use educe::Educe;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::Zeroize;
 
 /// Jacobian coordinates for a point on an elliptic curve in short Weierstrass
@@ -329,6 +335,59 @@ impl<P: SWCurveConfig> Neg for Projective<P> {
     }
 }
 
+impl<P: SWCurveConfig> Neg for &Projective<P> {
+    type Output = Projective<P>;
+
+    #[inline]
+    fn neg(self) -> Projective<P> {
+        -*self
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: SWCurveConfig> CtCurveGroup for Projective<P>
+where
+    P::BaseField: CtField,
+{
+    #[inline]
+    fn mul_ct(&self, scalar: &P::ScalarField) -> Self {
+        P::mul_projective_ct(self, scalar.into_bigint().as_ref())
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: SWCurveConfig> ConstantTimeEq for Projective<P>
+where
+    P::BaseField: CtField,
+{
+    /// Same as `PartialEq`, without branching on the coordinates.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let self_is_zero = self.z.ct_eq(&P::BaseField::ZERO);
+        let other_is_zero = other.z.ct_eq(&P::BaseField::ZERO);
+
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let coords_eq = (self.x * &z2z2).ct_eq(&(other.x * &z1z1))
+            & (self.y * &(z2z2 * &other.z)).ct_eq(&(other.y * &(z1z1 * &self.z)));
+
+        (self_is_zero & other_is_zero) | (!self_is_zero & !other_is_zero & coords_eq)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: SWCurveConfig> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: CtField,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new_unchecked(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: SWCurveConfig, T: Borrow<Affine<P>>> AddAssign<T> for Projective<P> {
     /// Using <http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-madd-2007-bl>
     fn add_assign(&mut self, other: T) {
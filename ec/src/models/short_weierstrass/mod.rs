@@ -7,6 +7,8 @@ use ark_std::{
     io::{Read, Write},
 };
 
+#[cfg(feature = "subtle")]
+use ark_ff::CtField;
 use ark_ff::{fields::Field, AdditiveGroup};
 
 use crate::{
@@ -29,6 +31,9 @@ pub use bucket::Bucket;
 mod serialization_flags;
 pub use serialization_flags::*;
 
+pub mod complete;
+pub use complete::CompleteSWCurveConfig;
+
 /// Constants and convenience functions that collectively define the [Short Weierstrass model](https://www.hyperelliptic.org/EFD/g1p/auto-shortw.html)
 /// of the curve.
 ///
@@ -108,6 +113,17 @@ pub trait SWCurveConfig: super::CurveConfig {
         sw_double_and_add_affine(base, scalar)
     }
 
+    /// Default implementation of constant-time group multiplication for
+    /// projective coordinates, using the fixed-window method of
+    /// [`crate::scalar_mul::fixed_window::mul_ct`].
+    #[cfg(feature = "subtle")]
+    fn mul_projective_ct(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self>
+    where
+        Self::BaseField: CtField,
+    {
+        crate::scalar_mul::fixed_window::mul_ct(base, scalar)
+    }
+
     /// Default implementation for multi scalar multiplication
     fn msm(
         bases: &[Affine<Self>],
@@ -213,6 +229,16 @@ pub trait ZeroFlag<C: SWCurveConfig>:
     }
 }
 
+/// A [`ZeroFlag`] whose checks and selections run in constant time.
+#[cfg(feature = "subtle")]
+pub trait CtZeroFlag<C: SWCurveConfig>: ZeroFlag<C> {
+    /// Constant-time version of [`ZeroFlag::is_zero`].
+    fn ct_is_zero(point: &Affine<C>) -> subtle::Choice;
+
+    /// Returns `a` if `choice` is `0`, and `b` if `choice` is `1`.
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self;
+}
+
 impl<C: SWCurveConfig<ZeroFlag = bool>> ZeroFlag<C> for bool {
     const IS_ZERO: Self = true;
     const IS_NOT_ZERO: Self = false;
@@ -221,6 +247,18 @@ impl<C: SWCurveConfig<ZeroFlag = bool>> ZeroFlag<C> for bool {
     }
 }
 
+#[cfg(feature = "subtle")]
+impl<C: SWCurveConfig<ZeroFlag = Self>> CtZeroFlag<C> for bool {
+    fn ct_is_zero(point: &Affine<C>) -> subtle::Choice {
+        subtle::Choice::from(u8::from(point.infinity))
+    }
+
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        use subtle::ConditionallySelectable;
+        u8::conditional_select(&u8::from(*a), &u8::from(*b), choice) == 1
+    }
+}
+
 impl<C: SWCurveConfig> ZeroFlag<C> for () {
     const IS_ZERO: Self = ();
     const IS_NOT_ZERO: Self = ();
@@ -228,3 +266,16 @@ impl<C: SWCurveConfig> ZeroFlag<C> for () {
         point.x.is_zero() & point.y.is_zero()
     }
 }
+
+#[cfg(feature = "subtle")]
+impl<C: SWCurveConfig> CtZeroFlag<C> for ()
+where
+    C::BaseField: CtField,
+{
+    fn ct_is_zero(point: &Affine<C>) -> subtle::Choice {
+        use subtle::ConstantTimeEq;
+        point.x.ct_eq(&C::BaseField::ZERO) & point.y.ct_eq(&C::BaseField::ZERO)
+    }
+
+    fn conditional_select(_: &Self, _: &Self, _: subtle::Choice) -> Self {}
+}
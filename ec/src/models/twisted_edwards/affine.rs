@@ -14,12 +14,18 @@ use ark_std::{
 };
 use educe::Educe;
 use num_traits::{One, Zero};
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, CtOption};
 use zeroize::Zeroize;
 
+#[cfg(feature = "subtle")]
+use ark_ff::CtField;
 use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
 
 use super::{Projective, TECurveConfig, TEFlags};
 use crate::AffineRepr;
+#[cfg(feature = "subtle")]
+use crate::CtAffine;
 
 /// Affine coordinates for a point on a twisted Edwards curve, over the
 /// base field `P::BaseField`.
@@ -128,6 +134,30 @@ impl<P: TECurveConfig> Affine<P> {
             })
     }
 
+    /// Constant-time version of [`Affine::get_xs_from_y_unchecked`].
+    #[cfg(feature = "subtle")]
+    pub fn ct_get_xs_from_y_unchecked(y: P::BaseField) -> (P::BaseField, P::BaseField, Choice)
+    where
+        P::BaseField: CtField,
+    {
+        let y2 = y.square();
+
+        let numerator = P::BaseField::one() - y2;
+        let denominator = P::COEFF_A - (y2 * P::COEFF_D);
+
+        let denominator_inv = denominator.ct_inverse();
+        let x = (denominator_inv.unwrap_or(P::BaseField::ZERO) * &numerator).ct_sqrt();
+        let is_some = denominator_inv.is_some() & x.is_some();
+        let x = x.unwrap_or(P::BaseField::ZERO);
+        let neg_x = -x;
+        let x_is_larger = x.ct_gt(&neg_x);
+        (
+            P::BaseField::conditional_select(&x, &neg_x, x_is_larger),
+            P::BaseField::conditional_select(&neg_x, &x, x_is_larger),
+            is_some,
+        )
+    }
+
     /// Checks that the current point is on the elliptic curve.
     pub fn is_on_curve(&self) -> bool {
         let x2 = self.x.square();
@@ -213,6 +243,54 @@ impl<P: TECurveConfig> Neg for Affine<P> {
     }
 }
 
+impl<P: TECurveConfig> Neg for &Affine<P> {
+    type Output = Affine<P>;
+
+    fn neg(self) -> Affine<P> {
+        -*self
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: TECurveConfig> CtAffine for Affine<P>
+where
+    P::BaseField: CtField,
+{
+    fn ct_from_random_bytes(bytes: &[u8]) -> CtOption<Self> {
+        let parsed = P::BaseField::from_random_bytes_with_flags::<TEFlags>(bytes);
+        let is_parsed = Choice::from(u8::from(parsed.is_some()));
+        let (y, flags) = parsed.unwrap_or((P::BaseField::ZERO, TEFlags::XIsPositive));
+        let greatest = Choice::from(u8::from(flags.is_negative()));
+
+        let (smaller, larger, is_on_curve) = Self::ct_get_xs_from_y_unchecked(y);
+        let x = P::BaseField::conditional_select(&smaller, &larger, greatest);
+        CtOption::new(Self::new_unchecked(x, y), is_parsed & is_on_curve)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: TECurveConfig> ConstantTimeEq for Affine<P>
+where
+    P::BaseField: CtField,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: TECurveConfig> ConditionallySelectable for Affine<P>
+where
+    P::BaseField: CtField,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new_unchecked(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+        )
+    }
+}
+
 impl<P: TECurveConfig, T: Borrow<Self>> Add<T> for Affine<P> {
     type Output = Projective<P>;
     fn add(self, other: T) -> Self::Output {
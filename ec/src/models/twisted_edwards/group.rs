@@ -15,15 +15,21 @@ use ark_std::{
     One, Zero,
 };
 
+#[cfg(feature = "subtle")]
+use ark_ff::CtField;
 use ark_ff::{fields::Field, AdditiveGroup, PrimeField, ToConstraintField, UniformRand};
 
 use educe::Educe;
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::Zeroize;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 use super::{Affine, MontCurveConfig, TECurveConfig};
+#[cfg(feature = "subtle")]
+use crate::CtCurveGroup;
 use crate::{
     scalar_mul::{variable_base::VariableBaseMSM, ScalarMul},
     AffineRepr, CurveGroup, PrimeGroup,
@@ -244,6 +250,52 @@ impl<P: TECurveConfig> Neg for Projective<P> {
     }
 }
 
+impl<P: TECurveConfig> Neg for &Projective<P> {
+    type Output = Projective<P>;
+    fn neg(self) -> Projective<P> {
+        -*self
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: TECurveConfig> CtCurveGroup for Projective<P>
+where
+    P::BaseField: CtField,
+{
+    #[inline]
+    fn mul_ct(&self, scalar: &P::ScalarField) -> Self {
+        P::mul_projective_ct(self, scalar.into_bigint().as_ref())
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: TECurveConfig> ConstantTimeEq for Projective<P>
+where
+    P::BaseField: CtField,
+{
+    /// Same as `PartialEq`, without branching on the coordinates.
+    /// Since `z` is never zero, the identity needs no special handling.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        (self.x * &other.z).ct_eq(&(other.x * &self.z))
+            & (self.y * &other.z).ct_eq(&(other.y * &self.z))
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: TECurveConfig> ConditionallySelectable for Projective<P>
+where
+    P::BaseField: CtField,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new_unchecked(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.t, &b.t, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: TECurveConfig, T: Borrow<Affine<P>>> AddAssign<T> for Projective<P> {
     fn add_assign(&mut self, other: T) {
         let other = other.borrow();
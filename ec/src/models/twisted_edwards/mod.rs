@@ -7,6 +7,8 @@ use ark_std::io::{Read, Write};
 use crate::{scalar_mul::variable_base::VariableBaseMSM, AffineRepr};
 use num_traits::Zero;
 
+#[cfg(feature = "subtle")]
+use ark_ff::CtField;
 use ark_ff::{fields::Field, AdditiveGroup};
 
 mod affine;
@@ -86,6 +88,17 @@ pub trait TECurveConfig: super::CurveConfig {
         res
     }
 
+    /// Default implementation of constant-time group multiplication for
+    /// projective coordinates, using the fixed-window method of
+    /// [`crate::scalar_mul::fixed_window::mul_ct`].
+    #[cfg(feature = "subtle")]
+    fn mul_projective_ct(base: &Projective<Self>, scalar: &[u64]) -> Projective<Self>
+    where
+        Self::BaseField: CtField,
+    {
+        crate::scalar_mul::fixed_window::mul_ct(base, scalar)
+    }
+
     /// Default implementation for multi scalar multiplication
     fn msm(
         bases: &[Affine<Self>],
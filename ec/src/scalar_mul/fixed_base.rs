@@ -0,0 +1,325 @@
+//! Multi-scalar multiplication over a fixed set of bases.
+//!
+//! When many MSMs are computed over the same bases (e.g. the SRS of a KZG or
+//! Groth16 prover), the shifted multiples `2^{k·c·t} · P_i` of each base can be
+//! precomputed once, where `c` is the window size. An MSM then only processes
+//! `t` windows of `c` bits, instead of all `⌈MODULUS_BIT_SIZE / c⌉` windows:
+//! the digits of the other windows are paired with the shifted multiples.
+//! This saves the doublings and the bucket reductions of the skipped windows, at
+//! the cost of storing `⌈MODULUS_BIT_SIZE / (c·t)⌉` points per base.
+use ark_ff::PrimeField;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use ark_std::{cfg_chunks, cfg_chunks_mut, cfg_iter, vec, vec::*};
+use educe::Educe;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::{ln_without_floats, variable_base::make_digits};
+use crate::{AffineRepr, CurveGroup};
+
+/// The largest supported window size. Each window uses `2^c` buckets.
+pub const MAX_WINDOW_SIZE: usize = 24;
+
+/// Precomputed tables for multi-scalar multiplications over a fixed set of
+/// bases.
+///
+/// For each base `P_i`, the table stores the multiples `2^{k·c·t} · P_i` for
+/// `k` in `0..multiples_per_base`, where `c` is the window size and `t` is the
+/// number of windows processed by each MSM. More multiples per base use more
+/// memory, and reduce the number of windows, hence the work per MSM.
+///
+/// The tables implement [`CanonicalSerialize`] and [`CanonicalDeserialize`],
+/// so that they can be computed once and cached.
+///
+/// # Example
+/// ```
+/// use ark_ec::{scalar_mul::fixed_base::FixedBaseMSM, CurveGroup, VariableBaseMSM};
+/// use ark_std::UniformRand;
+/// use ark_test_curves::bls12_381::{Fr, G1Projective as G};
+///
+/// let mut rng = ark_std::test_rng();
+/// let bases = G::normalize_batch(&(0..100).map(|_| G::rand(&mut rng)).collect::<Vec<_>>());
+/// let scalars = (0..100).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+///
+/// // Store 4 shifted multiples of each base.
+/// let table = FixedBaseMSM::<G>::new(&bases, 4);
+/// assert_eq!(table.msm(&scalars), G::msm(&bases, &scalars));
+/// ```
+#[derive(Educe)]
+#[educe(Clone, Debug, PartialEq, Eq)]
+pub struct FixedBaseMSM<G: CurveGroup> {
+    window_size: usize,
+    num_windows: usize,
+    num_bases: usize,
+    /// The multiples of each base, stored contiguously, base by base.
+    tables: Vec<G::Affine>,
+}
+
+impl<G: CurveGroup> FixedBaseMSM<G> {
+    /// Precomputes the tables for `bases`, storing at most `multiples_per_base`
+    /// shifted multiples of each base.
+    ///
+    /// The window size is the one [`VariableBaseMSM`] uses for as many bases, so
+    /// that the buckets take up as much memory: larger windows need fewer
+    /// additions, but their buckets quickly stop fitting in the cache.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `multiples_per_base` is zero.
+    ///
+    /// [`VariableBaseMSM`]: crate::VariableBaseMSM
+    pub fn new(bases: &[G::Affine], multiples_per_base: usize) -> Self {
+        let window_size = if bases.len() < 32 {
+            3
+        } else {
+            (ln_without_floats(bases.len()) + 2).min(MAX_WINDOW_SIZE)
+        };
+        Self::with_window_size(bases, window_size, multiples_per_base)
+    }
+
+    /// Precomputes the tables for `bases` with the given window size, storing at
+    /// most `multiples_per_base` shifted multiples of each base.
+    ///
+    /// Storing `⌈MODULUS_BIT_SIZE / window_size⌉` multiples or more makes each
+    /// MSM process a single window, without any doublings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `multiples_per_base` is zero, or if `window_size` is zero or
+    /// larger than [`MAX_WINDOW_SIZE`].
+    pub fn with_window_size(
+        bases: &[G::Affine],
+        window_size: usize,
+        multiples_per_base: usize,
+    ) -> Self {
+        assert!(
+            (1..=MAX_WINDOW_SIZE).contains(&window_size),
+            "window size must be between 1 and {MAX_WINDOW_SIZE}"
+        );
+        assert!(
+            multiples_per_base > 0,
+            "at least one multiple per base is needed"
+        );
+
+        let digits_count = Self::digits_count(window_size);
+        let num_windows = digits_count.div_ceil(multiples_per_base.min(digits_count));
+        let multiples_per_base = digits_count.div_ceil(num_windows);
+        let shift = window_size * num_windows;
+
+        let mut tables = vec![G::zero(); bases.len() * multiples_per_base];
+        cfg_chunks_mut!(tables, multiples_per_base)
+            .zip(cfg_iter!(bases))
+            .for_each(|(multiples, base)| {
+                multiples[0] = base.into_group();
+                for k in 1..multiples.len() {
+                    let mut multiple = multiples[k - 1];
+                    for _ in 0..shift {
+                        multiple.double_in_place();
+                    }
+                    multiples[k] = multiple;
+                }
+            });
+
+        Self {
+            window_size,
+            num_windows,
+            num_bases: bases.len(),
+            tables: G::normalize_batch(&tables),
+        }
+    }
+
+    /// Returns the number of bases.
+    pub const fn num_bases(&self) -> usize {
+        self.num_bases
+    }
+
+    /// Returns the window size, in bits.
+    pub const fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Returns the number of windows processed by each MSM.
+    pub const fn num_windows(&self) -> usize {
+        self.num_windows
+    }
+
+    /// Returns the number of shifted multiples stored for each base.
+    pub const fn multiples_per_base(&self) -> usize {
+        Self::digits_count(self.window_size).div_ceil(self.num_windows)
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]`.
+    ///
+    /// # Warning
+    ///
+    /// This method checks that there are as many scalars as bases.
+    /// If there are not, it returns an error containing the shortest length
+    /// over which the MSM can be performed.
+    pub fn msm(&self, scalars: &[G::ScalarField]) -> Result<G, usize> {
+        (self.num_bases == scalars.len())
+            .then(|| self.msm_unchecked(scalars))
+            .ok_or_else(|| self.num_bases.min(scalars.len()))
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]`, over the shortest length between
+    /// `scalars.len()` and the number of bases.
+    pub fn msm_unchecked(&self, scalars: &[G::ScalarField]) -> G {
+        let bigints = cfg_iter!(scalars)
+            .map(|s| s.into_bigint())
+            .collect::<Vec<_>>();
+        self.msm_bigint(&bigints)
+    }
+
+    /// Computes `sum_i bigints[i] * bases[i]`, over the shortest length between
+    /// `bigints.len()` and the number of bases.
+    pub fn msm_bigint(&self, bigints: &[<G::ScalarField as PrimeField>::BigInt]) -> G {
+        let size = self.num_bases.min(bigints.len());
+        if size == 0 {
+            return G::zero();
+        }
+        let bigints = &bigints[..size];
+        let multiples_per_base = self.multiples_per_base();
+        let tables = &self.tables[..size * multiples_per_base];
+
+        #[cfg(feature = "parallel")]
+        let chunk_size = size.div_ceil(rayon::current_num_threads());
+        #[cfg(not(feature = "parallel"))]
+        let chunk_size = size;
+
+        cfg_chunks!(bigints, chunk_size)
+            .zip(cfg_chunks!(tables, chunk_size * multiples_per_base))
+            .map(|(bigints, tables)| self.msm_serial(bigints, tables))
+            .sum()
+    }
+
+    fn msm_serial(
+        &self,
+        bigints: &[<G::ScalarField as PrimeField>::BigInt],
+        tables: &[G::Affine],
+    ) -> G {
+        let c = self.window_size;
+        let num_bits = G::ScalarField::MODULUS_BIT_SIZE as usize;
+        let digits_count = Self::digits_count(c);
+        let multiples_per_base = self.multiples_per_base();
+        let digits = bigints
+            .iter()
+            .flat_map(|s| make_digits(s, c, num_bits))
+            .collect::<Vec<_>>();
+
+        // The digits lie in `[-2^c, 2^c]`, so we need `2^c` buckets,
+        // where the bucket at index `i` holds the points with digit `±(i + 1)`.
+        let mut buckets = vec![G::ZERO_BUCKET; 1 << c];
+        let mut result = G::zero();
+        // We're traversing windows from high to low.
+        for j in (0..self.num_windows).rev() {
+            for _ in 0..c {
+                result.double_in_place();
+            }
+            for (digits, multiples) in digits
+                .chunks(digits_count)
+                .zip(tables.chunks(multiples_per_base))
+            {
+                // The digit of window `k·t + j` is paired with the multiple
+                // `2^{k·c·t} · P_i`.
+                for (&digit, multiple) in
+                    digits[j..].iter().step_by(self.num_windows).zip(multiples)
+                {
+                    use ark_std::cmp::Ordering;
+                    match 0.cmp(&digit) {
+                        Ordering::Less => buckets[(digit - 1) as usize] += multiple,
+                        Ordering::Greater => buckets[(-digit - 1) as usize] -= multiple,
+                        Ordering::Equal => (),
+                    }
+                }
+            }
+
+            // `running_sum` = sum_{l in i..num_buckets} bucket[l],
+            // where we iterate backward from i = num_buckets to 0.
+            let mut running_sum = G::ZERO_BUCKET;
+            let mut window_sum = G::ZERO_BUCKET;
+            for bucket in buckets.iter_mut().rev() {
+                running_sum += &*bucket;
+                window_sum += &running_sum;
+                *bucket = G::ZERO_BUCKET;
+            }
+            result += &window_sum;
+        }
+        result
+    }
+
+    /// Returns the number of signed digits of a scalar, for the given window
+    /// size.
+    const fn digits_count(window_size: usize) -> usize {
+        (G::ScalarField::MODULUS_BIT_SIZE as usize).div_ceil(window_size)
+    }
+
+    /// Returns the number of bases described by the given parameters and
+    /// number of table entries, if they are consistent.
+    fn num_bases_for(window_size: usize, num_windows: usize, tables_len: usize) -> Option<usize> {
+        if !(1..=MAX_WINDOW_SIZE).contains(&window_size) {
+            return None;
+        }
+        let digits_count = Self::digits_count(window_size);
+        if !(1..=digits_count).contains(&num_windows) {
+            return None;
+        }
+        let multiples_per_base = digits_count.div_ceil(num_windows);
+        (tables_len % multiples_per_base == 0).then_some(tables_len / multiples_per_base)
+    }
+}
+
+impl<G: CurveGroup> CanonicalSerialize for FixedBaseMSM<G> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.window_size
+            .serialize_with_mode(&mut writer, compress)?;
+        self.num_windows
+            .serialize_with_mode(&mut writer, compress)?;
+        self.tables.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.window_size.serialized_size(compress)
+            + self.num_windows.serialized_size(compress)
+            + self.tables.serialized_size(compress)
+    }
+}
+
+impl<G: CurveGroup> Valid for FixedBaseMSM<G> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if Self::num_bases_for(self.window_size, self.num_windows, self.tables.len())
+            != Some(self.num_bases)
+        {
+            return Err(SerializationError::InvalidData);
+        }
+        self.tables.check()
+    }
+}
+
+impl<G: CurveGroup> CanonicalDeserialize for FixedBaseMSM<G> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let window_size = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let num_windows = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let tables = Vec::deserialize_with_mode(&mut reader, compress, validate)?;
+        // The shape is always checked, since the MSM relies on it.
+        let num_bases = Self::num_bases_for(window_size, num_windows, tables.len())
+            .ok_or(SerializationError::InvalidData)?;
+        Ok(Self {
+            window_size,
+            num_windows,
+            num_bases,
+            tables,
+        })
+    }
+}
@@ -0,0 +1,129 @@
+//! Constant-time scalar multiplication using a fixed-window method.
+//!
+//! The scalar is recoded into signed odd digits, following Joye and Tunstall,
+//! "Exponent Recoding and Regular Exponentiation Algorithms" (AFRICACRYPT
+//! 2009). Every window of the scalar is processed, including leading zero
+//! windows, and the multiple of the base for each window is read from a
+//! precomputed table of odd multiples by scanning the whole table with
+//! conditional selections. Since no digit is zero, the accumulator never has
+//! the identity added to it, so the sequence of group operations and memory
+//! accesses does not depend on the value of the scalar, only on its number of
+//! limbs.
+//!
+//! The group law itself may still branch on its inputs: for instance, the
+//! Jacobian formulas of [`short_weierstrass::Projective`] special-case the
+//! identity and doubling. With the recoding above, and for a scalar smaller
+//! than the order `r` of the base, these cases are only reached when `scalar`,
+//! `scalar + 1` or `scalar + 2` is a multiple of `r`.
+//!
+//! [`short_weierstrass::Projective`]: crate::short_weierstrass::Projective
+use ark_ff::AdditiveGroup;
+use ark_std::vec::Vec;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+/// The number of scalar bits processed per window.
+pub const WINDOW_SIZE: usize = 4;
+
+/// The number of odd multiples `base, 3 * base, ..., (2^w - 1) * base`.
+const TABLE_SIZE: usize = 1 << (WINDOW_SIZE - 1);
+
+/// Computes `scalar * base` in time that does not depend on the value of
+/// `scalar`, where `scalar` is a little-endian sequence of limbs.
+///
+/// All `64 * scalar.len()` bits of the scalar are processed, so leading zero
+/// bits are not skipped.
+pub fn mul_ct<G>(base: &G, scalar: impl AsRef<[u64]>) -> G
+where
+    G: AdditiveGroup + ConditionallySelectable,
+{
+    let scalar = scalar.as_ref();
+
+    // `table[i] = (2i + 1) * base`.
+    let double = base.double();
+    let mut table = [*base; TABLE_SIZE];
+    for i in 1..TABLE_SIZE {
+        table[i] = table[i - 1] + double;
+    }
+
+    // The recoding needs an odd scalar, so we compute `(scalar + 1) * base`
+    // for even scalars and `(scalar + 2) * base` for odd ones, and subtract
+    // `base` or `2 * base` at the end.
+    let is_odd = Choice::from((scalar.first().copied().unwrap_or(0) & 1) as u8);
+    let digits = recode(scalar, 1 + u64::from(is_odd.unwrap_u8()));
+
+    let (top, rest) = digits.split_last().unwrap();
+    let mut res = select(&table, *top);
+    for &digit in rest.iter().rev() {
+        for _ in 0..WINDOW_SIZE {
+            res.double_in_place();
+        }
+        res += select(&table, digit);
+    }
+
+    let correction = G::conditional_select(base, &double, is_odd);
+    res - correction
+}
+
+/// Recodes the odd integer `scalar + offset` into `64 * scalar.len() /
+/// WINDOW_SIZE + 1` odd digits in `[-(2^w - 1), 2^w - 1]`, least significant
+/// first, such that `scalar + offset = sum_i digits[i] * 2^(w * i)`. The last
+/// digit is positive.
+fn recode(scalar: &[u64], offset: u64) -> Vec<i64> {
+    const MASK: u64 = (1 << (WINDOW_SIZE + 1)) - 1;
+
+    // `scalar + offset` may not fit in `scalar.len()` limbs.
+    let mut k = Vec::with_capacity(scalar.len() + 1);
+    k.extend_from_slice(scalar);
+    k.push(0);
+    add_signed(&mut k, offset as i64);
+
+    let num_windows = 64 * scalar.len() / WINDOW_SIZE;
+    let mut digits = Vec::with_capacity(num_windows + 1);
+    for _ in 0..num_windows {
+        // `k` is odd, so `digit` is odd, and `(k - digit) / 2^w` is odd again.
+        let digit = (k[0] & MASK) as i64 - (1 << WINDOW_SIZE);
+        add_signed(&mut k, -digit);
+        shift_right(&mut k, WINDOW_SIZE as u32);
+        digits.push(digit);
+    }
+    // What remains is odd and less than `2^w`.
+    digits.push(k[0] as i64);
+    digits
+}
+
+/// Adds `value` to the little-endian integer `k`, propagating the carry
+/// through every limb.
+fn add_signed(k: &mut [u64], value: i64) {
+    let mut carry = i128::from(value);
+    for limb in k {
+        let t = i128::from(*limb) + carry;
+        *limb = t as u64;
+        carry = t >> 64;
+    }
+}
+
+/// Shifts the little-endian integer `k` right by `shift < 64` bits.
+fn shift_right(k: &mut [u64], shift: u32) {
+    for i in 0..k.len() {
+        let high = k.get(i + 1).copied().unwrap_or(0);
+        k[i] = (k[i] >> shift) | (high << (64 - shift));
+    }
+}
+
+/// Returns `digit * base` for an odd `digit`, given the table of odd multiples
+/// of `base`, by scanning the whole table.
+fn select<G>(table: &[G; TABLE_SIZE], digit: i64) -> G
+where
+    G: AdditiveGroup + ConditionallySelectable,
+{
+    let sign = (digit >> 63) as u64;
+    let abs = ((digit as u64) ^ sign).wrapping_sub(sign);
+    let index = abs >> 1;
+    let mut multiple = table[0];
+    for (i, entry) in table.iter().enumerate().skip(1) {
+        multiple.conditional_assign(entry, (i as u64).ct_eq(&index));
+    }
+    let negated = -multiple;
+    multiple.conditional_assign(&negated, Choice::from((sign & 1) as u8));
+    multiple
+}
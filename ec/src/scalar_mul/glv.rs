@@ -1,9 +1,11 @@
 use crate::{
+    scalar_mul::variable_base::{msm_glv, VariableBaseMSM},
     short_weierstrass::{Affine, Projective, SWCurveConfig},
+    twisted_edwards::{self as te, TECurveConfig},
     AdditiveGroup, CurveGroup,
 };
-use ark_ff::{PrimeField, Zero};
-use ark_std::ops::Neg;
+use ark_ff::PrimeField;
+use ark_std::ops::{Add, AddAssign, Neg};
 use num_bigint::{BigInt, BigUint, Sign};
 use num_integer::Integer;
 use num_traits::{One, Signed};
@@ -25,63 +27,20 @@ pub trait GLVConfig: Send + Sync + 'static + SWCurveConfig {
     /// The determinant of this matrix must equal `ScalarField::characteristic()`.
     const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4];
 
+    /// The largest number of points for which [`Self::glv_msm`] uses
+    /// [`msm_glv`], which must be faster than the generic MSM up to this size.
+    ///
+    /// The crossover depends on the relative cost of the scalar decomposition
+    /// and of the group operations. Single-threaded, `msm_glv` was measured to
+    /// be faster up to `2^11` points on secp256k1, which is the default, and
+    /// up to `2^12` points on BLS12-381 G1.
+    const GLV_MSM_MAX_SIZE: usize = 1 << 11;
+
     /// Decomposes a scalar s into k1, k2, s.t. s = k1 + lambda k2,
     fn scalar_decomposition(
         k: Self::ScalarField,
     ) -> ((bool, Self::ScalarField), (bool, Self::ScalarField)) {
-        let scalar: BigInt = k.into_bigint().into().into();
-
-        let [n11, n12, n21, n22] = Self::SCALAR_DECOMP_COEFFS.map(|x| {
-            let sign = if x.0 { Sign::Plus } else { Sign::Minus };
-            BigInt::from_biguint(sign, x.1.into())
-        });
-
-        let r = BigInt::from(Self::ScalarField::MODULUS.into());
-
-        // beta = vector([k,0]) * self.curve.N_inv
-        // The inverse of N is 1/r * Matrix([[n22, -n12], [-n21, n11]]).
-        // so β = (k*n22, -k*n12)/r
-
-        let beta_1 = {
-            let (mut div, rem) = (&scalar * &n22).div_rem(&r);
-            if (&rem + &rem) > r {
-                div += BigInt::one();
-            }
-            div
-        };
-        let beta_2 = {
-            let (mut div, rem) = (&scalar * &n12.clone().neg()).div_rem(&r);
-            if (&rem + &rem) > r {
-                div += BigInt::one();
-            }
-            div
-        };
-
-        // b = vector([int(beta[0]), int(beta[1])]) * self.curve.N
-        // b = (β1N11 + β2N21, β1N12 + β2N22) with the signs!
-        //   = (b11   + b12  , b21   + b22)   with the signs!
-
-        // b1
-        let b11 = &beta_1 * &n11;
-        let b12 = &beta_2 * &n21;
-        let b1 = b11 + b12;
-
-        // b2
-        let b21 = &beta_1 * &n12;
-        let b22 = &beta_2 * &n22;
-        let b2 = b21 + b22;
-
-        let k1 = &scalar - b1;
-        let k1_abs = BigUint::try_from(k1.abs()).unwrap();
-
-        // k2
-        let k2 = -b2;
-        let k2_abs = BigUint::try_from(k2.abs()).unwrap();
-
-        (
-            (k1.sign() == Sign::Plus, k1_abs.into()),
-            (k2.sign() == Sign::Plus, k2_abs.into()),
-        )
+        decompose_scalar(k, &Self::SCALAR_DECOMP_COEFFS)
     }
 
     fn endomorphism(p: &Projective<Self>) -> Projective<Self>;
@@ -89,74 +48,190 @@ pub trait GLVConfig: Send + Sync + 'static + SWCurveConfig {
     fn endomorphism_affine(p: &Affine<Self>) -> Affine<Self>;
 
     fn glv_mul_projective(p: Projective<Self>, k: Self::ScalarField) -> Projective<Self> {
-        let ((sgn_k1, k1), (sgn_k2, k2)) = Self::scalar_decomposition(k);
+        let decomposition = Self::scalar_decomposition(k);
+        glv_mul(p, Self::endomorphism(&p), decomposition)
+    }
 
-        let mut b1 = p;
-        let mut b2 = Self::endomorphism(&p);
+    fn glv_mul_affine(p: Affine<Self>, k: Self::ScalarField) -> Affine<Self> {
+        let decomposition = Self::scalar_decomposition(k);
+        let res: Projective<Self> = glv_mul(p, Self::endomorphism_affine(&p), decomposition);
+        res.into_affine()
+    }
 
-        if !sgn_k1 {
-            b1 = -b1;
+    /// Performs multi-scalar multiplication, using the endomorphism with
+    /// [`msm_glv`] for inputs of up to [`Self::GLV_MSM_MAX_SIZE`] points, and
+    /// [`VariableBaseMSM::msm_unchecked`] otherwise.
+    ///
+    /// The endomorphism only acts as multiplication by `LAMBDA` on the
+    /// prime-order subgroup, so for points outside of it, this agrees with
+    /// [`SWCurveConfig::mul_projective`] only if the latter uses
+    /// [`Self::glv_mul_projective`]. Hence this should only be used as
+    /// [`SWCurveConfig::msm`] for such curves, or for curves of cofactor one.
+    ///
+    /// Like [`SWCurveConfig::msm`], this returns an error containing the
+    /// shortest length if `bases` and `scalars` have different lengths.
+    fn glv_msm(
+        bases: &[Affine<Self>],
+        scalars: &[Self::ScalarField],
+    ) -> Result<Projective<Self>, usize> {
+        if bases.len() != scalars.len() {
+            return Err(bases.len().min(scalars.len()));
         }
-        if !sgn_k2 {
-            b2 = -b2;
+        if bases.len() <= Self::GLV_MSM_MAX_SIZE {
+            Ok(msm_glv(bases, scalars))
+        } else {
+            Ok(VariableBaseMSM::msm_unchecked(bases, scalars))
         }
+    }
+}
 
-        let b1b2 = b1 + b2;
-
-        let iter_k1 = ark_ff::BitIteratorBE::new(k1.into_bigint());
-        let iter_k2 = ark_ff::BitIteratorBE::new(k2.into_bigint());
-
-        let mut res = Projective::zero();
-        let mut skip_zeros = true;
-        for pair in iter_k1.zip(iter_k2) {
-            if skip_zeros && pair == (false, false) {
-                skip_zeros = false;
-                continue;
-            }
-            res.double_in_place();
-            match pair {
-                (true, false) => res += b1,
-                (false, true) => res += b2,
-                (true, true) => res += b1b2,
-                (false, false) => {},
-            }
-        }
-        res
+/// The GLV parameters for computing the endomorphism and scalar decomposition
+/// on a twisted Edwards curve.
+///
+/// This is the twisted Edwards counterpart of [`GLVConfig`]. The
+/// endomorphism only acts as multiplication by `LAMBDA` on the prime-order
+/// subgroup, so the `glv_mul_*` methods must only be called on points of
+/// that subgroup.
+pub trait TEGLVConfig: Send + Sync + 'static + TECurveConfig {
+    /// Constant used to calculate `phi(G) := lambda*G`.
+    ///
+    /// The coefficients of the endomorphism
+    const ENDO_COEFFS: &[Self::BaseField];
+
+    /// Constant used to calculate `phi(G) := lambda*G`.
+    ///
+    /// The eigenvalue corresponding to the endomorphism.
+    const LAMBDA: Self::ScalarField;
+
+    /// The LLL-reduced basis for the scalar decomposition, in the same layout
+    /// as [`GLVConfig::SCALAR_DECOMP_COEFFS`].
+    /// The determinant of this matrix must equal `ScalarField::characteristic()`.
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4];
+
+    /// Decomposes a scalar s into k1, k2, s.t. s = k1 + lambda k2,
+    fn scalar_decomposition(
+        k: Self::ScalarField,
+    ) -> ((bool, Self::ScalarField), (bool, Self::ScalarField)) {
+        decompose_scalar(k, &Self::SCALAR_DECOMP_COEFFS)
     }
 
-    fn glv_mul_affine(p: Affine<Self>, k: Self::ScalarField) -> Affine<Self> {
-        let ((sgn_k1, k1), (sgn_k2, k2)) = Self::scalar_decomposition(k);
+    fn endomorphism(p: &te::Projective<Self>) -> te::Projective<Self>;
+
+    fn endomorphism_affine(p: &te::Affine<Self>) -> te::Affine<Self>;
 
-        let mut b1 = p;
-        let mut b2 = Self::endomorphism_affine(&p);
+    fn glv_mul_projective(p: te::Projective<Self>, k: Self::ScalarField) -> te::Projective<Self> {
+        let decomposition = Self::scalar_decomposition(k);
+        glv_mul(p, Self::endomorphism(&p), decomposition)
+    }
 
-        if !sgn_k1 {
-            b1 = -b1;
+    fn glv_mul_affine(p: te::Affine<Self>, k: Self::ScalarField) -> te::Affine<Self> {
+        let decomposition = Self::scalar_decomposition(k);
+        let res: te::Projective<Self> = glv_mul(p, Self::endomorphism_affine(&p), decomposition);
+        res.into_affine()
+    }
+}
+
+/// Decomposes `k` into `(k1, k2)` such that `k = k1 + lambda * k2`, using the
+/// lattice basis `coeffs` laid out as in [`GLVConfig::SCALAR_DECOMP_COEFFS`].
+fn decompose_scalar<F: PrimeField>(
+    k: F,
+    coeffs: &[(bool, F::BigInt); 4],
+) -> ((bool, F), (bool, F)) {
+    let scalar: BigInt = k.into_bigint().into().into();
+
+    let [n11, n12, n21, n22] = coeffs.map(|x| {
+        let sign = if x.0 { Sign::Plus } else { Sign::Minus };
+        BigInt::from_biguint(sign, x.1.into())
+    });
+
+    let r = BigInt::from(F::MODULUS.into());
+
+    // beta = vector([k,0]) * self.curve.N_inv
+    // The inverse of N is 1/r * Matrix([[n22, -n12], [-n21, n11]]).
+    // so β = (k*n22, -k*n12)/r
+
+    let beta_1 = {
+        let (mut div, rem) = (&scalar * &n22).div_rem(&r);
+        if (&rem + &rem) > r {
+            div += BigInt::one();
         }
-        if !sgn_k2 {
-            b2 = -b2;
+        div
+    };
+    let beta_2 = {
+        let (mut div, rem) = (&scalar * &n12.clone().neg()).div_rem(&r);
+        if (&rem + &rem) > r {
+            div += BigInt::one();
         }
+        div
+    };
+
+    // b = vector([int(beta[0]), int(beta[1])]) * self.curve.N
+    // b = (β1N11 + β2N21, β1N12 + β2N22) with the signs!
+    //   = (b11   + b12  , b21   + b22)   with the signs!
+
+    // b1
+    let b11 = &beta_1 * &n11;
+    let b12 = &beta_2 * &n21;
+    let b1 = b11 + b12;
+
+    // b2
+    let b21 = &beta_1 * &n12;
+    let b22 = &beta_2 * &n22;
+    let b2 = b21 + b22;
+
+    let k1 = &scalar - b1;
+    let k1_abs = BigUint::try_from(k1.abs()).unwrap();
+
+    // k2
+    let k2 = -b2;
+    let k2_abs = BigUint::try_from(k2.abs()).unwrap();
+
+    (
+        (k1.sign() == Sign::Plus, k1_abs.into()),
+        (k2.sign() == Sign::Plus, k2_abs.into()),
+    )
+}
+
+/// Computes `k1 * p + k2 * endo_p` with a joint double-and-add, given the
+/// signed decomposition `((sgn_k1, k1), (sgn_k2, k2))` of a scalar.
+///
+/// `B` is the representation of the inputs, which may be affine to make use
+/// of mixed additions, and `G` that of the output.
+fn glv_mul<F, B, G>(p: B, endo_p: B, ((sgn_k1, k1), (sgn_k2, k2)): ((bool, F), (bool, F))) -> G
+where
+    F: PrimeField,
+    B: Copy + Neg<Output = B> + Add<Output = G>,
+    G: AdditiveGroup + AddAssign<B>,
+{
+    let mut b1 = p;
+    let mut b2 = endo_p;
+
+    if !sgn_k1 {
+        b1 = -b1;
+    }
+    if !sgn_k2 {
+        b2 = -b2;
+    }
+
+    let b1b2 = b1 + b2;
+
+    let iter_k1 = ark_ff::BitIteratorBE::new(k1.into_bigint());
+    let iter_k2 = ark_ff::BitIteratorBE::new(k2.into_bigint());
 
-        let b1b2 = b1 + b2;
-
-        let iter_k1 = ark_ff::BitIteratorBE::new(k1.into_bigint());
-        let iter_k2 = ark_ff::BitIteratorBE::new(k2.into_bigint());
-
-        let mut res = Projective::zero();
-        let mut skip_zeros = true;
-        for pair in iter_k1.zip(iter_k2) {
-            if skip_zeros && pair == (false, false) {
-                skip_zeros = false;
-                continue;
-            }
-            res.double_in_place();
-            match pair {
-                (true, false) => res += b1,
-                (false, true) => res += b2,
-                (true, true) => res += b1b2,
-                (false, false) => {},
-            }
+    let mut res = G::zero();
+    let mut skip_zeros = true;
+    for pair in iter_k1.zip(iter_k2) {
+        if skip_zeros && pair == (false, false) {
+            skip_zeros = false;
+            continue;
+        }
+        res.double_in_place();
+        match pair {
+            (true, false) => res += b1,
+            (false, true) => res += b2,
+            (true, true) => res += b1b2,
+            (false, false) => {},
         }
-        res.into_affine()
     }
+    res
 }
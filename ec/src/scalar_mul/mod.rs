@@ -1,3 +1,6 @@
+pub mod fixed_base;
+#[cfg(feature = "subtle")]
+pub mod fixed_window;
 pub mod glv;
 pub mod wnaf;
 
@@ -0,0 +1,243 @@
+use ark_ff::{batch_inversion, Field, PrimeField};
+use ark_std::{cfg_into_iter, vec, vec::Vec, Zero};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::make_digits;
+use crate::{
+    scalar_mul::ln_without_floats,
+    short_weierstrass::{Affine, Bucket, Projective, SWCurveConfig},
+    AdditiveGroup, AffineRepr,
+};
+
+/// Once a round of batched additions schedules fewer than this many
+/// additions, the shared inversion no longer pays for itself, and the
+/// remaining additions are performed in extended Jacobian coordinates instead.
+const MIN_BATCH_SIZE: usize = 16;
+
+/// Computes a multi-scalar multiplication with Pippenger's algorithm, keeping
+/// the buckets in affine coordinates.
+///
+/// Adding a point to a [`Bucket`] in extended Jacobian coordinates costs about
+/// 10 field multiplications. Here, the additions into the buckets of a window
+/// are instead performed in rounds of independent affine additions, whose
+/// denominators are inverted together with a single [`batch_inversion`], so that
+/// each addition costs about 6 field multiplications.
+///
+/// An addition into a bucket that already has an addition scheduled in the
+/// current round is pushed onto a conflict queue, which is processed in the
+/// next round. When a round becomes too small for the batched inversion to be
+/// worthwhile, the remaining conflicts are accumulated in extended Jacobian
+/// coordinates.
+///
+/// The scalars are recoded into signed digits, since negating an affine point
+/// is free.
+///
+/// If the slices have different lengths, they are truncated to the shorter
+/// length, as in [`VariableBaseMSM::msm_unchecked`](super::VariableBaseMSM::msm_unchecked).
+pub fn msm_batch_affine<P: SWCurveConfig>(
+    bases: &[Affine<P>],
+    bigints: &[<P::ScalarField as PrimeField>::BigInt],
+) -> Projective<P> {
+    let size = bases.len().min(bigints.len());
+    if size == 0 {
+        return Projective::zero();
+    }
+    let bases = &bases[..size];
+    let scalars = &bigints[..size];
+
+    let c = if size < 32 {
+        3
+    } else {
+        ln_without_floats(size) + 2
+    };
+
+    let num_bits = P::ScalarField::MODULUS_BIT_SIZE as usize;
+    let digits_count = num_bits.div_ceil(c);
+    #[cfg(feature = "parallel")]
+    let scalar_digits = scalars
+        .into_par_iter()
+        .flat_map_iter(|s| make_digits(s, c, num_bits))
+        .collect::<Vec<_>>();
+    #[cfg(not(feature = "parallel"))]
+    let scalar_digits = scalars
+        .iter()
+        .flat_map(|s| make_digits(s, c, num_bits))
+        .collect::<Vec<_>>();
+
+    let window_sums: Vec<_> = cfg_into_iter!(0..digits_count)
+        .map(|i| {
+            // The digits lie in `[-2^c, 2^c]`, so we need `2^c` buckets,
+            // where the bucket at index `j` holds the points with digit `±(j + 1)`.
+            let mut buckets = vec![Affine::<P>::identity(); 1 << c];
+            let addition = |k: usize| {
+                let base = bases[k];
+                if base.is_zero() {
+                    return None;
+                }
+                match scalar_digits[k * digits_count + i] {
+                    0 => None,
+                    d if d > 0 => Some(((d - 1) as usize, base)),
+                    d => Some(((-d - 1) as usize, -base)),
+                }
+            };
+            let leftovers = accumulate(&mut buckets, size, addition);
+
+            // Additions that could not be batched are accumulated separately,
+            // and only allocated when there are any.
+            let mut overflow = Vec::new();
+            if !leftovers.is_empty() {
+                overflow = vec![Bucket::<P>::ZERO; buckets.len()];
+                for (j, point) in leftovers.into_iter().filter_map(addition) {
+                    overflow[j] += &point;
+                }
+            }
+
+            // Compute sum_{j in 0..num_buckets} (j + 1) * bucket[j]
+            // with running sums, iterating backward over the buckets.
+            let mut running_sum = Bucket::<P>::ZERO;
+            let mut res = Bucket::<P>::ZERO;
+            for (j, bucket) in buckets.iter().enumerate().rev() {
+                running_sum += bucket;
+                if let Some(extra) = overflow.get(j) {
+                    running_sum += extra;
+                }
+                res += &running_sum;
+            }
+            res
+        })
+        .collect();
+
+    // We store the sum for the lowest window.
+    let lowest = Projective::from(window_sums[0]);
+
+    // We're traversing windows from high to low.
+    lowest
+        + window_sums[1..]
+            .iter()
+            .rev()
+            .fold(Projective::zero(), |mut total, sum_i| {
+                total += sum_i;
+                for _ in 0..c {
+                    total.double_in_place();
+                }
+                total
+            })
+}
+
+/// Adds `addition(k)` for each base index `k < size` into the bucket at the
+/// returned index, skipping the bases for which it is `None`, using rounds of
+/// independent affine additions that share a single inversion.
+///
+/// The additions are recomputed from their index in every round, so that
+/// only the indices of conflicting additions are stored, rather than a copy
+/// of the bases for each window.
+///
+/// Returns the indices of the additions that were not performed because the
+/// rounds became too small to batch.
+fn accumulate<P: SWCurveConfig>(
+    buckets: &mut [Affine<P>],
+    size: usize,
+    addition: impl Fn(usize) -> Option<(usize, Affine<P>)> + Copy,
+) -> Vec<usize> {
+    // `scheduled[j] == round` iff bucket `j` already has an addition in the
+    // current round.
+    let mut scheduled = vec![0usize; buckets.len()];
+    let mut batch = Vec::with_capacity(size.min(buckets.len()));
+    let mut denominators = Vec::with_capacity(batch.capacity());
+
+    // The first round goes over all the bases, and each later round over the
+    // bases whose addition conflicted with another one in the previous round.
+    let mut round = 1;
+    let mut pending = schedule(
+        buckets,
+        &mut scheduled,
+        round,
+        (0..size).filter_map(|k| addition(k).map(|a| (k, a))),
+        &mut batch,
+        &mut denominators,
+    );
+    loop {
+        let batch_size = batch.len();
+        add_batch(buckets, &mut batch, &mut denominators);
+        if pending.is_empty() || batch_size < MIN_BATCH_SIZE {
+            return pending;
+        }
+
+        round += 1;
+        pending = schedule(
+            buckets,
+            &mut scheduled,
+            round,
+            pending
+                .into_iter()
+                .filter_map(|k| addition(k).map(|a| (k, a))),
+            &mut batch,
+            &mut denominators,
+        );
+    }
+}
+
+/// Schedules the additions of `round`, given with the index of their base,
+/// into `batch`, together with the denominators of their slopes. Additions
+/// into the identity or into the negation of the point are performed
+/// directly.
+///
+/// Returns the indices of the additions into a bucket that already has an
+/// addition scheduled in this round.
+fn schedule<P: SWCurveConfig>(
+    buckets: &mut [Affine<P>],
+    scheduled: &mut [usize],
+    round: usize,
+    additions: impl Iterator<Item = (usize, (usize, Affine<P>))>,
+    batch: &mut Vec<(usize, Affine<P>)>,
+    denominators: &mut Vec<P::BaseField>,
+) -> Vec<usize> {
+    let mut conflicts = Vec::new();
+    for (k, (j, point)) in additions {
+        let bucket = &mut buckets[j];
+        if scheduled[j] == round {
+            conflicts.push(k);
+        } else if bucket.is_zero() {
+            *bucket = point;
+        } else if bucket.x != point.x {
+            scheduled[j] = round;
+            denominators.push(point.x - bucket.x);
+            batch.push((j, point));
+        } else if bucket.y == point.y && !bucket.y.is_zero() {
+            // Doubling: the denominator of the tangent's slope is `2y`.
+            scheduled[j] = round;
+            denominators.push(bucket.y.double());
+            batch.push((j, point));
+        } else {
+            // `point == -bucket`.
+            *bucket = Affine::identity();
+        }
+    }
+    conflicts
+}
+
+/// Performs the scheduled additions in `batch`, with a single inversion of
+/// all `denominators`, and clears both.
+fn add_batch<P: SWCurveConfig>(
+    buckets: &mut [Affine<P>],
+    batch: &mut Vec<(usize, Affine<P>)>,
+    denominators: &mut Vec<P::BaseField>,
+) {
+    batch_inversion(denominators);
+    for (&(j, point), inverse) in batch.iter().zip(denominators.iter()) {
+        let bucket = &mut buckets[j];
+        let lambda = if bucket.x == point.x {
+            let x_squared = bucket.x.square();
+            (x_squared.double() + x_squared + P::mul_by_a(bucket.x)) * inverse
+        } else {
+            (point.y - bucket.y) * inverse
+        };
+        let x = lambda.square() - bucket.x - point.x;
+        let y = lambda * (bucket.x - x) - bucket.y;
+        *bucket = Affine::new_unchecked(x, y);
+    }
+    batch.clear();
+    denominators.clear();
+}
@@ -14,7 +14,11 @@ use rayon::prelude::*;
 pub mod stream_pippenger;
 pub use stream_pippenger::*;
 
-use super::ScalarMul;
+mod batch_affine;
+pub use batch_affine::msm_batch_affine;
+
+use super::{glv::GLVConfig, ScalarMul};
+use crate::short_weierstrass::{Affine, Projective};
 
 #[cfg(all(
     target_has_atomic = "8",
@@ -76,6 +80,52 @@ pub trait VariableBaseMSM: ScalarMul + for<'a> AddAssign<&'a Self::Bucket> {
             .ok_or_else(|| bases.len().min(scalars.len()))
     }
 
+    /// Computes one multi-scalar multiplication over `bases` for each vector
+    /// in `scalars`.
+    ///
+    /// Rather than running the MSMs one after the other, this processes each
+    /// window once per group of scalar vectors: every base is loaded once per
+    /// window and added to the buckets of each vector in the group. Groups are
+    /// sized so that their buckets stay cache-resident, for which the windows
+    /// are made smaller than those of [`Self::msm`] if there are many vectors.
+    ///
+    /// The `i`-th result is equal to `Self::msm_unchecked(bases, scalars[i])`:
+    /// in particular, each scalar vector is chopped to the length of `bases`
+    /// if it is longer, and vice versa.
+    ///
+    /// # Example
+    /// ```
+    /// use ark_ec::VariableBaseMSM;
+    /// use ark_std::UniformRand;
+    /// use ark_test_curves::bls12_381::{Fr, G1Projective as G};
+    /// use ark_ec::CurveGroup;
+    ///
+    /// let mut rng = ark_std::test_rng();
+    /// let bases = G::normalize_batch(&(0..100).map(|_| G::rand(&mut rng)).collect::<Vec<_>>());
+    /// let columns = (0..5)
+    ///     .map(|_| (0..100).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let commitments = G::msm_batch(&bases, &columns);
+    /// for (commitment, column) in commitments.iter().zip(&columns) {
+    ///     assert_eq!(*commitment, G::msm(&bases, column).unwrap());
+    /// }
+    /// ```
+    fn msm_batch<S: AsRef<[Self::ScalarField]> + Sync>(
+        bases: &[Self::MulBase],
+        scalars: &[S],
+    ) -> Vec<Self> {
+        let bigints = scalars
+            .iter()
+            .map(|s| {
+                cfg_iter!(s.as_ref())
+                    .map(|s| s.into_bigint())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        msm_batch_bigint(bases, &bigints)
+    }
+
     /// Optimized implementation of multi-scalar multiplication.
     fn msm_bigint(
         bases: &[Self::MulBase],
@@ -338,7 +388,7 @@ fn msm_signed<V: VariableBaseMSM>(
     // Handle the rest of the scalars.
     let (bf, sf) = large_value_unzip(&bigints, |i| (bases[i], scalars[i]));
     if V::NEGATION_IS_CHEAP {
-        add_result += msm_bigint_wnaf::<V>(&bf, &sf);
+        add_result += msm_bigint_wnaf::<V>(&bf, &sf, V::ScalarField::MODULUS_BIT_SIZE as usize);
     } else {
         add_result += msm_bigint::<V>(&bf, &sf);
     }
@@ -346,6 +396,40 @@ fn msm_signed<V: VariableBaseMSM>(
     (add_result - sub_result).into()
 }
 
+/// Computes a multi-scalar multiplication on a curve with an efficient
+/// endomorphism `phi`, using the GLV method.
+///
+/// Each scalar `k` is decomposed as `k = k1 + lambda * k2` with
+/// [`GLVConfig::scalar_decomposition`], where `k1` and `k2` have about half
+/// the bit length of `k`, and each base `P` is paired with `phi(P) = lambda * P`.
+/// The signs of `k1` and `k2` are moved to the bases, and the resulting MSM of
+/// size `2n` is computed with the WNAF-based Pippenger algorithm over only as
+/// many windows as the half-width scalars need.
+///
+/// If the slices have different lengths, they are truncated to the shorter
+/// length, as in [`VariableBaseMSM::msm_unchecked`].
+pub fn msm_glv<P: GLVConfig>(bases: &[Affine<P>], scalars: &[P::ScalarField]) -> Projective<P> {
+    let size = bases.len().min(scalars.len());
+    let decomposed = cfg_iter!(bases[..size])
+        .zip(&scalars[..size])
+        .map(|(base, scalar)| {
+            let ((sgn_k1, k1), (sgn_k2, k2)) = P::scalar_decomposition(*scalar);
+            let base1 = if sgn_k1 { *base } else { -*base };
+            let base2 = P::endomorphism_affine(base);
+            let base2 = if sgn_k2 { base2 } else { -base2 };
+            [(base1, k1.into_bigint()), (base2, k2.into_bigint())]
+        })
+        .collect::<Vec<_>>();
+    let (bases, scalars): (Vec<_>, Vec<_>) = decomposed
+        .into_iter()
+        .flatten()
+        .filter(|(_, scalar)| !scalar.is_zero())
+        .unzip();
+
+    let num_bits = scalars.iter().map(|s| s.num_bits()).max().unwrap_or(0);
+    msm_bigint_wnaf::<Projective<P>>(&bases, &scalars, num_bits as usize)
+}
+
 fn preamble<A, B>(bases: &mut &[A], scalars: &mut &[B]) -> Option<usize> {
     let size = bases.len().min(scalars.len());
     if size == 0 {
@@ -433,10 +517,12 @@ fn msm_u64<V: VariableBaseMSM>(mut bases: &[V::MulBase], mut scalars: &[u64]) ->
         .sum()
 }
 
-// Compute msm using windowed non-adjacent form
+// Compute msm using windowed non-adjacent form, for scalars of at most
+// `num_bits` bits
 fn msm_bigint_wnaf_parallel<V: VariableBaseMSM>(
     bases: &[V::MulBase],
     bigints: &[<V::ScalarField as PrimeField>::BigInt],
+    num_bits: usize,
 ) -> V {
     let size = bases.len().min(bigints.len());
     let scalars = &bigints[..size];
@@ -448,7 +534,6 @@ fn msm_bigint_wnaf_parallel<V: VariableBaseMSM>(
         super::ln_without_floats(size) + 2
     };
 
-    let num_bits = V::ScalarField::MODULUS_BIT_SIZE as usize;
     let digits_count = num_bits.div_ceil(c);
     #[cfg(feature = "parallel")]
     let scalar_digits = scalars
@@ -505,13 +590,15 @@ fn msm_bigint_wnaf_parallel<V: VariableBaseMSM>(
 #[cfg(feature = "parallel")]
 const THREADS_PER_CHUNK: usize = 2;
 
-/// Computes an MSM using the windowed non-adjacent form (WNAF) algorithm.
+/// Computes an MSM using the windowed non-adjacent form (WNAF) algorithm,
+/// for scalars of at most `num_bits` bits.
 /// To improve parallelism, when number of threads is at least 2, this
 /// function will split the input into enough chunks so that each chunk
 /// can be processed with 2 threads.
 fn msm_bigint_wnaf<V: VariableBaseMSM>(
     mut bases: &[V::MulBase],
     mut scalars: &[<V::ScalarField as PrimeField>::BigInt],
+    num_bits: usize,
 ) -> V {
     let size = bases.len().min(scalars.len());
     if size == 0 {
@@ -547,10 +634,10 @@ fn msm_bigint_wnaf<V: VariableBaseMSM>(
                 .num_threads(THREADS_PER_CHUNK.min(rayon::current_num_threads()))
                 .build()
                 .unwrap()
-                .install(|| msm_bigint_wnaf_parallel::<V>(bases, scalars));
+                .install(|| msm_bigint_wnaf_parallel::<V>(bases, scalars, num_bits));
 
             #[cfg(not(feature = "parallel"))]
-            let result = msm_bigint_wnaf_parallel::<V>(bases, scalars);
+            let result = msm_bigint_wnaf_parallel::<V>(bases, scalars, num_bits);
 
             result
         })
@@ -654,6 +741,125 @@ fn msm_bigint<V: VariableBaseMSM>(
             })
 }
 
+/// Computes one multi-scalar multiplication over `bases` for each vector of
+/// `scalars`, sharing the traversal of the bases between all of them.
+fn msm_batch_bigint<V: VariableBaseMSM>(
+    bases: &[V::MulBase],
+    scalars: &[Vec<<V::ScalarField as PrimeField>::BigInt>],
+) -> Vec<V> {
+    let size = scalars
+        .iter()
+        .map(Vec::len)
+        .max()
+        .unwrap_or(0)
+        .min(bases.len());
+    if size == 0 {
+        return vec![V::zero(); scalars.len()];
+    }
+    let bases = &bases[..size];
+
+    let c = if size < 32 {
+        3
+    } else {
+        super::ln_without_floats(size) + 2
+    };
+    // Vectors are processed in groups whose buckets together stay small enough
+    // to remain cache-resident; larger groups lose more to cache misses than
+    // they save on the shared traversal of the bases. To fit more vectors in a
+    // group, the window size is reduced, but not below `MSM_BATCH_MIN_WINDOW`.
+    let log_num_vectors = ark_std::log2(scalars.len()) as usize;
+    let c = c.min(
+        MSM_BATCH_LOG_MAX_BUCKETS
+            .saturating_sub(log_num_vectors)
+            .max(MSM_BATCH_MIN_WINDOW),
+    );
+    let group_size = ((1 << MSM_BATCH_LOG_MAX_BUCKETS) >> c).max(1);
+    let results: Vec<Vec<V>> = ark_std::cfg_chunks!(scalars, group_size)
+        .map(|group| match group {
+            // A single vector gains nothing from batching, and `msm_bigint`
+            // handles small scalars and parallelism better.
+            [scalars] => vec![V::msm_bigint(bases, scalars)],
+            _ => msm_batch_group::<V>(bases, group, c),
+        })
+        .collect();
+    results.into_iter().flatten().collect()
+}
+
+/// The base-2 logarithm of the maximum number of buckets per window shared by
+/// a group of scalar vectors in [`msm_batch_bigint`].
+const MSM_BATCH_LOG_MAX_BUCKETS: usize = 12;
+
+/// The smallest window size to which [`msm_batch_bigint`] reduces its windows
+/// in order to fit more scalar vectors into a group.
+const MSM_BATCH_MIN_WINDOW: usize = 8;
+
+/// Computes the multi-scalar multiplications of a group of scalar vectors,
+/// using `2^c` buckets per vector and window.
+fn msm_batch_group<V: VariableBaseMSM>(
+    bases: &[V::MulBase],
+    scalars: &[Vec<<V::ScalarField as PrimeField>::BigInt>],
+    c: usize,
+) -> Vec<V> {
+    let num_bits = V::ScalarField::MODULUS_BIT_SIZE as usize;
+    let digits_count = num_bits.div_ceil(c);
+
+    // For each window, the sums of the windows of every scalar vector.
+    let window_sums: Vec<Vec<_>> = ark_std::cfg_into_iter!(0..digits_count)
+        .map(|w| {
+            let is_last = w == digits_count - 1;
+            // The buckets of the `i`-th scalar vector are `buckets[i << c..(i + 1) << c]`,
+            // where the bucket at index `j` holds the bases with digit `±(j + 1)`.
+            let mut buckets = vec![V::ZERO_BUCKET; scalars.len() << c];
+            for (j, base) in bases.iter().enumerate() {
+                for (i, scalars) in scalars.iter().enumerate() {
+                    let Some(scalar) = scalars.get(j) else {
+                        continue;
+                    };
+                    let digit = if V::NEGATION_IS_CHEAP {
+                        signed_digit(scalar, w, c, is_last)
+                    } else {
+                        window_bits(scalar, w * c, c) as i64
+                    };
+                    use ark_std::cmp::Ordering;
+                    match 0.cmp(&digit) {
+                        Ordering::Less => buckets[(i << c) + (digit - 1) as usize] += base,
+                        Ordering::Greater => buckets[(i << c) + (-digit - 1) as usize] -= base,
+                        Ordering::Equal => (),
+                    }
+                }
+            }
+
+            buckets
+                .chunks(1 << c)
+                .map(|buckets| {
+                    // `running_sum` = sum_{k in j..num_buckets} bucket[k],
+                    // where we iterate backward from j = num_buckets to 0.
+                    let mut running_sum = V::ZERO_BUCKET;
+                    let mut res = V::ZERO_BUCKET;
+                    buckets.iter().rev().for_each(|b| {
+                        running_sum += b;
+                        res += &running_sum;
+                    });
+                    res
+                })
+                .collect()
+        })
+        .collect();
+
+    // We're traversing windows from high to low.
+    (0..scalars.len())
+        .map(|i| {
+            window_sums.iter().rev().fold(V::zero(), |mut total, sums| {
+                for _ in 0..c {
+                    total.double_in_place();
+                }
+                total += &sums[i];
+                total
+            })
+        })
+        .collect()
+}
+
 fn msm_serial<V: VariableBaseMSM>(
     bases: &[V::MulBase],
     scalars: &[impl Into<u64> + Copy + Send + Sync],
@@ -751,7 +957,11 @@ fn msm_serial<V: VariableBaseMSM>(
 }
 
 // From: https://github.com/arkworks-rs/gemini/blob/main/src/kzg/msm/variable_base.rs#L20
-fn make_digits(a: &impl BigInteger, w: usize, num_bits: usize) -> impl Iterator<Item = i64> + '_ {
+pub(super) fn make_digits(
+    a: &impl BigInteger,
+    w: usize,
+    num_bits: usize,
+) -> impl Iterator<Item = i64> + '_ {
     let scalar = a.as_ref();
     let radix: u64 = 1 << w;
     let window_mask: u64 = radix - 1;
@@ -792,3 +1002,42 @@ fn make_digits(a: &impl BigInteger, w: usize, num_bits: usize) -> impl Iterator<
         digit
     })
 }
+
+/// Returns the `w` bits of `a` starting at bit `offset`.
+#[inline]
+fn window_bits(a: &impl BigInteger, offset: usize, w: usize) -> u64 {
+    let limbs = a.as_ref();
+    let (index, shift) = (offset / 64, offset % 64);
+    let Some(&low) = limbs.get(index) else {
+        return 0;
+    };
+    let mut bits = low >> shift;
+    if shift + w > 64 {
+        if let Some(&high) = limbs.get(index + 1) {
+            bits |= high << (64 - shift);
+        }
+    }
+    bits & ((1 << w) - 1)
+}
+
+/// Returns the `i`-th digit of a signed radix-`2^w` representation of `a`,
+/// without computing the lower digits.
+///
+/// A window whose top bit is set is recentered by subtracting `2^w`, and the
+/// resulting carry is the top bit of the previous window, so the digits lie in
+/// `[-2^{w-1}, 2^{w-1}]`. The last digit is not recentered, and lies in `[0, 2^w]`.
+#[inline]
+fn signed_digit(a: &impl BigInteger, i: usize, w: usize, is_last: bool) -> i64 {
+    let offset = i * w;
+    let carry = if offset == 0 {
+        0
+    } else {
+        window_bits(a, offset - 1, 1)
+    };
+    let bits = window_bits(a, offset, w);
+    let mut digit = (bits + carry) as i64;
+    if !is_last && bits >> (w - 1) == 1 {
+        digit -= 1 << w;
+    }
+    digit
+}
@@ -1,5 +1,6 @@
 //! A space-efficient implementation of Pippenger's algorithm.
 use ark_ff::{PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, Compress, Read, SerializationError, Valid, Validate};
 
 use ark_std::{borrow::Borrow, vec::*};
 use hashbrown::HashMap;
@@ -126,3 +127,115 @@ impl<G: VariableBaseMSM> HashMapPippenger<G> {
         self.result
     }
 }
+
+/// Computes the multi-scalar multiplication of `bigints` with bases read from
+/// `reader`, without loading all the bases in memory.
+///
+/// The bases are expected to be serialized back to back in `reader` with the
+/// given `compress` mode, one for each scalar; a serialized `Vec` of bases
+/// starts with its length, which must be consumed beforehand. Bases are read
+/// and accumulated in chunks of `chunk_size`: under the `parallel` feature,
+/// the next chunk is deserialized while the current one is validated (if
+/// `validate` is [`Validate::Yes`]) and accumulated.
+///
+/// # Example
+/// ```
+/// use ark_ec::{scalar_mul::variable_base::msm_from_reader, CurveGroup, VariableBaseMSM};
+/// use ark_ff::PrimeField;
+/// use ark_serialize::{CanonicalSerialize, Compress, Validate};
+/// use ark_std::UniformRand;
+/// use ark_test_curves::bls12_381::{Fr, G1Projective as G};
+///
+/// let mut rng = ark_std::test_rng();
+/// let bases = G::normalize_batch(&(0..100).map(|_| G::rand(&mut rng)).collect::<Vec<_>>());
+/// let scalars = (0..100).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+///
+/// let mut srs = Vec::new();
+/// for base in &bases {
+///     base.serialize_compressed(&mut srs).unwrap();
+/// }
+///
+/// let bigints = scalars.iter().map(|s| s.into_bigint()).collect::<Vec<_>>();
+/// let result: G =
+///     msm_from_reader(&srs[..], &bigints, Compress::Yes, Validate::Yes, 32).unwrap();
+/// assert_eq!(result, G::msm(&bases, &scalars).unwrap());
+/// ```
+pub fn msm_from_reader<G, R>(
+    mut reader: R,
+    bigints: &[<G::ScalarField as PrimeField>::BigInt],
+    compress: Compress,
+    validate: Validate,
+    chunk_size: usize,
+) -> Result<G, SerializationError>
+where
+    G: VariableBaseMSM,
+    G::MulBase: CanonicalDeserialize,
+    R: Read + Send,
+{
+    let mut chunks = bigints.chunks(chunk_size.max(1));
+    let mut result = G::zero();
+    let Some(mut scalars) = chunks.next() else {
+        return Ok(result);
+    };
+    let mut bases = read_bases::<G, _>(&mut reader, scalars.len(), compress)?;
+    for next_scalars in chunks {
+        let (partial, next_bases) = join(
+            || accumulate_bases::<G>(&bases, scalars, validate),
+            || read_bases::<G, _>(&mut reader, next_scalars.len(), compress),
+        );
+        result += partial?;
+        bases = next_bases?;
+        scalars = next_scalars;
+    }
+    result += accumulate_bases::<G>(&bases, scalars, validate)?;
+    Ok(result)
+}
+
+/// Deserializes `len` bases from `reader`, leaving validation to
+/// [`accumulate_bases`].
+fn read_bases<G: VariableBaseMSM, R: Read>(
+    reader: &mut R,
+    len: usize,
+    compress: Compress,
+) -> Result<Vec<G::MulBase>, SerializationError>
+where
+    G::MulBase: CanonicalDeserialize,
+{
+    (0..len)
+        .map(|_| G::MulBase::deserialize_with_mode(&mut *reader, compress, Validate::No))
+        .collect()
+}
+
+fn accumulate_bases<G: VariableBaseMSM>(
+    bases: &[G::MulBase],
+    bigints: &[<G::ScalarField as PrimeField>::BigInt],
+    validate: Validate,
+) -> Result<G, SerializationError>
+where
+    G::MulBase: CanonicalDeserialize,
+{
+    if validate == Validate::Yes {
+        G::MulBase::batch_check(bases.iter())?;
+    }
+    Ok(G::msm_bigint(bases, bigints))
+}
+
+#[cfg(feature = "parallel")]
+fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    rayon::join(a, b)
+}
+
+#[cfg(not(feature = "parallel"))]
+fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA,
+    B: FnOnce() -> RB,
+{
+    (a(), b())
+}
@@ -24,6 +24,10 @@ use ark_std::{
     Zero,
 };
 use num_bigint::BigUint;
+#[cfg(feature = "subtle")]
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
 use zeroize::Zeroize;
 
 #[macro_use]
@@ -889,6 +893,44 @@ impl<const N: usize> Not for BigInt<N> {
     }
 }
 
+#[cfg(feature = "subtle")]
+impl<const N: usize> ConstantTimeEq for BigInt<N> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0[..].ct_eq(&other.0[..])
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<const N: usize> ConditionallySelectable for BigInt<N> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut result = Self::zero();
+        for i in 0..N {
+            result.0[i] = u64::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+        result
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<const N: usize> ConstantTimeGreater for BigInt<N> {
+    /// Returns `1` if `self > other`, by checking whether `other - self`
+    /// borrows.
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> Choice {
+        let mut borrow = 0;
+        for i in 0..N {
+            let mut tmp = other.0[i];
+            borrow = arithmetic::sbb(&mut tmp, self.0[i], borrow);
+        }
+        Choice::from(borrow as u8)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<const N: usize> ConstantTimeLess for BigInt<N> {}
+
 /// Compute the signed modulo operation on a u64 representation, returning the result.
 /// If n % modulus > modulus / 2, return modulus - n
 /// # Example
@@ -0,0 +1,46 @@
+//! Constant-time operations on field elements, built on the primitives of
+//! the [`subtle`](https://docs.rs/subtle) crate.
+use subtle::{
+    Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq, ConstantTimeLess,
+    CtOption,
+};
+
+use crate::Field;
+
+/// A field whose elements support constant-time equality, selection, negation
+/// and comparison, together with constant-time square roots and inversion.
+///
+/// This is implemented for `Fp` with a [`MontBackend`](crate::MontBackend),
+/// and for `QuadExtField` and `CubicExtField` whenever their base field
+/// implements it.
+pub trait CtField:
+    Field + ConstantTimeEq + ConditionallySelectable + ConditionallyNegatable + ConstantTimeLess
+{
+    /// Returns the square root of self, if it exists, in time that does not
+    /// depend on the value of `self`.
+    ///
+    /// The default implementation uses [`Field::SQRT_PRECOMP`], and returns
+    /// no square root if it is `None`.
+    #[must_use]
+    fn ct_sqrt(&self) -> CtOption<Self> {
+        match Self::SQRT_PRECOMP {
+            Some(tv) => tv.ct_sqrt(self),
+            None => CtOption::new(Self::ZERO, Choice::from(0)),
+        }
+    }
+
+    /// Computes the multiplicative inverse of `self` if `self` is nonzero,
+    /// in time that does not depend on the value of `self`.
+    ///
+    /// The default implementation is built on [`Field::inverse_secret`],
+    /// which implementors must ensure runs in constant time. Whether `self`
+    /// is zero is returned as a [`subtle::Choice`] too.
+    #[must_use]
+    fn ct_inverse(&self) -> CtOption<Self> {
+        let is_zero = self.ct_eq(&Self::ZERO);
+        // Invert one instead of zero, so that `inverse_secret` always succeeds.
+        let input = Self::conditional_select(self, &Self::ONE, is_zero);
+        let inverse = input.inverse_secret().unwrap_or(Self::ONE);
+        CtOption::new(inverse, !is_zero)
+    }
+}
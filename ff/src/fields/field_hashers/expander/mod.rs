@@ -34,7 +34,6 @@ impl DST {
         DST(array)
     }
 
-    #[allow(dead_code)]
     pub fn new_xof<H: ExtendableOutput + Default>(dst: &[u8], k: usize) -> DST {
         let array = if dst.len() > MAX_DST_LENGTH {
             let mut long = H::default();
@@ -58,7 +57,6 @@ impl DST {
     }
 }
 
-#[allow(dead_code)]
 pub(super) struct ExpanderXof<H: ExtendableOutput + Clone + Default> {
     pub(super) xofer: PhantomData<H>,
     pub(super) dst: Vec<u8>,
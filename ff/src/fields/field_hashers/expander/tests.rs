@@ -1,5 +1,6 @@
 use libtest_mimic::{run, Arguments, Failed, Trial};
 
+use digest::ExtendableOutput;
 use sha2::{Sha256, Sha384, Sha512};
 use sha3::{Shake128, Shake256};
 use std::{
@@ -9,6 +10,60 @@ use std::{
 };
 
 use super::{Expander, ExpanderXmd, ExpanderXof};
+use crate::{
+    field_hashers::{HashToField, XofFieldHasher},
+    BigInt, Fp, Fp256, Fp384, MontBackend, MontConfig, PrimeField,
+};
+
+// The `MontConfig` derive expands to `ark_ff::` paths, which do not resolve
+// inside this crate, so the test fields are configured by hand. Both moduli
+// have a two-adicity of one, so `-1` is the two-adic root of unity.
+
+/// The base field of BLS12-381.
+struct Bls12_381FqConfig;
+
+impl MontConfig<6> for Bls12_381FqConfig {
+    const MODULUS: BigInt<6> = BigInt::new([
+        0xb9feffffffffaaab,
+        0x1eabfffeb153ffff,
+        0x6730d2a0f6b0f624,
+        0x64774b84f38512bf,
+        0x4b1ba7b6434bacd7,
+        0x1a0111ea397fe69a,
+    ]);
+    const GENERATOR: Fp384<MontBackend<Self, 6>> = Fp::new(BigInt::new([2, 0, 0, 0, 0, 0]));
+    const TWO_ADIC_ROOT_OF_UNITY: Fp384<MontBackend<Self, 6>> = Fp::new(BigInt::new([
+        0xb9feffffffffaaaa,
+        0x1eabfffeb153ffff,
+        0x6730d2a0f6b0f624,
+        0x64774b84f38512bf,
+        0x4b1ba7b6434bacd7,
+        0x1a0111ea397fe69a,
+    ]));
+}
+
+type Bls12_381Fq = Fp384<MontBackend<Bls12_381FqConfig, 6>>;
+
+/// The base field of secp256k1.
+struct Secp256k1FqConfig;
+
+impl MontConfig<4> for Secp256k1FqConfig {
+    const MODULUS: BigInt<4> = BigInt::new([
+        0xfffffffefffffc2f,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ]);
+    const GENERATOR: Fp256<MontBackend<Self, 4>> = Fp::new(BigInt::new([3, 0, 0, 0]));
+    const TWO_ADIC_ROOT_OF_UNITY: Fp256<MontBackend<Self, 4>> = Fp::new(BigInt::new([
+        0xfffffffefffffc2e,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ]));
+}
+
+type Secp256k1Fq = Fp256<MontBackend<Secp256k1FqConfig, 4>>;
 
 #[derive(Debug, serde_derive::Serialize, serde_derive::Deserialize)]
 pub struct ExpanderVector {
@@ -50,6 +105,42 @@ fn expander() {
     run(&args, tests).exit_if_failed();
 }
 
+#[test]
+fn xof_field_hasher() {
+    for filename in read_dir("./src/fields/field_hashers/expander/testdata").unwrap() {
+        let file = File::open(filename.unwrap().path()).unwrap();
+        let data: ExpanderVector = serde_json::from_reader(BufReader::new(file)).unwrap();
+        // Pick fields for which two elements take up the 0x80 bytes of the
+        // longer vectors, so that these can be checked against the field
+        // hasher directly.
+        match data.hash.as_str() {
+            "SHAKE128" => check_xof_field_hasher::<Bls12_381Fq, Shake128, 128>(&data),
+            "SHAKE256" => check_xof_field_hasher::<Secp256k1Fq, Shake256, 256>(&data),
+            _ => continue,
+        }
+    }
+}
+
+fn check_xof_field_hasher<
+    F: PrimeField,
+    H: ExtendableOutput + Default + Clone,
+    const SEC_PARAM: usize,
+>(
+    data: &ExpanderVector,
+) {
+    assert_eq!(data.k, SEC_PARAM);
+    let hasher = <XofFieldHasher<H, SEC_PARAM> as HashToField<F>>::new(data.dst.as_bytes());
+    for v in data.vectors.iter().filter(|v| v.len_in_bytes == "0x80") {
+        let got: [F; 2] = hasher.hash_to_field(v.msg.as_bytes());
+        let uniform_bytes = hex::decode(&v.uniform_bytes).unwrap();
+        let want = [
+            F::from_be_bytes_mod_order(&uniform_bytes[..64]),
+            F::from_be_bytes_mod_order(&uniform_bytes[64..]),
+        ];
+        assert_eq!(got, want, "{}: {}", data.hash, v.msg);
+    }
+}
+
 #[derive(Copy, Clone)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum ExpID {
@@ -4,10 +4,10 @@ use core::marker::PhantomData;
 
 use crate::{Field, PrimeField};
 
-use digest::{FixedOutputReset, XofReader};
+use digest::{crypto_common::BlockSizeUser, ExtendableOutput, FixedOutputReset, XofReader};
 use expander::Expander;
 
-use self::expander::ExpanderXmd;
+use self::expander::{ExpanderXmd, ExpanderXof};
 
 /// Trait for hashing messages to field elements.
 pub trait HashToField<F: Field>: Sized {
@@ -39,13 +39,16 @@ pub trait HashToField<F: Field>: Sized {
 ///
 /// assert_eq!(field_elements.len(), 2);
 /// ```
-pub struct DefaultFieldHasher<H: FixedOutputReset + Default + Clone, const SEC_PARAM: usize = 128> {
+pub struct DefaultFieldHasher<
+    H: FixedOutputReset + BlockSizeUser + Default + Clone,
+    const SEC_PARAM: usize = 128,
+> {
     expander: ExpanderXmd<H>,
     len_per_base_elem: usize,
 }
 
-impl<F: Field, H: FixedOutputReset + Default + Clone, const SEC_PARAM: usize> HashToField<F>
-    for DefaultFieldHasher<H, SEC_PARAM>
+impl<F: Field, H: FixedOutputReset + BlockSizeUser + Default + Clone, const SEC_PARAM: usize>
+    HashToField<F> for DefaultFieldHasher<H, SEC_PARAM>
 {
     fn new(dst: &[u8]) -> Self {
         // The final output of `hash_to_field` will be an array of field
@@ -55,7 +58,7 @@ impl<F: Field, H: FixedOutputReset + Default + Clone, const SEC_PARAM: usize> Ha
         let expander = ExpanderXmd {
             hasher: PhantomData,
             dst: dst.to_vec(),
-            block_size: len_per_base_elem,
+            block_size: H::block_size(),
         };
 
         DefaultFieldHasher {
@@ -65,26 +68,84 @@ impl<F: Field, H: FixedOutputReset + Default + Clone, const SEC_PARAM: usize> Ha
     }
 
     fn hash_to_field<const N: usize>(&self, message: &[u8]) -> [F; N] {
-        let m = F::extension_degree() as usize;
-
-        // The user requests `N` of elements of F_p^m to output per input msg,
-        // each field element comprising `m` BasePrimeField elements.
-        let len_in_bytes = N * m * self.len_per_base_elem;
-        let uniform_bytes = self.expander.expand(message, len_in_bytes);
-
-        let cb = |i| {
-            let base_prime_field_elem = |j| {
-                let elm_offset = self.len_per_base_elem * (j + i * m);
-                F::BasePrimeField::from_be_bytes_mod_order(
-                    &uniform_bytes[elm_offset..][..self.len_per_base_elem],
-                )
-            };
-            F::from_base_prime_field_elems((0..m).map(base_prime_field_elem)).unwrap()
+        expand_to_field(&self.expander, self.len_per_base_elem, message)
+    }
+}
+
+/// This field hasher constructs a Hash-To-Field based on an extendable-output
+/// function, like SHAKE128 or SHAKE256, using `expand_message_xof`.
+///
+/// The implementation aims to follow the specification in
+/// [RFC 9380, Section 5.3.2](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3.2).
+/// `SEC_PARAM` is the target security level `k` of the suite, and is also used
+/// to shorten domain separation tags longer than 255 bytes.
+///
+/// # Examples
+///
+/// ```
+/// use ark_ff::fields::field_hashers::{HashToField, XofFieldHasher};
+/// use ark_test_curves::bls12_381::Fq;
+/// use sha3::Shake128;
+///
+/// let hasher = <XofFieldHasher<Shake128> as HashToField<Fq>>::new(&[1, 2, 3]);
+/// let field_elements: [Fq; 2] = hasher.hash_to_field(b"Hello, World!");
+///
+/// assert_eq!(field_elements.len(), 2);
+/// ```
+pub struct XofFieldHasher<H: ExtendableOutput + Default + Clone, const SEC_PARAM: usize = 128> {
+    expander: ExpanderXof<H>,
+    len_per_base_elem: usize,
+}
+
+impl<F: Field, H: ExtendableOutput + Default + Clone, const SEC_PARAM: usize> HashToField<F>
+    for XofFieldHasher<H, SEC_PARAM>
+{
+    fn new(dst: &[u8]) -> Self {
+        let len_per_base_elem = get_len_per_elem::<F, SEC_PARAM>();
+
+        let expander = ExpanderXof {
+            xofer: PhantomData,
+            dst: dst.to_vec(),
+            k: SEC_PARAM,
         };
-        ark_std::array::from_fn(cb)
+
+        XofFieldHasher {
+            expander,
+            len_per_base_elem,
+        }
+    }
+
+    fn hash_to_field<const N: usize>(&self, message: &[u8]) -> [F; N] {
+        expand_to_field(&self.expander, self.len_per_base_elem, message)
     }
 }
 
+/// Expands `message` with `expander`, and reduces each consecutive chunk of
+/// `len_per_base_elem` bytes to an element of `F::BasePrimeField`.
+fn expand_to_field<F: Field, E: Expander, const N: usize>(
+    expander: &E,
+    len_per_base_elem: usize,
+    message: &[u8],
+) -> [F; N] {
+    let m = F::extension_degree() as usize;
+
+    // The user requests `N` of elements of F_p^m to output per input msg,
+    // each field element comprising `m` BasePrimeField elements.
+    let len_in_bytes = N * m * len_per_base_elem;
+    let uniform_bytes = expander.expand(message, len_in_bytes);
+
+    let cb = |i| {
+        let base_prime_field_elem = |j| {
+            let elm_offset = len_per_base_elem * (j + i * m);
+            F::BasePrimeField::from_be_bytes_mod_order(
+                &uniform_bytes[elm_offset..][..len_per_base_elem],
+            )
+        };
+        F::from_base_prime_field_elems((0..m).map(base_prime_field_elem)).unwrap()
+    };
+    ark_std::array::from_fn(cb)
+}
+
 pub fn hash_to_field<F: Field, H: XofReader, const SEC_PARAM: usize>(h: &mut H) -> F {
     // The final output of `hash_to_field` will be an array of field
     // elements from F::BaseField, each of size `len_per_elem`.
@@ -38,6 +38,11 @@ pub use cyclotomic::*;
 mod sqrt;
 pub use sqrt::*;
 
+#[cfg(feature = "subtle")]
+mod constant_time;
+#[cfg(feature = "subtle")]
+pub use constant_time::*;
+
 #[cfg(feature = "parallel")]
 use ark_std::cmp::max;
 #[cfg(feature = "parallel")]
@@ -293,6 +298,26 @@ pub trait Field:
     /// `self` to `self.inverse().unwrap()`.
     fn inverse_in_place(&mut self) -> Option<&mut Self>;
 
+    /// Computes the multiplicative inverse of `self` if `self` is nonzero,
+    /// using a constant-time algorithm if the field provides one.
+    ///
+    /// The result always agrees with [`Field::inverse`]. `Fp` inverts in time
+    /// that does not depend on the value of `self`, using the safegcd
+    /// algorithm, as does every extension field built on top of it, so that
+    /// only whether `self` is zero is revealed by the returned `Option`.
+    ///
+    /// # Security
+    ///
+    /// The default implementation just calls [`Field::inverse`], and is
+    /// therefore *not* constant time. Fields implemented outside this crate
+    /// must override it with a constant-time inversion before it is used on
+    /// secret values, and before implementing `CtField`, whose `ct_inverse`
+    /// relies on it.
+    #[must_use]
+    fn inverse_secret(&self) -> Option<Self> {
+        self.inverse()
+    }
+
     /// Returns `sum([a_i * b_i])`.
     #[inline]
     fn sum_of_products<const T: usize>(a: &[Self; T], b: &[Self; T]) -> Self {
@@ -1,3 +1,5 @@
+#[cfg(feature = "subtle")]
+use crate::CtField;
 use crate::{
     fields::{Field, PrimeField},
     AdditiveGroup, FftField, LegendreSymbol, One, SqrtPrecomputation, ToConstraintField,
@@ -19,6 +21,10 @@ use ark_std::{
     },
     vec::*,
 };
+#[cfg(feature = "subtle")]
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
 use zeroize::Zeroize;
 
 /// Defines a Cubic extension field from a cubic non-residue.
@@ -322,6 +328,33 @@ impl<P: CubicExtConfig> Field for CubicExtField<P> {
         })
     }
 
+    fn inverse_secret(&self) -> Option<Self> {
+        // Same as `inverse`, but without the early exit on zero, and
+        // inverting the norm in constant time.
+        let t0 = self.c0.square();
+        let t1 = self.c1.square();
+        let t2 = self.c2.square();
+        let t3 = self.c0 * &self.c1;
+        let t4 = self.c0 * &self.c2;
+        let t5 = self.c1 * &self.c2;
+        let n5 = P::mul_base_field_by_nonresidue(t5);
+
+        let s0 = t0 - &n5;
+        let s1 = P::mul_base_field_by_nonresidue(t2) - &t3;
+        let s2 = t1 - &t4;
+
+        let a1 = self.c2 * &s1;
+        let a2 = self.c1 * &s2;
+        let a3 = P::mul_base_field_by_nonresidue(a1 + &a2);
+
+        (self.c0 * &s0 + &a3).inverse_secret().map(|t6| {
+            let c0 = t6 * &s0;
+            let c1 = t6 * &s1;
+            let c2 = t6 * &s2;
+            Self::new(c0, c1, c2)
+        })
+    }
+
     fn frobenius_map_in_place(&mut self, power: usize) {
         self.c0.frobenius_map_in_place(power);
         self.c1.frobenius_map_in_place(power);
@@ -357,6 +390,54 @@ impl<P: CubicExtConfig> PartialOrd for CubicExtField<P> {
     }
 }
 
+#[cfg(feature = "subtle")]
+impl<P: CubicExtConfig> ConstantTimeEq for CubicExtField<P>
+where
+    P::BaseField: CtField,
+{
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1) & self.c2.ct_eq(&other.c2)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: CubicExtConfig> ConditionallySelectable for CubicExtField<P>
+where
+    P::BaseField: CtField,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.c0, &b.c0, choice),
+            P::BaseField::conditional_select(&a.c1, &b.c1, choice),
+            P::BaseField::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+}
+
+/// Compares elements lexicographically, consistently with the `Ord`
+/// implementation.
+#[cfg(feature = "subtle")]
+impl<P: CubicExtConfig> ConstantTimeGreater for CubicExtField<P>
+where
+    P::BaseField: CtField,
+{
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> Choice {
+        let c2_eq = self.c2.ct_eq(&other.c2);
+        self.c2.ct_gt(&other.c2)
+            | (c2_eq & self.c1.ct_gt(&other.c1))
+            | (c2_eq & self.c1.ct_eq(&other.c1) & self.c0.ct_gt(&other.c0))
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: CubicExtConfig> ConstantTimeLess for CubicExtField<P> where P::BaseField: CtField {}
+
+#[cfg(feature = "subtle")]
+impl<P: CubicExtConfig> CtField for CubicExtField<P> where P::BaseField: CtField {}
+
 impl<P: CubicExtConfig> Zeroize for CubicExtField<P> {
     // The phantom data does not contain element-specific data
     // and thus does not need to be zeroized.
@@ -475,6 +556,14 @@ impl<P: CubicExtConfig> Neg for CubicExtField<P> {
     }
 }
 
+impl<P: CubicExtConfig> Neg for &CubicExtField<P> {
+    type Output = CubicExtField<P>;
+    #[inline]
+    fn neg(self) -> CubicExtField<P> {
+        -*self
+    }
+}
+
 impl<P: CubicExtConfig> Distribution<CubicExtField<P>> for Standard {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CubicExtField<P> {
@@ -16,11 +16,17 @@ use ark_std::{
 };
 use core::iter;
 use itertools::Itertools;
+#[cfg(feature = "subtle")]
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
 
 #[macro_use]
 mod montgomery_backend;
 pub use montgomery_backend::*;
 
+mod safegcd;
+
 /// A trait that specifies the configuration of a prime field.
 /// Also specifies how to perform arithmetic on field elements.
 pub trait FpConfig<const N: usize>: Send + Sync + 'static + Sized {
@@ -93,6 +99,9 @@ pub trait FpConfig<const N: usize>: Send + Sync + 'static + Sized {
     /// Compute a^{-1} if `a` is not zero.
     fn inverse(a: &Fp<Self, N>) -> Option<Fp<Self, N>>;
 
+    /// Compute a^{-1} if `a` is not zero, in time independent of `a`.
+    fn inverse_secret(a: &Fp<Self, N>) -> Option<Fp<Self, N>>;
+
     /// Construct a field element from an integer in the range
     /// `0..(Self::MODULUS - 1)`. Returns `None` if the integer is outside
     /// this range.
@@ -314,6 +323,11 @@ impl<P: FpConfig<N>, const N: usize> Field for Fp<P, N> {
         })
     }
 
+    #[inline]
+    fn inverse_secret(&self) -> Option<Self> {
+        P::inverse_secret(self)
+    }
+
     /// The Frobenius map has no effect in a prime field.
     #[inline]
     fn frobenius_map_in_place(&mut self, _: usize) {}
@@ -691,6 +705,14 @@ impl<P: FpConfig<N>, const N: usize> Neg for Fp<P, N> {
     }
 }
 
+impl<P: FpConfig<N>, const N: usize> Neg for &Fp<P, N> {
+    type Output = Fp<P, N>;
+    #[inline]
+    fn neg(self) -> Fp<P, N> {
+        -*self
+    }
+}
+
 impl<P: FpConfig<N>, const N: usize> Add<&Fp<P, N>> for Fp<P, N> {
     type Output = Self;
 
@@ -967,6 +989,40 @@ impl<P: FpConfig<N>, const N: usize> core::ops::DivAssign<Self> for Fp<P, N> {
     }
 }
 
+#[cfg(feature = "subtle")]
+impl<P: FpConfig<N>, const N: usize> ConstantTimeEq for Fp<P, N> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: FpConfig<N>, const N: usize> ConditionallySelectable for Fp<P, N> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(BigInt::conditional_select(&a.0, &b.0, choice), PhantomData)
+    }
+}
+
+/// Compares field elements as integers in the range 0, 1, ..., `P::MODULUS`
+/// - 1, consistently with the `Ord` implementation.
+#[cfg(feature = "subtle")]
+impl<P: FpConfig<N>, const N: usize> ConstantTimeGreater for Fp<P, N> {
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> Choice {
+        self.into_bigint().ct_gt(&other.into_bigint())
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: FpConfig<N>, const N: usize> ConstantTimeLess for Fp<P, N> {}
+
+/// Only implemented for [`MontBackend`], whose [`FpConfig::inverse_secret`] is
+/// known to run in constant time.
+#[cfg(feature = "subtle")]
+impl<T: MontConfig<N>, const N: usize> crate::CtField for Fp<MontBackend<T, N>, N> {}
+
 impl<P: FpConfig<N>, const N: usize> zeroize::Zeroize for Fp<P, N> {
     // The phantom data does not contain element-specific data
     // and thus does not need to be zeroized.
@@ -346,6 +346,24 @@ pub trait MontConfig<const N: usize>: 'static + Sync + Send + Sized {
         }
     }
 
+    /// Computes `a^{-1}`, if `a` is not zero, in time independent of `a`.
+    ///
+    /// This uses the Bernstein–Yang "safegcd" algorithm, see
+    /// <https://eprint.iacr.org/2019/266>. Apart from the final check
+    /// for zero, the sequence of operations depends only on the modulus.
+    fn inverse_secret(a: &Fp<MontBackend<Self, N>, N>) -> Option<Fp<MontBackend<Self, N>, N>> {
+        // If `a = xR`, then `R^2 * a^{-1} = x^{-1}R`, which is the Montgomery
+        // form of the inverse of `x`.
+        let inverse = super::safegcd::inverse(
+            &a.0,
+            &Self::R2,
+            &Self::MODULUS,
+            Self::MODULUS.const_num_bits(),
+            Self::INV.wrapping_neg(),
+        );
+        (!a.is_zero()).then_some(Fp::new_unchecked(inverse))
+    }
+
     fn from_bigint(r: BigInt<N>) -> Option<Fp<MontBackend<Self, N>, N>> {
         let mut r = Fp::new_unchecked(r);
         if r.is_zero() {
@@ -660,6 +678,10 @@ impl<T: MontConfig<N>, const N: usize> FpConfig<N> for MontBackend<T, N> {
         T::inverse(a)
     }
 
+    fn inverse_secret(a: &Fp<Self, N>) -> Option<Fp<Self, N>> {
+        T::inverse_secret(a)
+    }
+
     fn from_bigint(r: BigInt<N>) -> Option<Fp<Self, N>> {
         T::from_bigint(r)
     }
@@ -0,0 +1,303 @@
+//! Constant-time modular inversion via the Bernstein–Yang "safegcd"
+//! algorithm.
+//!
+//! References:
+//! - Bernstein, Yang. "Fast constant-time gcd computation and modular
+//!   inversion". <https://eprint.iacr.org/2019/266>
+//! - Wuille. "The safegcd implementation in libsecp256k1 explained".
+//!   <https://github.com/bitcoin-core/secp256k1/blob/master/doc/safegcd_implementation.md>
+//!
+//! Integers are represented in signed base 2^62: every limb except the top
+//! one lies in `[0, 2^62)`, while the top limb is signed. `N + 1` such
+//! limbs are enough to hold every intermediate value for moduli of up to
+//! `64 * N` bits, as long as `N <= 30`.
+use crate::BigInt;
+use ark_std::ops::{Index, IndexMut};
+
+const M62: i64 = (u64::MAX >> 2) as i64;
+
+/// A signed integer in base 2^62 with `N + 1` limbs.
+#[derive(Clone, Copy)]
+struct Signed62<const N: usize> {
+    low: [i64; N],
+    top: i64,
+}
+
+impl<const N: usize> Index<usize> for Signed62<N> {
+    type Output = i64;
+
+    #[inline(always)]
+    fn index(&self, i: usize) -> &i64 {
+        if i == N {
+            &self.top
+        } else {
+            &self.low[i]
+        }
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Signed62<N> {
+    #[inline(always)]
+    fn index_mut(&mut self, i: usize) -> &mut i64 {
+        if i == N {
+            &mut self.top
+        } else {
+            &mut self.low[i]
+        }
+    }
+}
+
+impl<const N: usize> Signed62<N> {
+    const ZERO: Self = Self {
+        low: [0; N],
+        top: 0,
+    };
+
+    /// Converts a non-negative integer to signed base 2^62.
+    fn from_bigint(a: &BigInt<N>) -> Self {
+        let mut result = Self::ZERO;
+        let mut acc = 0u128;
+        let mut acc_bits = 0u32;
+        let mut j = 0;
+        for i in 0..=N {
+            while acc_bits < 62 && j < N {
+                acc |= (a.0[j] as u128) << acc_bits;
+                acc_bits += 64;
+                j += 1;
+            }
+            result[i] = (acc as i64) & M62;
+            acc >>= 62;
+            acc_bits = acc_bits.saturating_sub(62);
+        }
+        result
+    }
+
+    /// Converts a normalized integer in `[0, 2^(64 * N))` back to a [`BigInt`].
+    fn to_bigint(self) -> BigInt<N> {
+        let mut result = BigInt([0u64; N]);
+        let mut acc = 0u128;
+        let mut acc_bits = 0;
+        let mut j = 0;
+        for i in 0..=N {
+            acc |= (self[i] as u64 as u128) << acc_bits;
+            acc_bits += 62;
+            if acc_bits >= 64 && j < N {
+                result.0[j] = acc as u64;
+                acc >>= 64;
+                acc_bits -= 64;
+                j += 1;
+            }
+        }
+        if j < N {
+            result.0[j] = acc as u64;
+        }
+        result
+    }
+}
+
+/// The 2x2 transition matrix `[[u, v], [q, r]]` produced by 62 divsteps,
+/// scaled by 2^62.
+struct Transition {
+    u: i64,
+    v: i64,
+    q: i64,
+    r: i64,
+}
+
+/// Performs 62 divsteps on the low 62 bits of `f` and `g`, starting from
+/// `delta`, and returns the new `delta` together with the transition matrix.
+///
+/// The matrix is such that `2^62 * f' = u * f + v * g` and
+/// `2^62 * g' = q * f + r * g`. No branches depend on the inputs.
+#[inline]
+fn divsteps_62(mut delta: i64, f: u64, g: u64) -> (i64, Transition) {
+    let (mut u, mut v, mut q, mut r) = (1i64, 0i64, 0i64, 1i64);
+    let (mut f, mut g) = (f, g);
+    for _ in 0..62 {
+        // All-ones if `delta > 0` and `g` is odd, zero otherwise.
+        let g_odd = ((g & 1) as i64).wrapping_neg();
+        let swap = (delta.wrapping_neg() >> 63) & g_odd;
+
+        // If `swap`, replace `(delta, f, g)` with `(-delta, g, -f)`.
+        let t = (f ^ g) & (swap as u64);
+        f ^= t;
+        g ^= t;
+        g = (g ^ (swap as u64)).wrapping_sub(swap as u64);
+        let t = (u ^ q) & swap;
+        u ^= t;
+        q ^= t;
+        q = (q ^ swap).wrapping_sub(swap);
+        let t = (v ^ r) & swap;
+        v ^= t;
+        r ^= t;
+        r = (r ^ swap).wrapping_sub(swap);
+        delta = (delta ^ swap).wrapping_sub(swap);
+
+        // If `g` is odd, add `f` to it, so that it becomes even.
+        g = g.wrapping_add(f & (g_odd as u64));
+        q = q.wrapping_add(u & g_odd);
+        r = r.wrapping_add(v & g_odd);
+
+        delta += 1;
+        g >>= 1;
+        u <<= 1;
+        v <<= 1;
+    }
+    (delta, Transition { u, v, q, r })
+}
+
+/// Sets `(f, g) = t * (f, g) / 2^62`. The division is exact.
+fn update_fg<const N: usize>(f: &mut Signed62<N>, g: &mut Signed62<N>, t: &Transition) {
+    let (u, v, q, r) = (t.u as i128, t.v as i128, t.q as i128, t.r as i128);
+    let (fi, gi) = (f[0] as i128, g[0] as i128);
+    let mut cf = u * fi + v * gi;
+    let mut cg = q * fi + r * gi;
+    debug_assert_eq!(cf as i64 & M62, 0);
+    debug_assert_eq!(cg as i64 & M62, 0);
+    cf >>= 62;
+    cg >>= 62;
+    for i in 1..=N {
+        let (fi, gi) = (f[i] as i128, g[i] as i128);
+        cf += u * fi + v * gi;
+        cg += q * fi + r * gi;
+        f[i - 1] = cf as i64 & M62;
+        g[i - 1] = cg as i64 & M62;
+        cf >>= 62;
+        cg >>= 62;
+    }
+    f[N] = cf as i64;
+    g[N] = cg as i64;
+}
+
+/// Sets `(d, e) = t * (d, e) / 2^62 mod modulus`, keeping both in the range
+/// `(-2 * modulus, modulus)`.
+fn update_de<const N: usize>(
+    d: &mut Signed62<N>,
+    e: &mut Signed62<N>,
+    t: &Transition,
+    modulus: &Signed62<N>,
+    modulus_inv62: u64,
+) {
+    let (u, v, q, r) = (t.u, t.v, t.q, t.r);
+    // `md` and `me` start as `u` resp. `q` if `d` is negative, plus `v` resp.
+    // `r` if `e` is negative. This keeps the results in range.
+    let sd = d[N] >> 63;
+    let se = e[N] >> 63;
+    let mut md = (u & sd) + (v & se);
+    let mut me = (q & sd) + (r & se);
+
+    let (di, ei) = (d[0] as i128, e[0] as i128);
+    let mut cd = u as i128 * di + v as i128 * ei;
+    let mut ce = q as i128 * di + r as i128 * ei;
+
+    // Correct `md` and `me` so that the bottom 62 bits of
+    // `t * (d, e) + modulus * (md, me)` are zero.
+    md -= (modulus_inv62
+        .wrapping_mul(cd as u64)
+        .wrapping_add(md as u64)
+        & M62 as u64) as i64;
+    me -= (modulus_inv62
+        .wrapping_mul(ce as u64)
+        .wrapping_add(me as u64)
+        & M62 as u64) as i64;
+
+    cd += modulus[0] as i128 * md as i128;
+    ce += modulus[0] as i128 * me as i128;
+    debug_assert_eq!(cd as i64 & M62, 0);
+    debug_assert_eq!(ce as i64 & M62, 0);
+    cd >>= 62;
+    ce >>= 62;
+
+    for i in 1..=N {
+        let (di, ei) = (d[i] as i128, e[i] as i128);
+        cd += u as i128 * di + v as i128 * ei + modulus[i] as i128 * md as i128;
+        ce += q as i128 * di + r as i128 * ei + modulus[i] as i128 * me as i128;
+        d[i - 1] = cd as i64 & M62;
+        e[i - 1] = ce as i64 & M62;
+        cd >>= 62;
+        ce >>= 62;
+    }
+    d[N] = cd as i64;
+    e[N] = ce as i64;
+}
+
+/// Maps `r` from the range `(-2 * modulus, modulus)` to `[0, modulus)`,
+/// negating it first if `sign` is negative.
+fn normalize<const N: usize>(r: &mut Signed62<N>, sign: i64, modulus: &Signed62<N>) {
+    // Add the modulus if `r` is negative, then conditionally negate.
+    // This brings `r` into the range `(-modulus, modulus)`.
+    let cond_add = r[N] >> 63;
+    let cond_negate = sign >> 63;
+    for i in 0..=N {
+        r[i] += modulus[i] & cond_add;
+        r[i] = (r[i] ^ cond_negate) - cond_negate;
+    }
+    propagate_carries(r);
+
+    // Add the modulus again if the result is still negative.
+    let cond_add = r[N] >> 63;
+    for i in 0..=N {
+        r[i] += modulus[i] & cond_add;
+    }
+    propagate_carries(r);
+}
+
+/// Brings all limbs but the top one back into the range `[0, 2^62)`.
+#[inline]
+fn propagate_carries<const N: usize>(r: &mut Signed62<N>) {
+    for i in 0..N {
+        r[i + 1] += r[i] >> 62;
+        r[i] &= M62;
+    }
+}
+
+/// An upper bound on the number of divsteps needed to reach `g = 0`
+/// for inputs of at most `bits` bits, from Theorem 11.2 of Bernstein–Yang.
+const fn iterations(bits: u32) -> u32 {
+    if bits < 46 {
+        (49 * bits + 80) / 17
+    } else {
+        (49 * bits + 57) / 17
+    }
+}
+
+/// Computes `scale * a^{-1} mod modulus` for an odd `modulus` of
+/// `modulus_bits` bits and `a` in `[0, modulus)`. The result is zero
+/// when `a` is zero.
+///
+/// `modulus_inv` must equal `modulus^{-1} mod 2^64`, and `scale` must lie
+/// in `[0, modulus)`. The sequence of operations performed depends only
+/// on `N` and `modulus_bits`.
+pub(super) fn inverse<const N: usize>(
+    a: &BigInt<N>,
+    scale: &BigInt<N>,
+    modulus: &BigInt<N>,
+    modulus_bits: u32,
+    modulus_inv: u64,
+) -> BigInt<N> {
+    debug_assert!(
+        N <= 30,
+        "safegcd inversion only supports moduli of up to 1920 bits"
+    );
+    let modulus_inv62 = modulus_inv & (M62 as u64);
+    let modulus = Signed62::from_bigint(modulus);
+
+    // Invariants: `f * scale = d * a` and `g * scale = e * a` modulo `modulus`.
+    let mut f = modulus;
+    let mut g = Signed62::from_bigint(a);
+    let mut d = Signed62::ZERO;
+    let mut e = Signed62::from_bigint(scale);
+    let mut delta = 1;
+
+    for _ in 0..iterations(modulus_bits).div_ceil(62) {
+        let (new_delta, t) = divsteps_62(delta, f[0] as u64, g[0] as u64);
+        delta = new_delta;
+        update_de(&mut d, &mut e, &t, &modulus, modulus_inv62);
+        update_fg(&mut f, &mut g, &t);
+    }
+
+    // Now `g = 0` and `f = ±gcd(a, modulus)`, which is `±1` when `a` is
+    // non-zero. Hence `d * a = ±scale`.
+    normalize(&mut d, f[N], &modulus);
+    d.to_bigint()
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "subtle")]
+use crate::CtField;
 use crate::{
     biginteger::BigInteger,
     fields::{Field, LegendreSymbol, PrimeField},
@@ -19,6 +21,11 @@ use ark_std::{
     },
     vec::*,
 };
+#[cfg(feature = "subtle")]
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+    CtOption,
+};
 use zeroize::Zeroize;
 
 /// Defines a Quadratic extension field from a quadratic non-residue.
@@ -345,6 +352,20 @@ impl<P: QuadExtConfig> Field for QuadExtField<P> {
         })
     }
 
+    fn inverse_secret(&self) -> Option<Self> {
+        // Same as `inverse`, but without the early exit on zero, and
+        // inverting the norm in constant time.
+        let v1 = self.c1.square();
+        let mut v0 = v1;
+        P::sub_and_mul_base_field_by_nonresidue(&mut v0, &self.c0.square());
+
+        v0.inverse_secret().map(|v1| {
+            let c0 = self.c0 * &v1;
+            let c1 = -(self.c1 * &v1);
+            Self::new(c0, c1)
+        })
+    }
+
     fn frobenius_map_in_place(&mut self, power: usize) {
         self.c0.frobenius_map_in_place(power);
         self.c1.frobenius_map_in_place(power);
@@ -459,6 +480,90 @@ impl<P: QuadExtConfig> PartialOrd for QuadExtField<P> {
     }
 }
 
+#[cfg(feature = "subtle")]
+impl<P: QuadExtConfig> ConstantTimeEq for QuadExtField<P>
+where
+    P::BaseField: CtField,
+{
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: QuadExtConfig> ConditionallySelectable for QuadExtField<P>
+where
+    P::BaseField: CtField,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.c0, &b.c0, choice),
+            P::BaseField::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+}
+
+/// Compares elements lexicographically, consistently with the `Ord`
+/// implementation.
+#[cfg(feature = "subtle")]
+impl<P: QuadExtConfig> ConstantTimeGreater for QuadExtField<P>
+where
+    P::BaseField: CtField,
+{
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> Choice {
+        self.c1.ct_gt(&other.c1) | (self.c1.ct_eq(&other.c1) & self.c0.ct_gt(&other.c0))
+    }
+}
+
+#[cfg(feature = "subtle")]
+impl<P: QuadExtConfig> ConstantTimeLess for QuadExtField<P> where P::BaseField: CtField {}
+
+#[cfg(feature = "subtle")]
+impl<P: QuadExtConfig> CtField for QuadExtField<P>
+where
+    P::BaseField: CtField,
+{
+    fn ct_sqrt(&self) -> CtOption<Self> {
+        // The complex method of `sqrt`, where every branch is evaluated and
+        // the result is chosen with conditional selections.
+        let mut two_inv = P::BasePrimeField::MODULUS;
+        two_inv.add_with_carry(&1u64.into());
+        two_inv.div2();
+        let two_inv = P::BaseField::from_base_prime_field(P::BasePrimeField::from(two_inv));
+
+        // The general case, where `c1 != 0`.
+        let alpha = self.norm().ct_sqrt().unwrap_or(P::BaseField::ZERO);
+        let delta = (alpha + &self.c0) * &two_inv;
+        let delta_sqrt = delta.ct_sqrt();
+        let c0 = P::BaseField::conditional_select(
+            &(delta - &alpha).ct_sqrt().unwrap_or(P::BaseField::ZERO),
+            &delta_sqrt.unwrap_or(P::BaseField::ZERO),
+            delta_sqrt.is_some(),
+        );
+        let c0_inv = c0.ct_inverse().unwrap_or(P::BaseField::ZERO);
+        let general = Self::new(c0, self.c1 * &two_inv * &c0_inv);
+
+        // The case where `c1 = 0`, so that the square root is either
+        // `sqrt(c0)` or `sqrt(c0/P::NONRESIDUE) * x`.
+        let c0_sqrt = self.c0.ct_sqrt();
+        let c0_div_nonresidue_sqrt = (self.c0 / &P::NONRESIDUE)
+            .ct_sqrt()
+            .unwrap_or(P::BaseField::ZERO);
+        let base = Self::conditional_select(
+            &Self::new(P::BaseField::ZERO, c0_div_nonresidue_sqrt),
+            &Self::new(c0_sqrt.unwrap_or(P::BaseField::ZERO), P::BaseField::ZERO),
+            c0_sqrt.is_some(),
+        );
+
+        let sqrt_cand =
+            Self::conditional_select(&general, &base, self.c1.ct_eq(&P::BaseField::ZERO));
+        CtOption::new(sqrt_cand, sqrt_cand.square().ct_eq(self))
+    }
+}
+
 impl<P: QuadExtConfig> Zeroize for QuadExtField<P> {
     // The phantom data does not contain element-specific data
     // and thus does not need to be zeroized.
@@ -574,6 +679,14 @@ impl<P: QuadExtConfig> Neg for QuadExtField<P> {
     }
 }
 
+impl<P: QuadExtConfig> Neg for &QuadExtField<P> {
+    type Output = QuadExtField<P>;
+    #[inline]
+    fn neg(self) -> QuadExtField<P> {
+        -*self
+    }
+}
+
 impl<P: QuadExtConfig> Distribution<QuadExtField<P>> for Standard {
     #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> QuadExtField<P> {
@@ -814,4 +927,29 @@ mod quad_ext_tests {
             );
         }
     }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn test_ct_sqrt() {
+        use ark_test_curves::ark_ff::CtField;
+
+        let rng = &mut test_rng();
+        for _ in 0..100 {
+            let a = Fq2::rand(rng);
+            let sqrt = a.square().ct_sqrt().unwrap();
+            assert!(sqrt == a || sqrt == -a);
+            let sqrt = a.ct_sqrt();
+            assert_eq!(bool::from(sqrt.is_some()), a.sqrt().is_some());
+            if let Some(sqrt) = Option::<Fq2>::from(sqrt) {
+                assert_eq!(sqrt.square(), a);
+            }
+
+            // Elements of the base field are squares in `Fq2`.
+            let c0 = Fq::rand(rng);
+            let b = Fq2::new(c0, Fq::zero());
+            let sqrt = b.ct_sqrt().unwrap();
+            assert_eq!(sqrt.square(), b);
+        }
+        assert!(Fq2::zero().ct_sqrt().unwrap().is_zero());
+    }
 }
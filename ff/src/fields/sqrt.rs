@@ -147,3 +147,58 @@ impl<F: crate::Field> SqrtPrecomputation<F> {
         }
     }
 }
+
+#[cfg(feature = "subtle")]
+impl<F: crate::CtField> SqrtPrecomputation<F> {
+    /// Computes the square root of `elem`, if it exists, in time that does
+    /// not depend on the value of `elem`.
+    pub fn ct_sqrt(&self, elem: &F) -> subtle::CtOption<F> {
+        use subtle::{ConditionallySelectable, ConstantTimeEq};
+        match self {
+            Self::TonelliShanks {
+                two_adicity,
+                quadratic_nonresidue_to_trace,
+                trace_of_modulus_minus_one_div_two,
+            } => {
+                // A constant-time variant of Tonelli-Shanks, following
+                // `sqrt_tonelli_shanks` from the `ff` crate. The loop bounds
+                // only depend on the two-adicity, and the data-dependent
+                // loop of `sqrt` is replaced by conditional selections.
+                let w = elem.pow(trace_of_modulus_minus_one_div_two);
+                let mut v = *two_adicity;
+                let mut x = w * elem;
+                let mut b = x * &w;
+                let mut z = *quadratic_nonresidue_to_trace;
+
+                for max_v in (1..=*two_adicity).rev() {
+                    let mut k = 1u32;
+                    let mut b2k = b.square();
+                    let mut j_less_than_v = subtle::Choice::from(1);
+
+                    for j in 2..max_v {
+                        let b2k_is_one = b2k.ct_eq(&F::ONE);
+                        let squared = F::conditional_select(&b2k, &z, b2k_is_one).square();
+                        b2k = F::conditional_select(&squared, &b2k, b2k_is_one);
+                        let new_z = F::conditional_select(&z, &squared, b2k_is_one);
+                        j_less_than_v &= !j.ct_eq(&v);
+                        k = u32::conditional_select(&j, &k, b2k_is_one);
+                        z = F::conditional_select(&z, &new_z, j_less_than_v);
+                    }
+
+                    let result = x * &z;
+                    x = F::conditional_select(&result, &x, b.ct_eq(&F::ONE));
+                    z.square_in_place();
+                    b *= &z;
+                    v = k;
+                }
+                subtle::CtOption::new(x, x.square().ct_eq(elem))
+            },
+            Self::Case3Mod4 {
+                modulus_plus_one_div_four,
+            } => {
+                let result = elem.pow(modulus_plus_one_div_four.as_ref());
+                subtle::CtOption::new(result, result.square().ct_eq(elem))
+            },
+        }
+    }
+}
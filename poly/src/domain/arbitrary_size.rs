@@ -0,0 +1,801 @@
+//! This module provides an `ArbitrarySizeEvaluationDomain` for performing
+//! polynomial arithmetic over multiplicative subgroups of any size.
+//!
+//! Unlike `Radix2EvaluationDomain` and `MixedRadixEvaluationDomain`, which
+//! require the size of the domain to be of the form `2^k` or `2^k * q^s`, this
+//! domain supports every size `n` dividing `p - 1` in a prime field `F_p`.
+//! This makes FFTs available for fields with little two-adicity, such as the
+//! scalar field of secp256k1.
+//!
+//! The FFT is a mixed-radix Cooley-Tukey FFT over the prime factors of `n`.
+//! The prime factors 2, 3, 5 and 7 use dedicated butterflies, other small
+//! prime factors use direct butterflies, and large prime factors use
+//! Bluestein's algorithm, which turns a DFT of prime size `r` into a cyclic
+//! convolution of length `r`. The convolution uses a radix-2 FFT if the field
+//! has one of a large enough size, and Karatsuba multiplication otherwise.
+//!
+//! The domain computes the factorization of `n` once, at construction. The
+//! butterflies, which include the chirp and the transformed kernel of
+//! Bluestein's algorithm, do not fit in a `Copy` domain, so they are prepared
+//! by [`ArbitrarySizeFft`], which can be reused across transforms.
+
+pub use crate::domain::utils::Elements;
+use crate::domain::{
+    utils::{karatsuba, scale},
+    DomainCoeff, EvaluationDomain, Radix2EvaluationDomain,
+};
+use ark_ff::{batch_inversion, FftField, Field};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::{fmt, iter, log2, vec, vec::*};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Prime factors of the domain size below this use direct butterflies, and
+/// larger ones use Bluestein's algorithm.
+const BLUESTEIN_THRESHOLD: usize = 32;
+
+/// The maximum number of distinct prime factors of a `u64`, as the product of
+/// the first 16 primes exceeds `2^64`.
+const MAX_DISTINCT_PRIMES: usize = 15;
+
+/// Defines a domain over which finite field (I)FFTs can be performed.
+///
+/// Works for every size `n` dividing `p - 1` in a prime field `F_p`, as long
+/// as `F::GENERATOR` generates the multiplicative group of `F`.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ArbitrarySizeEvaluationDomain<F: Field> {
+    /// The size of the domain.
+    pub size: u64,
+    /// `ceil(log_2(self.size))`.
+    pub log_size_of_group: u32,
+    /// Size of the domain as a field element.
+    pub size_as_field_element: F,
+    /// Inverse of the size in the field.
+    pub size_inv: F,
+    /// A generator of the subgroup.
+    pub group_gen: F,
+    /// Inverse of the generator of the subgroup.
+    pub group_gen_inv: F,
+    /// Offset that specifies the coset.
+    pub offset: F,
+    /// Inverse of the offset that specifies the coset.
+    pub offset_inv: F,
+    /// Constant coefficient for the vanishing polynomial.
+    /// Equals `self.offset^self.size`.
+    pub offset_pow_size: F,
+    /// The prime factorization of the size.
+    factorization: Factorization,
+}
+
+impl<F: Field> fmt::Debug for ArbitrarySizeEvaluationDomain<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Arbitrary-size multiplicative subgroup of size {}",
+            self.size
+        )
+    }
+}
+
+impl<F: FftField> EvaluationDomain<F> for ArbitrarySizeEvaluationDomain<F> {
+    type Elements = Elements<F>;
+
+    /// Construct a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients.
+    fn new(num_coeffs: usize) -> Option<Self> {
+        let size = Self::compute_size_of_domain(num_coeffs)? as u64;
+        let factorization = Factorization::new(size);
+        let group_gen = root_of_unity::<F>(size, &factorization)?;
+        let size_as_field_element = F::from(size);
+        let size_inv = size_as_field_element.inverse()?;
+
+        Some(Self {
+            size,
+            log_size_of_group: log2(size as usize),
+            size_as_field_element,
+            size_inv,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+            offset: F::one(),
+            offset_inv: F::one(),
+            offset_pow_size: F::one(),
+            factorization,
+        })
+    }
+
+    fn get_coset(&self, offset: F) -> Option<Self> {
+        Some(Self {
+            offset,
+            offset_inv: offset.inverse()?,
+            offset_pow_size: offset.pow([self.size]),
+            ..*self
+        })
+    }
+
+    /// Returns the smallest divisor of `p - 1` that is at least `num_coeffs`
+    /// and at most `2 * num_coeffs`, if any.
+    fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        let modulus_minus_one = modulus_minus_one::<F>()?;
+        let num_coeffs = num_coeffs.max(1);
+        (num_coeffs..=num_coeffs.checked_mul(2)?)
+            .find(|n| rem_small(&modulus_minus_one, *n as u64) == 0)
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.size.try_into().unwrap()
+    }
+
+    #[inline]
+    fn log_size_of_group(&self) -> u64 {
+        self.log_size_of_group as u64
+    }
+
+    #[inline]
+    fn size_inv(&self) -> F {
+        self.size_inv
+    }
+
+    #[inline]
+    fn group_gen(&self) -> F {
+        self.group_gen
+    }
+
+    #[inline]
+    fn group_gen_inv(&self) -> F {
+        self.group_gen_inv
+    }
+
+    #[inline]
+    fn coset_offset(&self) -> F {
+        self.offset
+    }
+
+    #[inline]
+    fn coset_offset_inv(&self) -> F {
+        self.offset_inv
+    }
+
+    #[inline]
+    fn coset_offset_pow_size(&self) -> F {
+        self.offset_pow_size
+    }
+
+    /// Prepares the butterflies on every call. Use [`ArbitrarySizeFft`] to
+    /// reuse them across transforms.
+    #[inline]
+    fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        ArbitrarySizeFft::new(*self).fft_in_place(coeffs);
+    }
+
+    /// Prepares the butterflies on every call. Use [`ArbitrarySizeFft`] to
+    /// reuse them across transforms.
+    #[inline]
+    fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        ArbitrarySizeFft::new(*self).ifft_in_place(evals);
+    }
+
+    /// Return an iterator over the elements of the domain.
+    fn elements(&self) -> Elements<F> {
+        Elements {
+            cur_elem: self.offset,
+            cur_pow: 0,
+            size: self.size,
+            group_gen: self.group_gen,
+        }
+    }
+}
+
+/// Returns `p - 1` in little-endian 64-bit limbs if `F` is a prime field
+/// `F_p`.
+fn modulus_minus_one<F: Field>() -> Option<Vec<u64>> {
+    if F::extension_degree() != 1 {
+        return None;
+    }
+    let mut limbs = F::characteristic().to_vec();
+    // `p` is odd, so this does not borrow.
+    limbs[0] -= 1;
+    Some(limbs)
+}
+
+/// Returns `limbs mod n` for a little-endian big integer `limbs`.
+fn rem_small(limbs: &[u64], n: u64) -> u64 {
+    limbs.iter().rev().fold(0, |rem, limb| {
+        ((u128::from(rem) << 64 | u128::from(*limb)) % u128::from(n)) as u64
+    })
+}
+
+/// Returns `limbs / n`, rounded down, for a little-endian big integer `limbs`.
+fn div_small(limbs: &[u64], n: u64) -> Vec<u64> {
+    let mut quotient = vec![0; limbs.len()];
+    let mut rem = 0u128;
+    for (q, limb) in quotient.iter_mut().zip(limbs).rev() {
+        let cur = rem << 64 | u128::from(*limb);
+        *q = (cur / u128::from(n)) as u64;
+        rem = cur % u128::from(n);
+    }
+    quotient
+}
+
+/// The prime factorization of the size of a domain, stored inline so that the
+/// domain stays `Copy`.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+struct Factorization {
+    /// The distinct prime factors in ascending order, padded with zeros.
+    primes: [u64; MAX_DISTINCT_PRIMES],
+    /// The multiplicities of the prime factors, padded with zeros.
+    exponents: [u8; MAX_DISTINCT_PRIMES],
+}
+
+impl Factorization {
+    const fn new(mut n: u64) -> Self {
+        let mut primes = [0; MAX_DISTINCT_PRIMES];
+        let mut exponents = [0; MAX_DISTINCT_PRIMES];
+        let mut i = 0;
+        let mut d = 2;
+        while d <= n / d {
+            if n % d == 0 {
+                primes[i] = d;
+                while n % d == 0 {
+                    exponents[i] += 1;
+                    n /= d;
+                }
+                i += 1;
+            }
+            d += 1;
+        }
+        if n > 1 {
+            primes[i] = n;
+            exponents[i] = 1;
+        }
+        Self { primes, exponents }
+    }
+
+    /// Returns the distinct prime factors in ascending order.
+    fn primes(&self) -> impl Iterator<Item = u64> + '_ {
+        self.primes.iter().copied().take_while(|r| *r != 0)
+    }
+
+    /// Returns the indices into `self.primes()` of the prime factors with
+    /// multiplicity, in descending order of the prime factors.
+    fn indices(&self) -> Vec<usize> {
+        let mut indices: Vec<usize> = self
+            .exponents
+            .iter()
+            .enumerate()
+            .flat_map(|(i, e)| iter::repeat(i).take((*e).into()))
+            .collect();
+        indices.reverse();
+        indices
+    }
+}
+
+/// Returns a primitive `n`-th root of unity, computed as
+/// `F::GENERATOR^((p - 1) / n)`, if `n` divides `p - 1` and it has order
+/// exactly `n`, whose prime factorization is `factorization`.
+fn root_of_unity<F: FftField>(n: u64, factorization: &Factorization) -> Option<F> {
+    let modulus_minus_one = modulus_minus_one::<F>()?;
+    if n == 0 || rem_small(&modulus_minus_one, n) != 0 {
+        return None;
+    }
+    let root = F::GENERATOR.pow(div_small(&modulus_minus_one, n));
+    factorization
+        .primes()
+        .all(|r| !root.pow([n / r]).is_one())
+        .then_some(root)
+}
+
+/// Prepared (I)FFTs over an [`ArbitrarySizeEvaluationDomain`].
+///
+/// This stores a butterfly for each prime factor of the size of the domain,
+/// including the chirp and the transformed kernel of Bluestein's algorithm for
+/// large prime factors, so that repeated transforms over the same domain only
+/// compute them once.
+///
+/// # Example
+/// ```
+/// use ark_poly::{domain::arbitrary_size::ArbitrarySizeFft, ArbitrarySizeEvaluationDomain, EvaluationDomain};
+/// use ark_test_curves::bls12_381::Fr;
+///
+/// let domain = ArbitrarySizeEvaluationDomain::<Fr>::new(3 * 10177).unwrap();
+/// let fft = ArbitrarySizeFft::new(domain);
+/// let mut evals = vec![Fr::from(1u64), Fr::from(2u64)];
+/// fft.fft_in_place(&mut evals);
+/// assert_eq!(evals, domain.fft(&[Fr::from(1u64), Fr::from(2u64)]));
+/// fft.ifft_in_place(&mut evals);
+/// assert_eq!(evals[..2], [Fr::from(1u64), Fr::from(2u64)]);
+/// ```
+pub struct ArbitrarySizeFft<F: FftField> {
+    domain: ArbitrarySizeEvaluationDomain<F>,
+    /// The butterflies for `domain.factorization.primes()`.
+    butterflies: Vec<Butterfly<F>>,
+    /// The indices into `butterflies` of the prime factors of the size, with
+    /// multiplicity, in descending order of the prime factors.
+    levels: Vec<usize>,
+}
+
+impl<F: FftField> ArbitrarySizeFft<F> {
+    /// Prepares (I)FFTs over `domain`.
+    pub fn new(domain: ArbitrarySizeEvaluationDomain<F>) -> Self {
+        // At every level of the recursion, the butterfly of prime size `r` is
+        // a DFT with respect to `group_gen^(size / r)`.
+        let butterflies = domain
+            .factorization
+            .primes()
+            .map(|r| Butterfly::new(r as usize, domain.group_gen.pow([domain.size / r])))
+            .collect();
+        Self {
+            domain,
+            butterflies,
+            levels: domain.factorization.indices(),
+        }
+    }
+
+    /// Returns the domain of the transforms.
+    pub const fn domain(&self) -> ArbitrarySizeEvaluationDomain<F> {
+        self.domain
+    }
+
+    /// Computes an FFT over the domain, modifying the vector in place.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        let domain = &self.domain;
+        if !domain.offset.is_one() {
+            ArbitrarySizeEvaluationDomain::distribute_powers(coeffs, domain.offset);
+        }
+        coeffs.resize(domain.size(), T::zero());
+        *coeffs = self.dft(coeffs, domain.group_gen, &self.levels);
+    }
+
+    /// Computes an IFFT over the domain, modifying the vector in place.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        let domain = &self.domain;
+        evals.resize(domain.size(), T::zero());
+        // The DFT with respect to `group_gen^(-1)` is the DFT with respect to
+        // `group_gen` with the outputs `1..size` reversed, so it uses the same
+        // butterflies.
+        *evals = self.dft(evals, domain.group_gen, &self.levels);
+        evals[1..].reverse();
+        if domain.offset.is_one() {
+            ark_std::cfg_iter_mut!(evals).for_each(|val| *val *= domain.size_inv);
+        } else {
+            ArbitrarySizeEvaluationDomain::distribute_powers_and_mul_by_const(
+                evals,
+                domain.offset_inv,
+                domain.size_inv,
+            );
+        }
+    }
+
+    /// Returns the DFT of `a` with respect to `omega`, a primitive root of
+    /// unity of order `a.len()`, whose prime factors are those of the
+    /// butterflies at `levels`.
+    fn dft<T: DomainCoeff<F>>(&self, a: &[T], omega: F, levels: &[usize]) -> Vec<T> {
+        let Some((&level, rest)) = levels.split_first() else {
+            return a.to_vec();
+        };
+        let butterfly = &self.butterflies[level];
+        let r = butterfly.size();
+        let n = a.len();
+        let m = n / r;
+
+        // Split `a` into the `r` subsequences `a[j], a[j + r], ...`, whose DFTs
+        // are taken with respect to `omega^r`.
+        let omega_r = omega.pow([r as u64]);
+        let sub_dfts: Vec<Vec<T>> = (0..r)
+            .map(|j| {
+                let sub: Vec<T> = a[j..].iter().step_by(r).copied().collect();
+                self.dft(&sub, omega_r, rest)
+            })
+            .collect();
+
+        // Combine them as `X[k + m * s] = sum_j (omega^(j * k) Y_j[k]) * omega^(j * m * s)`,
+        // which is a DFT of size `r` with respect to `omega^m` for each `k`.
+        let mut result = vec![T::zero(); n];
+        let mut terms = vec![T::zero(); r];
+        let mut twiddle = F::one();
+        for k in 0..m {
+            let mut w = F::one();
+            for (term, sub_dft) in terms.iter_mut().zip(&sub_dfts) {
+                *term = scale(sub_dft[k], w);
+                w *= twiddle;
+            }
+            butterfly.apply(&mut terms);
+            for (s, term) in terms.iter().enumerate() {
+                result[k + m * s] = *term;
+            }
+            twiddle *= omega;
+        }
+        result
+    }
+}
+
+/// A DFT of prime size `r`.
+enum Butterfly<F: FftField> {
+    /// The DFT of size 2.
+    Radix2,
+    /// The DFT of size 3.
+    Radix3(OddButterfly<F, 1>),
+    /// The DFT of size 5.
+    Radix5(OddButterfly<F, 2>),
+    /// The DFT of size 7.
+    Radix7(OddButterfly<F, 3>),
+    /// The direct `O(r^2)` DFT for the other primes below
+    /// [`BLUESTEIN_THRESHOLD`], using the powers `root^i` for `i < r`.
+    Direct(Vec<F>),
+    /// Bluestein's algorithm, using the chirp `v^(k^2)` for `k < r`, where
+    /// `v^2 = root`, and the convolution with its inverse.
+    Bluestein {
+        chirp: Vec<F>,
+        convolution: CyclicConvolution<F>,
+    },
+}
+
+impl<F: FftField> Butterfly<F> {
+    /// Prepares a DFT of prime size `r` with respect to `root`, a primitive
+    /// root of unity of order `r`.
+    fn new(r: usize, root: F) -> Self {
+        match r {
+            2 => return Self::Radix2,
+            3 => return Self::Radix3(OddButterfly::new(root)),
+            5 => return Self::Radix5(OddButterfly::new(root)),
+            7 => return Self::Radix7(OddButterfly::new(root)),
+            _ if r < BLUESTEIN_THRESHOLD => return Self::Direct(powers(r, root)),
+            _ => {},
+        }
+
+        // As `r` is odd, `v = root^((r + 1) / 2)` satisfies `v^2 = root`, and
+        // `v` has order `r`. Then `j * k = (j^2 + k^2 - (k - j)^2) / 2` gives
+        // `X[k] = v^(k^2) * sum_j (x[j] * v^(j^2)) * v^(-(k - j)^2)`, and as
+        // `v^(t^2)` only depends on `t mod r`, this is a cyclic convolution.
+        let v = root.pow([(r as u64).div_ceil(2)]);
+        let chirp: Vec<F> = (0..r as u64).map(|k| v.pow([(k * k) % r as u64])).collect();
+        let mut kernel = chirp.clone();
+        batch_inversion(&mut kernel);
+        Self::Bluestein {
+            chirp,
+            convolution: CyclicConvolution::new(kernel),
+        }
+    }
+
+    /// Returns the size of the DFT.
+    fn size(&self) -> usize {
+        match self {
+            Self::Radix2 => 2,
+            Self::Radix3(_) => 3,
+            Self::Radix5(_) => 5,
+            Self::Radix7(_) => 7,
+            Self::Direct(powers) => powers.len(),
+            Self::Bluestein { chirp, .. } => chirp.len(),
+        }
+    }
+
+    /// Replaces `terms` by their DFT.
+    fn apply<T: DomainCoeff<F>>(&self, terms: &mut [T]) {
+        match self {
+            Self::Radix2 => {
+                let (t0, t1) = (terms[0], terms[1]);
+                terms[0] = t0 + t1;
+                terms[1] = t0 - t1;
+            },
+            Self::Radix3(butterfly) => butterfly.apply(terms),
+            Self::Radix5(butterfly) => butterfly.apply(terms),
+            Self::Radix7(butterfly) => butterfly.apply(terms),
+            Self::Direct(powers) => {
+                let r = powers.len();
+                let input = terms.to_vec();
+                for (s, term) in terms.iter_mut().enumerate() {
+                    *term = input.iter().enumerate().fold(T::zero(), |acc, (j, x)| {
+                        acc + scale(*x, powers[(j * s) % r])
+                    });
+                }
+            },
+            Self::Bluestein { chirp, convolution } => {
+                let mut input: Vec<T> = terms
+                    .iter()
+                    .zip(chirp)
+                    .map(|(x, c)| scale(*x, *c))
+                    .collect();
+                convolution.apply(&mut input);
+                for ((term, x), c) in terms.iter_mut().zip(input).zip(chirp) {
+                    *term = scale(x, *c);
+                }
+            },
+        }
+    }
+}
+
+/// Returns `root^i` for `i < r`.
+fn powers<F: Field>(r: usize, root: F) -> Vec<F> {
+    iter::successors(Some(F::one()), |power| Some(*power * root))
+        .take(r)
+        .collect()
+}
+
+/// A DFT of odd prime size `r = 2 * H + 1`.
+///
+/// As `root^(j * (r - k)) = root^(-j * k)`, the outputs `X[k]` and `X[r - k]`
+/// only differ in the sign of their odd part, so with `s[j] = x[j] + x[r - j]`
+/// and `d[j] = x[j] - x[r - j]` for `1 <= j <= H`,
+/// `X[k] = x[0] + sum_j even[k][j] * s[j] + sum_j odd[k][j] * d[j]`, and
+/// `X[r - k]` is the same with the second sum subtracted. This takes `2 * H^2`
+/// multiplications instead of the `(r - 1)^2` of the direct DFT.
+struct OddButterfly<F: FftField, const H: usize> {
+    /// `even[k - 1][j - 1] = (root^(j * k) + root^(-j * k)) / 2`.
+    even: [[F; H]; H],
+    /// `odd[k - 1][j - 1] = (root^(j * k) - root^(-j * k)) / 2`.
+    odd: [[F; H]; H],
+}
+
+impl<F: FftField, const H: usize> OddButterfly<F, H> {
+    /// Prepares a DFT of size `2 * H + 1` with respect to `root`, a primitive
+    /// root of unity of that order.
+    fn new(root: F) -> Self {
+        let r = 2 * H + 1;
+        let powers = powers(r, root);
+        // The characteristic is odd, as the field has a root of unity of odd
+        // prime order.
+        let two_inv = F::from(2u64).inverse().unwrap();
+        let mut even = [[F::zero(); H]; H];
+        let mut odd = [[F::zero(); H]; H];
+        for (k, (even, odd)) in even.iter_mut().zip(&mut odd).enumerate() {
+            for (j, (even, odd)) in even.iter_mut().zip(odd).enumerate() {
+                let e = ((j + 1) * (k + 1)) % r;
+                let (power, inverse) = (powers[e], powers[r - e]);
+                *even = (power + inverse) * two_inv;
+                *odd = (power - inverse) * two_inv;
+            }
+        }
+        Self { even, odd }
+    }
+
+    /// Replaces `terms`, of which there are `2 * H + 1`, by their DFT.
+    fn apply<T: DomainCoeff<F>>(&self, terms: &mut [T]) {
+        let r = 2 * H + 1;
+        debug_assert_eq!(terms.len(), r);
+        let x0 = terms[0];
+        let mut sums = [T::zero(); H];
+        let mut diffs = [T::zero(); H];
+        for (j, (s, d)) in sums.iter_mut().zip(&mut diffs).enumerate() {
+            *s = terms[j + 1] + terms[r - 1 - j];
+            *d = terms[j + 1] - terms[r - 1 - j];
+        }
+        terms[0] = sums.iter().fold(x0, |acc, s| acc + *s);
+        for (k, (even, odd)) in self.even.iter().zip(&self.odd).enumerate() {
+            let even_part = (sums.iter().zip(even)).fold(x0, |acc, (s, c)| acc + scale(*s, *c));
+            let odd_part =
+                (diffs.iter().zip(odd)).fold(T::zero(), |acc, (d, c)| acc + scale(*d, *c));
+            terms[k + 1] = even_part + odd_part;
+            terms[r - 1 - k] = even_part - odd_part;
+        }
+    }
+}
+
+/// A cyclic convolution with a fixed kernel.
+enum CyclicConvolution<F: FftField> {
+    /// Uses a radix-2 FFT of size at least `2 * kernel.len() - 1`, storing the
+    /// FFT of the kernel.
+    Fft {
+        len: usize,
+        domain: Radix2EvaluationDomain<F>,
+        kernel_evals: Vec<F>,
+    },
+    /// Uses Karatsuba multiplication.
+    Karatsuba(Vec<F>),
+}
+
+impl<F: FftField> CyclicConvolution<F> {
+    fn new(kernel: Vec<F>) -> Self {
+        let len = kernel.len();
+        match Radix2EvaluationDomain::new(2 * len - 1) {
+            Some(domain) => Self::Fft {
+                len,
+                domain,
+                kernel_evals: domain.fft(&kernel),
+            },
+            None => Self::Karatsuba(kernel),
+        }
+    }
+
+    /// Replaces `input` by its cyclic convolution with the kernel.
+    fn apply<T: DomainCoeff<F>>(&self, input: &mut [T]) {
+        let len = input.len();
+        let mut linear = match self {
+            Self::Fft {
+                len: kernel_len,
+                domain,
+                kernel_evals,
+            } => {
+                debug_assert_eq!(len, *kernel_len);
+                let mut evals = domain.fft(input);
+                evals
+                    .iter_mut()
+                    .zip(kernel_evals)
+                    .for_each(|(e, k)| *e *= *k);
+                domain.ifft_in_place(&mut evals);
+                evals
+            },
+            Self::Karatsuba(kernel) => karatsuba(input, kernel),
+        };
+        linear.resize(2 * len, T::zero());
+        let (low, high) = linear.split_at(len);
+        for ((x, l), h) in input.iter_mut().zip(low).zip(high) {
+            *x = *l + *h;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArbitrarySizeEvaluationDomain, ArbitrarySizeFft, Factorization};
+    use crate::{
+        domain::utils::karatsuba,
+        polynomial::{univariate::*, DenseUVPolynomial, Polynomial},
+        EvaluationDomain, Evaluations,
+    };
+    use ark_ff::{FftField, Fp64, MontBackend, MontConfig, UniformRand, Zero};
+    use ark_std::{rand::Rng, test_rng, vec, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+
+    /// A field of size `283 = 2 * 3 * 47 + 1` without radix-2 FFTs of size 128
+    /// or more, so that Bluestein's algorithm falls back to Karatsuba.
+    #[derive(MontConfig)]
+    #[modulus = "283"]
+    #[generator = "3"]
+    pub(crate) struct F283Config;
+    pub(crate) type F283 = Fp64<MontBackend<F283Config, 1>>;
+
+    /// A field of size `211 = 2 * 3 * 5 * 7 + 1`, which has the roots of unity
+    /// for all the dedicated butterflies.
+    #[derive(MontConfig)]
+    #[modulus = "211"]
+    #[generator = "2"]
+    pub(crate) struct F211Config;
+    pub(crate) type F211 = Fp64<MontBackend<F211Config, 1>>;
+
+    fn check_fft<F: ark_ff::FftField, R: Rng>(size: usize, rng: &mut R) {
+        let domain = ArbitrarySizeEvaluationDomain::<F>::new(size).unwrap();
+        assert_eq!(domain.size(), size);
+        assert!(domain.group_gen().pow([size as u64]).is_one());
+
+        let elements: Vec<F> = domain.elements().collect();
+        // Evaluate at a few elements with Horner's rule to keep large sizes fast.
+        let indices: Vec<usize> = (0..8).map(|_| rng.gen_range(0..size)).collect();
+        for degree in [0, size / 2, size - 1] {
+            let poly = DensePolynomial::<F>::rand(degree, rng);
+            let evals = domain.fft(&poly.coeffs);
+            assert_eq!(evals.len(), size);
+            for i in &indices {
+                assert_eq!(evals[*i], poly.evaluate(&elements[*i]));
+            }
+            assert_eq!(
+                DensePolynomial::from_coefficients_vec(domain.ifft(&evals)),
+                poly
+            );
+
+            let offset = F::GENERATOR;
+            let coset = domain.get_coset(offset).unwrap();
+            let coset_evals = coset.fft(&poly.coeffs);
+            for i in &indices {
+                assert_eq!(coset_evals[*i], poly.evaluate(&(elements[*i] * offset)));
+            }
+            assert_eq!(
+                DensePolynomial::from_coefficients_vec(coset.ifft(&coset_evals)),
+                poly
+            );
+        }
+    }
+
+    #[test]
+    fn fft_small_and_composite_sizes() {
+        let rng = &mut test_rng();
+        // The multiplicative group of `Fr` has order `2^32 * 3 * 11 * 19 * 10177 * ...`.
+        for size in [1, 2, 3, 6, 11, 19, 33, 64, 3 * 11 * 19, 2 * 3 * 11 * 19 * 8] {
+            check_fft::<Fr, _>(size, rng);
+        }
+    }
+
+    #[test]
+    fn fft_large_prime_size() {
+        let rng = &mut test_rng();
+        check_fft::<Fr, _>(10177, rng);
+        check_fft::<Fr, _>(2 * 3 * 10177, rng);
+        // These use Bluestein's algorithm with Karatsuba convolutions.
+        check_fft::<F283, _>(47, rng);
+        check_fft::<F283, _>(282, rng);
+    }
+
+    #[test]
+    fn fft_dedicated_butterflies() {
+        let rng = &mut test_rng();
+        for size in [2, 3, 5, 7, 15, 35, 105, 210] {
+            check_fft::<F211, _>(size, rng);
+        }
+    }
+
+    #[test]
+    fn fft_low_two_adicity() {
+        // The multiplicative group of the secp256k1 scalar field has order
+        // `2^6 * 3 * 149 * 631 * ...`, so Bluestein's algorithm uses Karatsuba
+        // convolutions.
+        use ark_test_curves::secp256k1::Fr;
+
+        let rng = &mut test_rng();
+        for size in [64, 2 * 3, 64 * 3, 149, 3 * 149, 631] {
+            check_fft::<Fr, _>(size, rng);
+        }
+    }
+
+    #[test]
+    fn prepared_fft() {
+        let rng = &mut test_rng();
+        let domain = ArbitrarySizeEvaluationDomain::<Fr>::new(2 * 10177).unwrap();
+        let coset = domain.get_coset(Fr::GENERATOR).unwrap();
+        for domain in [domain, coset] {
+            let fft = ArbitrarySizeFft::new(domain);
+            assert_eq!(fft.domain(), domain);
+            for degree in [0, 100, domain.size() - 1] {
+                let poly = DensePolynomial::<Fr>::rand(degree, rng);
+                let mut evals = poly.coeffs.clone();
+                fft.fft_in_place(&mut evals);
+                assert_eq!(evals, domain.fft(&poly.coeffs));
+                fft.ifft_in_place(&mut evals);
+                assert_eq!(DensePolynomial::from_coefficients_vec(evals), poly);
+            }
+        }
+    }
+
+    #[test]
+    fn domain_sizes() {
+        assert_eq!(
+            ArbitrarySizeEvaluationDomain::<Fr>::compute_size_of_domain(5),
+            Some(6)
+        );
+        assert_eq!(
+            ArbitrarySizeEvaluationDomain::<Fr>::compute_size_of_domain(19),
+            Some(19)
+        );
+        assert_eq!(
+            ArbitrarySizeEvaluationDomain::<F283>::compute_size_of_domain(48),
+            Some(94)
+        );
+        assert_eq!(
+            ArbitrarySizeEvaluationDomain::<F283>::compute_size_of_domain(142),
+            Some(282)
+        );
+        assert!(ArbitrarySizeEvaluationDomain::<F283>::new(283).is_none());
+        assert!(ArbitrarySizeEvaluationDomain::<ark_test_curves::bls12_381::Fq2>::new(4).is_none());
+        let factorization = Factorization::new(2 * 2 * 3 * 47);
+        assert_eq!(factorization.primes().collect::<Vec<_>>(), vec![2, 3, 47]);
+        assert_eq!(factorization.indices(), vec![2, 1, 0, 0]);
+        assert_eq!(Factorization::new(1).primes().count(), 0);
+    }
+
+    #[test]
+    fn evaluations_over_domain() {
+        let rng = &mut test_rng();
+        let domain = ArbitrarySizeEvaluationDomain::<Fr>::new(33).unwrap();
+        let p1 = DensePolynomial::<Fr>::rand(10, rng);
+        let p2 = DensePolynomial::<Fr>::rand(15, rng);
+        let evals1 = p1.clone().evaluate_over_domain(domain);
+        let evals2 = p2.evaluate_over_domain_by_ref(domain);
+        assert_eq!(evals1.interpolate_by_ref(), p1);
+        assert_eq!((&evals1 * &evals2).interpolate(), &p1 * &p2);
+
+        let evals = Evaluations::from_vec_and_domain(vec![Fr::zero(); 33], domain);
+        assert!(evals.interpolate().is_zero());
+    }
+
+    #[test]
+    fn karatsuba_matches_naive() {
+        let rng = &mut test_rng();
+        for n in [1, 5, 31, 32, 33, 100, 257] {
+            let a: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+            let b: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+            let expected = DensePolynomial::from_coefficients_slice(&a)
+                .naive_mul(&DensePolynomial::from_coefficients_slice(&b));
+            let mut result = karatsuba(&a, &b);
+            result.resize(expected.coeffs.len(), Fr::zero());
+            assert_eq!(result, expected.coeffs);
+        }
+    }
+}
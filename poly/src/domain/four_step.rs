@@ -0,0 +1,542 @@
+//! This module provides `FourStepFft`, a driver for (I)FFTs over an
+//! `EvaluationDomain` whose data does not need to fit in memory.
+//!
+//! The four-step (or Bailey) FFT views a vector of size `n = n_1 * n_2` as a
+//! matrix with `n_2` rows and `n_1` columns, stored in row-major order, and
+//! 1. performs an FFT of size `n_2` on each column,
+//! 2. multiplies the entry in row `k_2` and column `j_1` by `ω^(j_1 * k_2)`,
+//! 3. performs an FFT of size `n_1` on each row, and
+//! 4. transposes the matrix.
+//!
+//! The data is accessed through an `FftStorage`, and only a batch of rows or
+//! columns is held in memory at a time, so that the buffers holding them are
+//! bounded by a configurable budget. Each FFT of a row or column also
+//! allocates scratch space (e.g. twiddle factors) of the size of that row or
+//! column, once per thread with the `parallel` feature, so the peak memory
+//! use is the budget plus `O(threads * sqrt(n))` elements. As field
+//! arithmetic is exact, the results are identical to those of the in-memory
+//! FFT of the domain.
+
+use crate::domain::{DomainCoeff, EvaluationDomain};
+use ark_ff::FftField;
+use ark_std::{io, marker::PhantomData, vec, vec::*};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Storage for the data of a `FourStepFft`, which is read and written in
+/// contiguous ranges.
+///
+/// This is implemented for slices, and for files by `FileStorage`. Other
+/// backends, such as memory-mapped files or remote storage, can implement it
+/// to use the four-step FFT.
+pub trait FftStorage<T> {
+    /// Returns the number of elements in `self`.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if `self` contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reads the elements starting at index `start` into `buf`.
+    fn read(&mut self, start: usize, buf: &mut [T]) -> io::Result<()>;
+
+    /// Writes `buf` to the elements starting at index `start`.
+    fn write(&mut self, start: usize, buf: &[T]) -> io::Result<()>;
+}
+
+impl<T: Copy> FftStorage<T> for [T] {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn read(&mut self, start: usize, buf: &mut [T]) -> io::Result<()> {
+        buf.copy_from_slice(&self[start..start + buf.len()]);
+        Ok(())
+    }
+
+    fn write(&mut self, start: usize, buf: &[T]) -> io::Result<()> {
+        self[start..start + buf.len()].copy_from_slice(buf);
+        Ok(())
+    }
+}
+
+/// An `FftStorage` backed by a file, which stores each element in its
+/// uncompressed serialization.
+///
+/// The elements must have serializations of a fixed size, as is the case for
+/// field elements.
+#[cfg(feature = "std")]
+pub struct FileStorage<T> {
+    file: std::fs::File,
+    len: usize,
+    element_size: usize,
+    _elements: PhantomData<T>,
+}
+
+#[cfg(feature = "std")]
+impl<T> FileStorage<T>
+where
+    T: ark_serialize::CanonicalSerialize + ark_serialize::CanonicalDeserialize + ark_ff::Zero,
+{
+    /// Uses `file` as storage for `len` elements, resizing it if needed.
+    pub fn new(file: std::fs::File, len: usize) -> io::Result<Self> {
+        let element_size = T::zero().uncompressed_size();
+        file.set_len((len * element_size) as u64)?;
+        Ok(Self {
+            file,
+            len,
+            element_size,
+            _elements: PhantomData,
+        })
+    }
+
+    /// Returns the underlying file.
+    pub fn into_inner(self) -> std::fs::File {
+        self.file
+    }
+
+    fn seek(&mut self, start: usize) -> io::Result<()> {
+        use std::io::Seek;
+        self.file
+            .seek(io::SeekFrom::Start((start * self.element_size) as u64))
+            .map(|_| ())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> FftStorage<T> for FileStorage<T>
+where
+    T: ark_serialize::CanonicalSerialize + ark_serialize::CanonicalDeserialize + ark_ff::Zero,
+{
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn read(&mut self, start: usize, buf: &mut [T]) -> io::Result<()> {
+        use std::io::Read;
+        self.seek(start)?;
+        let mut bytes = vec![0u8; buf.len() * self.element_size];
+        self.file.read_exact(&mut bytes)?;
+        for (element, bytes) in buf.iter_mut().zip(bytes.chunks(self.element_size)) {
+            *element = T::deserialize_uncompressed_unchecked(bytes).map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+
+    fn write(&mut self, start: usize, buf: &[T]) -> io::Result<()> {
+        use std::io::Write;
+        self.seek(start)?;
+        let mut bytes = Vec::with_capacity(buf.len() * self.element_size);
+        for element in buf {
+            element
+                .serialize_uncompressed(&mut bytes)
+                .map_err(io::Error::other)?;
+        }
+        self.file.write_all(&bytes)
+    }
+}
+
+/// Performs (I)FFTs over `domain` on data in an `FftStorage`, buffering at
+/// most `memory_budget` elements of it in memory at a time.
+///
+/// This does not count the scratch space of the FFTs of the rows and columns,
+/// which adds `O(threads * sqrt(n))` elements to the peak memory use.
+///
+/// # Example
+/// ```
+/// use ark_poly::{domain::four_step::FourStepFft, EvaluationDomain, Radix2EvaluationDomain};
+/// use ark_std::{test_rng, UniformRand};
+/// use ark_test_curves::bls12_381::Fr;
+///
+/// let rng = &mut test_rng();
+/// let domain = Radix2EvaluationDomain::<Fr>::new(1 << 10).unwrap();
+/// let coeffs: Vec<Fr> = (0..1 << 10).map(|_| Fr::rand(rng)).collect();
+///
+/// // Buffer at most 256 field elements in memory.
+/// let four_step = FourStepFft::new(domain, 256).unwrap();
+/// let mut input = coeffs.clone();
+/// let mut evals = vec![Fr::from(0u64); 1 << 10];
+/// four_step.fft(&mut input[..], &mut evals[..]).unwrap();
+/// assert_eq!(evals, domain.fft(&coeffs));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct FourStepFft<F: FftField, D: EvaluationDomain<F>> {
+    domain: D,
+    /// The domain of size `num_rows`, for the FFTs of the columns.
+    column_domain: D,
+    /// The domain of size `num_columns`, for the FFTs of the rows.
+    row_domain: D,
+    num_rows: usize,
+    num_columns: usize,
+    memory_budget: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F: FftField, D: EvaluationDomain<F>> FourStepFft<F, D> {
+    /// Prepares four-step (I)FFTs over `domain` which buffer at most
+    /// `memory_budget` elements in memory at a time, in addition to the
+    /// scratch space of the FFTs of the rows and columns.
+    ///
+    /// Returns `None` if the size of `domain` cannot be split into two factors
+    /// whose domains of type `D` are subgroups of `domain`, or if the budget
+    /// is too small for the split. A budget of `2 * sqrt(n)` elements suffices
+    /// for a radix-2 domain of size `n = 4^k`.
+    pub fn new(domain: D, memory_budget: usize) -> Option<Self> {
+        let size = domain.size();
+        let mut splits: Vec<(usize, usize)> = (1..=size)
+            .take_while(|d| d * d <= size)
+            .filter(|d| size % d == 0)
+            .flat_map(|d| [(d, size / d), (size / d, d)])
+            .collect();
+        splits.sort_by_key(|(num_rows, num_columns)| (*num_rows).max(*num_columns));
+
+        splits
+            .into_iter()
+            .filter(|(num_rows, num_columns)| 2 * num_rows.max(num_columns) <= memory_budget)
+            .find_map(|(num_rows, num_columns)| {
+                let column_domain = Self::subgroup(&domain, num_rows)?;
+                let row_domain = Self::subgroup(&domain, num_columns)?;
+                Some(Self {
+                    domain,
+                    column_domain,
+                    row_domain,
+                    num_rows,
+                    num_columns,
+                    memory_budget,
+                    _field: PhantomData,
+                })
+            })
+    }
+
+    /// Returns the domain of type `D` of size `size`, if it is a subgroup of
+    /// `domain`.
+    fn subgroup(domain: &D, size: usize) -> Option<D> {
+        let subgroup = D::new(size)?;
+        (subgroup.size() == size
+            && subgroup.group_gen() == domain.group_gen().pow([(domain.size() / size) as u64]))
+        .then_some(subgroup)
+    }
+
+    /// Returns the domain of `self`.
+    pub const fn domain(&self) -> D {
+        self.domain
+    }
+
+    /// Returns the number of rows and columns of the matrix the data is split
+    /// into.
+    pub const fn shape(&self) -> (usize, usize) {
+        (self.num_rows, self.num_columns)
+    }
+
+    /// Computes the FFT of the coefficients in `input`, and writes the
+    /// evaluations to `output`.
+    ///
+    /// Both must have the size of the domain, and `input` is used as scratch
+    /// space, so its contents are overwritten.
+    pub fn fft<T, S1, S2>(&self, input: &mut S1, output: &mut S2) -> io::Result<()>
+    where
+        T: DomainCoeff<F>,
+        S1: FftStorage<T> + ?Sized,
+        S2: FftStorage<T> + ?Sized,
+    {
+        self.four_step(
+            input,
+            output,
+            self.domain.group_gen(),
+            self.domain.coset_offset(),
+            false,
+        )
+    }
+
+    /// Computes the IFFT of the evaluations in `input`, and writes the
+    /// coefficients to `output`.
+    ///
+    /// Both must have the size of the domain, and `input` is used as scratch
+    /// space, so its contents are overwritten.
+    pub fn ifft<T, S1, S2>(&self, input: &mut S1, output: &mut S2) -> io::Result<()>
+    where
+        T: DomainCoeff<F>,
+        S1: FftStorage<T> + ?Sized,
+        S2: FftStorage<T> + ?Sized,
+    {
+        self.four_step(
+            input,
+            output,
+            self.domain.group_gen_inv(),
+            self.domain.coset_offset_inv(),
+            true,
+        )
+    }
+
+    fn four_step<T, S1, S2>(
+        &self,
+        input: &mut S1,
+        output: &mut S2,
+        root: F,
+        offset: F,
+        inverse: bool,
+    ) -> io::Result<()>
+    where
+        T: DomainCoeff<F>,
+        S1: FftStorage<T> + ?Sized,
+        S2: FftStorage<T> + ?Sized,
+    {
+        let size = self.domain.size();
+        assert_eq!(input.len(), size, "input must have the size of the domain");
+        assert_eq!(
+            output.len(),
+            size,
+            "output must have the size of the domain"
+        );
+        self.column_pass(input, root, offset, inverse)?;
+        self.row_pass(input, output, offset, inverse)
+    }
+
+    /// Performs steps 1 and 2 in place, on batches of columns. For the FFT,
+    /// the coefficient at index `j` is first multiplied by `offset^j`.
+    fn column_pass<T, S>(&self, data: &mut S, root: F, offset: F, inverse: bool) -> io::Result<()>
+    where
+        T: DomainCoeff<F>,
+        S: FftStorage<T> + ?Sized,
+    {
+        let (num_rows, num_columns) = (self.num_rows, self.num_columns);
+        // The batch is held twice, once as rows and once as columns.
+        let batch_size = (self.memory_budget / (2 * num_rows)).min(num_columns);
+        let offset_pow_columns = offset.pow([num_columns as u64]);
+        let mut rows = vec![T::zero(); batch_size * num_rows];
+
+        for start in (0..num_columns).step_by(batch_size) {
+            let width = batch_size.min(num_columns - start);
+            let rows = &mut rows[..width * num_rows];
+            for (j_2, row) in rows.chunks_mut(width).enumerate() {
+                data.read(start + j_2 * num_columns, row)?;
+            }
+
+            let mut columns: Vec<Vec<T>> = (0..width)
+                .map(|c| rows.iter().skip(c).step_by(width).copied().collect())
+                .collect();
+            ark_std::cfg_iter_mut!(columns)
+                .enumerate()
+                .for_each(|(c, column)| {
+                    let j_1 = (start + c) as u64;
+                    if inverse {
+                        self.column_domain.ifft_in_place(column);
+                    } else {
+                        if !offset.is_one() {
+                            D::distribute_powers_and_mul_by_const(
+                                column,
+                                offset_pow_columns,
+                                offset.pow([j_1]),
+                            );
+                        }
+                        self.column_domain.fft_in_place(column);
+                    }
+                    D::distribute_powers(column, root.pow([j_1]));
+                });
+
+            for (j_2, row) in rows.chunks_mut(width).enumerate() {
+                for (entry, column) in row.iter_mut().zip(&columns) {
+                    *entry = column[j_2];
+                }
+                data.write(start + j_2 * num_columns, row)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Performs steps 3 and 4, on batches of rows of `input`, writing the
+    /// transposed result to `output`. For the IFFT, the coefficient at index
+    /// `k` is finally multiplied by `offset^k`.
+    fn row_pass<T, S1, S2>(
+        &self,
+        input: &mut S1,
+        output: &mut S2,
+        offset: F,
+        inverse: bool,
+    ) -> io::Result<()>
+    where
+        T: DomainCoeff<F>,
+        S1: FftStorage<T> + ?Sized,
+        S2: FftStorage<T> + ?Sized,
+    {
+        let (num_rows, num_columns) = (self.num_rows, self.num_columns);
+        // The batch is held twice, once as rows and once transposed.
+        let batch_size = (self.memory_budget / (2 * num_columns)).min(num_rows);
+        let offset_pow_rows = offset.pow([num_rows as u64]);
+        let mut buffer = vec![T::zero(); batch_size * num_columns];
+
+        for start in (0..num_rows).step_by(batch_size) {
+            let height = batch_size.min(num_rows - start);
+            let buffer = &mut buffer[..height * num_columns];
+            input.read(start * num_columns, buffer)?;
+
+            let mut rows: Vec<Vec<T>> = buffer.chunks(num_columns).map(<[T]>::to_vec).collect();
+            ark_std::cfg_iter_mut!(rows)
+                .enumerate()
+                .for_each(|(r, row)| {
+                    if inverse {
+                        self.row_domain.ifft_in_place(row);
+                        if !offset.is_one() {
+                            let k_2 = (start + r) as u64;
+                            D::distribute_powers_and_mul_by_const(
+                                row,
+                                offset_pow_rows,
+                                offset.pow([k_2]),
+                            );
+                        }
+                    } else {
+                        self.row_domain.fft_in_place(row);
+                    }
+                });
+
+            // The entry in row `k_2` and column `k_1` is the output at index
+            // `k_2 + k_1 * num_rows`.
+            for (k_1, column) in buffer.chunks_mut(height).enumerate() {
+                for (entry, row) in column.iter_mut().zip(&rows) {
+                    *entry = row[k_1];
+                }
+                output.write(start + k_1 * num_rows, column)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FftStorage, FourStepFft};
+    use crate::{
+        ArbitrarySizeEvaluationDomain, EvaluationDomain, GeneralEvaluationDomain,
+        MixedRadixEvaluationDomain, Radix2EvaluationDomain,
+    };
+    use ark_ff::{FftField, UniformRand, Zero};
+    use ark_std::{io, test_rng, vec, vec::*};
+    use ark_test_curves::{bls12_381, bn384_small_two_adicity::Fr as SmallTwoAdicityFr};
+    use bls12_381::Fr;
+
+    /// Slice storage that checks that reads and writes stay within the budget.
+    struct BudgetStorage<'a> {
+        data: &'a mut [Fr],
+        max_access: usize,
+    }
+
+    impl FftStorage<Fr> for BudgetStorage<'_> {
+        fn len(&self) -> usize {
+            self.data.len()
+        }
+
+        fn read(&mut self, start: usize, buf: &mut [Fr]) -> io::Result<()> {
+            assert!(buf.len() <= self.max_access);
+            self.data.read(start, buf)
+        }
+
+        fn write(&mut self, start: usize, buf: &[Fr]) -> io::Result<()> {
+            assert!(buf.len() <= self.max_access);
+            self.data.write(start, buf)
+        }
+    }
+
+    fn check_four_step<D: EvaluationDomain<Fr>>(domain: D, memory_budget: usize) {
+        let rng = &mut test_rng();
+        let size = domain.size();
+        let four_step = FourStepFft::new(domain, memory_budget).unwrap();
+        let (num_rows, num_columns) = four_step.shape();
+        assert_eq!(num_rows * num_columns, size);
+
+        for domain in [domain, domain.get_coset(Fr::GENERATOR).unwrap()] {
+            let four_step = FourStepFft::new(domain, memory_budget).unwrap();
+            let coeffs: Vec<Fr> = (0..size).map(|_| Fr::rand(rng)).collect();
+
+            let mut input = coeffs.clone();
+            let mut evals = vec![Fr::zero(); size];
+            four_step
+                .fft(
+                    &mut BudgetStorage {
+                        data: &mut input,
+                        max_access: memory_budget / 2,
+                    },
+                    &mut evals[..],
+                )
+                .unwrap();
+            assert_eq!(evals, domain.fft(&coeffs));
+
+            let mut result = vec![Fr::zero(); size];
+            four_step.ifft(&mut evals[..], &mut result[..]).unwrap();
+            assert_eq!(result, coeffs);
+        }
+    }
+
+    #[test]
+    fn four_step_matches_fft() {
+        check_four_step(Radix2EvaluationDomain::<Fr>::new(1 << 10).unwrap(), 64);
+        check_four_step(Radix2EvaluationDomain::<Fr>::new(1 << 11).unwrap(), 256);
+        check_four_step(Radix2EvaluationDomain::<Fr>::new(1 << 12).unwrap(), 1 << 13);
+        check_four_step(GeneralEvaluationDomain::<Fr>::new(1 << 9).unwrap(), 100);
+        check_four_step(
+            ArbitrarySizeEvaluationDomain::<Fr>::new(3 * 11 * 19).unwrap(),
+            80,
+        );
+    }
+
+    #[test]
+    fn four_step_small_two_adicity() {
+        let rng = &mut test_rng();
+        let domain =
+            MixedRadixEvaluationDomain::<SmallTwoAdicityFr>::new(2 * 2 * 3 * 3 * 3).unwrap();
+        let four_step = FourStepFft::new(domain, 64).unwrap();
+        let coeffs: Vec<SmallTwoAdicityFr> = (0..domain.size())
+            .map(|_| SmallTwoAdicityFr::rand(rng))
+            .collect();
+        let mut input = coeffs.clone();
+        let mut evals = vec![SmallTwoAdicityFr::zero(); domain.size()];
+        four_step.fft(&mut input[..], &mut evals[..]).unwrap();
+        assert_eq!(evals, domain.fft(&coeffs));
+    }
+
+    #[test]
+    fn budget_too_small() {
+        let domain = Radix2EvaluationDomain::<Fr>::new(1 << 10).unwrap();
+        assert!(FourStepFft::new(domain, 63).is_none());
+        assert_eq!(FourStepFft::new(domain, 64).unwrap().shape(), (32, 32));
+        // A prime size can only be split into itself and a single row or column.
+        let domain = ArbitrarySizeEvaluationDomain::<Fr>::new(19).unwrap();
+        assert!(FourStepFft::new(domain, 1 << 10).is_some());
+        assert!(FourStepFft::new(domain, 20).is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn file_storage() {
+        use super::FileStorage;
+
+        let rng = &mut test_rng();
+        let domain = Radix2EvaluationDomain::<Fr>::new(1 << 10).unwrap();
+        let coeffs: Vec<Fr> = (0..1 << 10).map(|_| Fr::rand(rng)).collect();
+
+        let dir = std::env::temp_dir();
+        let path =
+            |name: &str| dir.join(format!("ark-poly-four-step-{}-{name}", std::process::id()));
+        let open = |name: &str| {
+            std::fs::OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(path(name))
+                .unwrap()
+        };
+        let mut input = FileStorage::new(open("input"), 1 << 10).unwrap();
+        let mut output = FileStorage::new(open("output"), 1 << 10).unwrap();
+        input.write(0, &coeffs).unwrap();
+
+        let four_step = FourStepFft::new(domain, 64).unwrap();
+        four_step.fft(&mut input, &mut output).unwrap();
+        let mut evals = vec![Fr::zero(); 1 << 10];
+        output.read(0, &mut evals).unwrap();
+        assert_eq!(evals, domain.fft(&coeffs));
+
+        std::fs::remove_file(path("input")).unwrap();
+        std::fs::remove_file(path("output")).unwrap();
+    }
+}
@@ -14,11 +14,14 @@ use ark_std::{fmt, hash, rand::Rng, vec, vec::*};
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+pub mod arbitrary_size;
+pub mod four_step;
 pub mod general;
 pub mod mixed_radix;
 pub mod radix2;
 pub(crate) mod utils;
 
+pub use arbitrary_size::ArbitrarySizeEvaluationDomain;
 pub use general::GeneralEvaluationDomain;
 pub use mixed_radix::MixedRadixEvaluationDomain;
 pub use radix2::Radix2EvaluationDomain;
@@ -1,6 +1,6 @@
 use crate::domain::DomainCoeff;
 use ark_ff::{FftField, Field};
-use ark_std::vec::*;
+use ark_std::{vec, vec::*};
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
@@ -9,6 +9,10 @@ use rayon::prelude::*;
 #[cfg(feature = "parallel")]
 const MIN_PARALLEL_CHUNK_SIZE: usize = 1 << 7;
 
+/// Below this length, convolutions are computed naively instead of with
+/// Karatsuba multiplication.
+const KARATSUBA_THRESHOLD: usize = 32;
+
 #[inline]
 pub(crate) fn bitreverse(mut n: u32, l: u32) -> u32 {
     let mut r = 0;
@@ -200,6 +204,55 @@ pub(crate) fn parallel_fft<T: DomainCoeff<F>, F: FftField>(
         .for_each(|(i, a)| *a = tmp[i % num_cosets][i / num_cosets]);
 }
 
+/// Returns `x * c`.
+#[inline]
+pub(crate) fn scale<T: DomainCoeff<F>, F: FftField>(mut x: T, c: F) -> T {
+    x *= c;
+    x
+}
+
+/// Returns the linear convolution of `a` and `b`, which have the same length,
+/// using Karatsuba multiplication.
+pub(crate) fn karatsuba<T: DomainCoeff<F>, F: FftField>(a: &[T], b: &[F]) -> Vec<T> {
+    let n = a.len();
+    debug_assert_eq!(n, b.len());
+    if n == 0 {
+        return Vec::new();
+    }
+    if n < KARATSUBA_THRESHOLD {
+        let mut result = vec![T::zero(); 2 * n - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                result[i + j] += scale(*x, *y);
+            }
+        }
+        return result;
+    }
+
+    // Split into halves of length `h`, padding the upper halves with zeros.
+    let h = n.div_ceil(2);
+    let (a0, b0) = (&a[..h], &b[..h]);
+    let mut a1 = a[h..].to_vec();
+    let mut b1 = b[h..].to_vec();
+    a1.resize(h, T::zero());
+    b1.resize(h, F::zero());
+
+    let z0 = karatsuba(a0, b0);
+    let z2 = karatsuba(&a1, &b1);
+    let a_sum: Vec<T> = a0.iter().zip(&a1).map(|(x, y)| *x + *y).collect();
+    let b_sum: Vec<F> = b0.iter().zip(&b1).map(|(x, y)| *x + y).collect();
+    let z1 = karatsuba(&a_sum, &b_sum);
+
+    let mut result = vec![T::zero(); 4 * h - 1];
+    for (i, ((z0, z1), z2)) in z0.iter().zip(&z1).zip(&z2).enumerate() {
+        result[i] += *z0;
+        result[i + h] += *z1 - *z0 - *z2;
+        result[i + 2 * h] += *z2;
+    }
+    result.truncate(2 * n - 1);
+    result
+}
+
 /// An iterator over the elements of a domain.
 pub struct Elements<F: FftField> {
     pub(crate) cur_elem: F,
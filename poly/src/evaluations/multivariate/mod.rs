@@ -1,4 +1,4 @@
 pub mod multilinear;
 pub use multilinear::{
-    DenseMultilinearExtension, MultilinearExtension, SparseMultilinearExtension,
+    DenseMultilinearExtension, MultilinearExtension, SparseMultilinearExtension, VirtualPolynomial,
 };
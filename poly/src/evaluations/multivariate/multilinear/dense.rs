@@ -1,13 +1,15 @@
 //! Multilinear polynomial represented in dense evaluation form.
 
 use crate::{
-    evaluations::multivariate::multilinear::{swap_bits, MultilinearExtension},
+    evaluations::multivariate::multilinear::{
+        mobius_transform, swap_bits, zeta_transform, MultilinearExtension,
+    },
     Polynomial,
 };
 use ark_ff::{Field, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::{
-    cfg_iter,
+    cfg_iter, cfg_iter_mut,
     fmt::{self, Formatter},
     iter::IntoIterator,
     log2,
@@ -152,6 +154,136 @@ impl<F: Field> DenseMultilinearExtension<F> {
 
         Self::from_evaluations_slice(num_vars as usize, &evaluations)
     }
+
+    /// Construct a new polynomial from its coefficients in the multilinear
+    /// monomial basis, where the index represents the monomial in little
+    /// endian form. For example, `0b1011` represents `x_0 * x_1 * x_3`.
+    ///
+    /// # Example
+    /// ```
+    /// use ark_test_curves::bls12_381::Fr;
+    /// use ark_poly::DenseMultilinearExtension;
+    ///
+    /// // x_0 + 2 * x_1 + 3 * x_0 * x_1 evaluates to [0, 1, 2, 6] over [00, 10, 01, 11]
+    /// let coefficients: Vec<Fr> = vec![0, 1, 2, 3].into_iter().map(Fr::from).collect();
+    /// let mle = DenseMultilinearExtension::from_coefficients_slice(2, &coefficients);
+    /// assert_eq!(mle.evaluations, vec![0, 1, 2, 6].into_iter().map(Fr::from).collect::<Vec<_>>());
+    /// assert_eq!(mle.to_coefficients(), coefficients);
+    /// ```
+    pub fn from_coefficients_slice(num_vars: usize, coefficients: &[F]) -> Self {
+        Self::from_coefficients_vec(num_vars, coefficients.to_vec())
+    }
+
+    /// Construct a new polynomial from its coefficients in the multilinear
+    /// monomial basis, where the index represents the monomial in little
+    /// endian form. For example, `0b1011` represents `x_0 * x_1 * x_3`.
+    pub fn from_coefficients_vec(num_vars: usize, mut coefficients: Vec<F>) -> Self {
+        assert_eq!(
+            coefficients.len(),
+            1 << num_vars,
+            "The size of coefficients should be 2^num_vars."
+        );
+        zeta_transform(&mut coefficients);
+        Self::from_evaluations_vec(num_vars, coefficients)
+    }
+
+    /// Returns the coefficients of `self` in the multilinear monomial basis,
+    /// indexed like in [`Self::from_coefficients_vec`].
+    pub fn to_coefficients(&self) -> Vec<F> {
+        let mut coefficients = self.evaluations.clone();
+        mobius_transform(&mut coefficients);
+        coefficients
+    }
+
+    /// Evaluates the multilinear polynomial with the given coefficients in the
+    /// monomial basis at `point`, without converting it to evaluation form.
+    pub fn evaluate_coefficients(coefficients: &[F], point: &[F]) -> F {
+        assert_eq!(
+            coefficients.len(),
+            1 << point.len(),
+            "The size of coefficients should be 2^num_vars."
+        );
+        // Split off one variable at a time, as `c(x) = c_0(x') + x_0 * c_1(x')`.
+        let mut coefficients = coefficients.to_vec();
+        for (i, r) in point.iter().enumerate() {
+            for b in 0..(1 << (point.len() - i - 1)) {
+                coefficients[b] = coefficients[b << 1] + *r * coefficients[(b << 1) + 1];
+            }
+        }
+        coefficients[0]
+    }
+
+    /// Returns the MLE of `eq(x, point)`, which is one if `x = point` and
+    /// zero elsewhere on the boolean hypercube, i.e.
+    /// `eq(x, r) = prod_i (x_i * r_i + (1 - x_i) * (1 - r_i))`.
+    ///
+    /// # Example
+    /// ```
+    /// use ark_test_curves::bls12_381::Fr;
+    /// use ark_poly::{DenseMultilinearExtension, Polynomial};
+    ///
+    /// let r = vec![Fr::from(3), Fr::from(5)];
+    /// let x = vec![Fr::from(7), Fr::from(11)];
+    /// let eq = DenseMultilinearExtension::eq(&r);
+    /// assert_eq!(eq.evaluate(&x), DenseMultilinearExtension::evaluate_eq(&x, &r));
+    /// ```
+    pub fn eq(point: &[F]) -> Self {
+        let mut evaluations = Vec::with_capacity(1 << point.len());
+        evaluations.push(F::one());
+        // The table over `x_0, ..., x_i` is obtained from the table over
+        // `x_0, ..., x_{i - 1}`, as `x_i` is the highest bit of the index.
+        for r in point {
+            let len = evaluations.len();
+            evaluations.extend_from_within(..);
+            let (low, high) = evaluations.split_at_mut(len);
+            cfg_iter_mut!(low).zip(high).for_each(|(low, high)| {
+                *high *= r;
+                *low -= *high;
+            });
+        }
+        Self::from_evaluations_vec(point.len(), evaluations)
+    }
+
+    /// Evaluates `eq(x, r) = prod_i (x_i * r_i + (1 - x_i) * (1 - r_i))` in
+    /// `O(n)` time, without building its MLE.
+    pub fn evaluate_eq(x: &[F], r: &[F]) -> F {
+        assert_eq!(x.len(), r.len(), "the points must have the same length");
+        x.iter()
+            .zip(r)
+            .map(|(x, r)| {
+                let xr = *x * r;
+                F::one() - x - r + xr.double()
+            })
+            .product()
+    }
+
+    /// Fixes the lowest variable `x_0` of `self` to `r` in place, reducing
+    /// the number of variables by one. This is the same as
+    /// `self.fix_variables(&[r])`, without allocating.
+    pub fn fix_lowest_variable_in_place(&mut self, r: F) {
+        assert!(self.num_vars > 0, "no variable to fix");
+        let half = self.evaluations.len() / 2;
+        for b in 0..half {
+            let left = self.evaluations[b << 1];
+            let right = self.evaluations[(b << 1) + 1];
+            self.evaluations[b] = left + r * (right - left);
+        }
+        self.evaluations.truncate(half);
+        self.num_vars -= 1;
+    }
+
+    /// Fixes the highest variable `x_{n - 1}` of `self` to `r` in place,
+    /// reducing the number of variables by one.
+    pub fn fix_highest_variable_in_place(&mut self, r: F) {
+        assert!(self.num_vars > 0, "no variable to fix");
+        let half = self.evaluations.len() / 2;
+        let (low, high) = self.evaluations.split_at_mut(half);
+        cfg_iter_mut!(low)
+            .zip(high)
+            .for_each(|(low, high)| *low += r * (*high - *low));
+        self.evaluations.truncate(half);
+        self.num_vars -= 1;
+    }
 }
 
 impl<'a, F: Field> IntoIterator for &'a DenseMultilinearExtension<F> {
@@ -374,6 +506,36 @@ impl<'a, F: Field> Mul<&'a F> for &DenseMultilinearExtension<F> {
     }
 }
 
+impl<'a, F: Field> Mul<&'a DenseMultilinearExtension<F>> for &DenseMultilinearExtension<F> {
+    type Output = DenseMultilinearExtension<F>;
+
+    /// Returns the element-wise product of `self` and `rhs` over the boolean
+    /// hypercube, which is the MLE of their product. Note that this is not the
+    /// product of `self` and `rhs` as polynomials, which is not multilinear.
+    fn mul(self, rhs: &'a DenseMultilinearExtension<F>) -> Self::Output {
+        // handle constant case
+        if rhs.num_vars == 0 {
+            return self * &rhs.evaluations[0];
+        }
+        if self.num_vars == 0 {
+            return rhs * &self.evaluations[0];
+        }
+        assert_eq!(self.num_vars, rhs.num_vars);
+        let result: Vec<F> = cfg_iter!(self.evaluations)
+            .zip(&rhs.evaluations)
+            .map(|(a, b)| *a * b)
+            .collect();
+
+        Self::Output::from_evaluations_vec(self.num_vars, result)
+    }
+}
+
+impl<'a, F: Field> MulAssign<&'a Self> for DenseMultilinearExtension<F> {
+    fn mul_assign(&mut self, other: &'a Self) {
+        *self = &*self * other;
+    }
+}
+
 impl<F: Field> MulAssign<F> for DenseMultilinearExtension<F> {
     fn mul_assign(&mut self, scalar: F) {
         *self = &*self * &scalar
@@ -450,7 +612,7 @@ impl<F: Field> Polynomial<F> for DenseMultilinearExtension<F> {
 mod tests {
     use crate::{DenseMultilinearExtension, MultilinearExtension, Polynomial};
     use ark_ff::{Field, One, Zero};
-    use ark_std::{ops::Neg, test_rng, vec::*, UniformRand};
+    use ark_std::{ops::Neg, test_rng, vec, vec::*, UniformRand};
     use ark_test_curves::bls12_381::Fr;
 
     /// utility: evaluate multilinear extension (in form of data array) at a random point
@@ -587,6 +749,104 @@ mod tests {
         }
     }
 
+    #[test]
+    fn eq_polynomial() {
+        let mut rng = test_rng();
+        for nv in 0..8 {
+            let r: Vec<_> = (0..nv).map(|_| Fr::rand(&mut rng)).collect();
+            let eq = DenseMultilinearExtension::eq(&r);
+            assert_eq!(eq.num_vars, nv);
+            assert_eq!(eq.evaluate(&r), evaluate_data_array(&eq.evaluations, &r));
+            for (i, e) in eq.iter().enumerate() {
+                let x: Vec<_> = (0..nv).map(|j| Fr::from(((i >> j) & 1) as u64)).collect();
+                assert_eq!(*e, DenseMultilinearExtension::evaluate_eq(&x, &r));
+            }
+
+            // `eq(x, r)` selects the evaluation at `r` of any MLE.
+            let x: Vec<_> = (0..nv).map(|_| Fr::rand(&mut rng)).collect();
+            assert_eq!(
+                eq.evaluate(&x),
+                DenseMultilinearExtension::evaluate_eq(&x, &r)
+            );
+            let poly = DenseMultilinearExtension::rand(nv, &mut rng);
+            let sum: Fr = poly.iter().zip(&eq).map(|(p, e)| *p * e).sum();
+            assert_eq!(sum, poly.evaluate(&r));
+        }
+    }
+
+    #[test]
+    fn fix_variable_in_place() {
+        let mut rng = test_rng();
+        let poly = DenseMultilinearExtension::rand(10, &mut rng);
+        let point: Vec<_> = (0..10).map(|_| Fr::rand(&mut rng)).collect();
+        let expected = poly.evaluate(&point);
+
+        let mut lowest = poly.clone();
+        let mut highest = poly.clone();
+        for (i, r) in point.iter().enumerate() {
+            lowest.fix_lowest_variable_in_place(*r);
+            assert_eq!(lowest, poly.fix_variables(&point[..=i]));
+            highest.fix_highest_variable_in_place(point[9 - i]);
+            assert_eq!(highest.num_vars, 9 - i);
+        }
+        assert_eq!(lowest.evaluations, vec![expected]);
+        assert_eq!(highest.evaluations, vec![expected]);
+    }
+
+    #[test]
+    fn element_wise_product() {
+        let mut rng = test_rng();
+        let poly1 = DenseMultilinearExtension::<Fr>::rand(8, &mut rng);
+        let poly2 = DenseMultilinearExtension::rand(8, &mut rng);
+        let product = &poly1 * &poly2;
+        for i in 0..1 << 8 {
+            assert_eq!(product[i], poly1[i] * poly2[i]);
+        }
+
+        let constant = DenseMultilinearExtension::from_evaluations_vec(0, vec![Fr::from(3u64)]);
+        assert_eq!(&poly1 * &constant, &poly1 * &Fr::from(3u64));
+        assert_eq!(&constant * &poly1, &poly1 * &Fr::from(3u64));
+        assert_eq!(
+            &poly1 * &DenseMultilinearExtension::zero(),
+            DenseMultilinearExtension::zero()
+        );
+
+        let mut poly = poly1;
+        poly *= &poly2;
+        assert_eq!(poly, product);
+    }
+
+    #[test]
+    fn coefficient_form() {
+        let mut rng = test_rng();
+        for nv in 0..10 {
+            let poly = DenseMultilinearExtension::<Fr>::rand(nv, &mut rng);
+            let coefficients = poly.to_coefficients();
+            assert_eq!(
+                DenseMultilinearExtension::from_coefficients_slice(nv, &coefficients),
+                poly
+            );
+
+            let point: Vec<_> = (0..nv).map(|_| Fr::rand(&mut rng)).collect();
+            let expected = poly.evaluate(&point);
+            assert_eq!(
+                DenseMultilinearExtension::evaluate_coefficients(&coefficients, &point),
+                expected
+            );
+            // Evaluate the monomials directly.
+            let naive: Fr = coefficients
+                .iter()
+                .enumerate()
+                .map(|(s, c)| {
+                    (0..nv)
+                        .filter(|i| (s >> i) & 1 == 1)
+                        .fold(*c, |acc, i| acc * point[i])
+                })
+                .sum();
+            assert_eq!(naive, expected);
+        }
+    }
+
     #[test]
     fn concat_two_equal_polys() {
         let mut rng = test_rng();
@@ -1,10 +1,13 @@
 mod dense;
 mod sparse;
+mod virtual_polynomial;
 
 pub use dense::DenseMultilinearExtension;
 pub use sparse::SparseMultilinearExtension;
+pub use virtual_polynomial::VirtualPolynomial;
 
 use ark_std::{
+    cfg_chunks_mut, cfg_iter_mut,
     fmt::Debug,
     hash::Hash,
     ops::{Add, AddAssign, Index, Neg, SubAssign},
@@ -18,6 +21,9 @@ use ark_std::rand::Rng;
 
 use crate::Polynomial;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 #[cfg(all(
     target_has_atomic = "8",
     target_has_atomic = "16",
@@ -86,6 +92,51 @@ pub trait MultilinearExtension<F: Field>:
     fn to_evaluations(&self) -> Vec<F>;
 }
 
+/// Converts the evaluations of a multilinear polynomial over the boolean
+/// hypercube into its coefficients in the monomial basis, in place, using the
+/// Möbius transform. Both are indexed in little-endian order, so that the
+/// coefficient at index `0b1011` is that of `x_0 * x_1 * x_3`.
+///
+/// # Panics
+///
+/// Panics if `values.len()` is not a power of two.
+pub fn mobius_transform<F: Field>(values: &mut [F]) {
+    assert!(
+        values.len().is_power_of_two(),
+        "the number of values must be a power of two"
+    );
+    let mut half = 1;
+    while half < values.len() {
+        cfg_chunks_mut!(values, 2 * half).for_each(|chunk| {
+            let (low, high) = chunk.split_at_mut(half);
+            cfg_iter_mut!(high).zip(low).for_each(|(h, l)| *h -= *l);
+        });
+        half *= 2;
+    }
+}
+
+/// Converts the coefficients of a multilinear polynomial in the monomial
+/// basis into its evaluations over the boolean hypercube, in place, using the
+/// zeta transform. This is the inverse of [`mobius_transform`].
+///
+/// # Panics
+///
+/// Panics if `values.len()` is not a power of two.
+pub fn zeta_transform<F: Field>(values: &mut [F]) {
+    assert!(
+        values.len().is_power_of_two(),
+        "the number of values must be a power of two"
+    );
+    let mut half = 1;
+    while half < values.len() {
+        cfg_chunks_mut!(values, 2 * half).for_each(|chunk| {
+            let (low, high) = chunk.split_at_mut(half);
+            cfg_iter_mut!(high).zip(low).for_each(|(h, l)| *h += *l);
+        });
+        half *= 2;
+    }
+}
+
 /// swap the bits of `x` from position `a..a+n` to `b..b+n` and from `b..b+n` to `a..a+n` in little endian order
 pub(crate) const fn swap_bits(x: usize, a: usize, b: usize, n: usize) -> usize {
     let a_bits = (x >> a) & ((1usize << n) - 1);
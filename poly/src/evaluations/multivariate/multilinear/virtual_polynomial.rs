@@ -0,0 +1,280 @@
+//! A sum of products of multilinear polynomials, as used by sumcheck provers.
+
+use crate::DenseMultilinearExtension;
+use ark_ff::Field;
+use ark_std::{vec, vec::*};
+
+/// Represents the polynomial `sum_j c_j * prod_{i in S_j} f_i(x)` for dense
+/// MLEs `f_i` over the same number of variables, coefficients `c_j` and sets of
+/// indices `S_j`. The polynomial is not multilinear in general, and its degree
+/// in each variable is at most the size of the largest product.
+///
+/// The MLEs are stored once and referenced by index, so that an MLE appearing
+/// in several products is only fixed once per sumcheck round.
+///
+/// # Example
+/// ```
+/// use ark_test_curves::bls12_381::Fr;
+/// use ark_poly::{DenseMultilinearExtension, MultilinearExtension, VirtualPolynomial};
+/// use ark_std::test_rng;
+///
+/// let rng = &mut test_rng();
+/// let (f, g) = (DenseMultilinearExtension::rand(3, rng), DenseMultilinearExtension::rand(3, rng));
+///
+/// // p(x) = 2 * f(x) * g(x) + f(x)
+/// let mut p = VirtualPolynomial::new(3);
+/// let (f, g) = (p.add_mle(f), p.add_mle(g));
+/// p.add_product(Fr::from(2u64), &[f, g]);
+/// p.add_product(Fr::from(1u64), &[f]);
+///
+/// // In each round, the prover sends the round polynomial, and fixes the
+/// // lowest variable to the verifier's challenge.
+/// let mut claim = p.sum_over_hypercube();
+/// for challenge in [Fr::from(5u64), Fr::from(7u64), Fr::from(11u64)] {
+///     let round = p.round_polynomial();
+///     assert_eq!(round[0] + round[1], claim);
+///     p.fix_lowest_variable(challenge);
+///     claim = p.sum_over_hypercube();
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VirtualPolynomial<F: Field> {
+    num_vars: usize,
+    /// The MLEs appearing in the products.
+    mles: Vec<DenseMultilinearExtension<F>>,
+    /// The coefficient and the indices into `mles` of each product.
+    products: Vec<(F, Vec<usize>)>,
+}
+
+impl<F: Field> VirtualPolynomial<F> {
+    /// Returns the zero polynomial in `num_vars` variables.
+    pub const fn new(num_vars: usize) -> Self {
+        Self {
+            num_vars,
+            mles: Vec::new(),
+            products: Vec::new(),
+        }
+    }
+
+    /// Returns the number of variables of `self`.
+    pub const fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Returns the size of the largest product, which bounds the degree of
+    /// `self` in each variable.
+    pub fn max_degree(&self) -> usize {
+        self.products
+            .iter()
+            .map(|(_, indices)| indices.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the MLEs of `self`, in the order they were added.
+    pub fn mles(&self) -> &[DenseMultilinearExtension<F>] {
+        &self.mles
+    }
+
+    /// Returns the coefficient and the MLE indices of each product.
+    pub fn products(&self) -> &[(F, Vec<usize>)] {
+        &self.products
+    }
+
+    /// Adds `mle` to the MLEs of `self`, and returns its index for use in
+    /// [`Self::add_product`].
+    pub fn add_mle(&mut self, mle: DenseMultilinearExtension<F>) -> usize {
+        assert_eq!(
+            mle.num_vars, self.num_vars,
+            "the MLE must have the same number of variables as the virtual polynomial"
+        );
+        self.mles.push(mle);
+        self.mles.len() - 1
+    }
+
+    /// Adds `coefficient` times the product of the MLEs at `indices` to
+    /// `self`. An MLE may appear several times in a product.
+    pub fn add_product(&mut self, coefficient: F, indices: &[usize]) {
+        assert!(
+            indices.iter().all(|i| *i < self.mles.len()),
+            "invalid MLE index"
+        );
+        self.products.push((coefficient, indices.to_vec()));
+    }
+
+    /// Evaluates `self` at `point`.
+    pub fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(point.len(), self.num_vars, "invalid size of point");
+        let evals: Vec<F> = self
+            .mles
+            .iter()
+            .map(|mle| {
+                let mut mle = mle.clone();
+                for r in point {
+                    mle.fix_lowest_variable_in_place(*r);
+                }
+                mle.evaluations[0]
+            })
+            .collect();
+        self.products
+            .iter()
+            .map(|(coefficient, indices)| {
+                indices.iter().fold(*coefficient, |acc, i| acc * evals[*i])
+            })
+            .sum()
+    }
+
+    /// Returns the sum of the evaluations of `self` over the boolean
+    /// hypercube.
+    pub fn sum_over_hypercube(&self) -> F {
+        (0..1 << self.num_vars)
+            .map(|x| {
+                self.products
+                    .iter()
+                    .map(|(coefficient, indices)| {
+                        indices
+                            .iter()
+                            .fold(*coefficient, |acc, i| acc * self.mles[*i][x])
+                    })
+                    .sum::<F>()
+            })
+            .sum()
+    }
+
+    /// Returns the evaluations at `0, 1, ..., self.max_degree()` of the round
+    /// polynomial `g(t) = sum_{x in {0,1}^(n - 1)} p(t, x)` of a sumcheck for
+    /// the sum of `self` over the boolean hypercube, where `t` is the lowest
+    /// variable.
+    ///
+    /// After receiving the verifier's challenge `r`, the prover continues with
+    /// [`Self::fix_lowest_variable`]. A verifier can evaluate `g(r)` from the
+    /// returned evaluations by Lagrange interpolation.
+    pub fn round_polynomial(&self) -> Vec<F> {
+        assert!(self.num_vars > 0, "no variable left to sum over");
+        let degree = self.max_degree();
+        let mut sums = vec![F::zero(); degree + 1];
+        // Evaluations of a product at `0, 1, ..., degree`, and of one of its
+        // MLEs along the line through its values at `t = 0` and `t = 1`.
+        let mut product = vec![F::zero(); degree + 1];
+        let mut line = vec![F::zero(); degree + 1];
+        for b in 0..1 << (self.num_vars - 1) {
+            for (coefficient, indices) in &self.products {
+                product.fill(*coefficient);
+                for i in indices {
+                    let mle = &self.mles[*i];
+                    let (low, high) = (mle[b << 1], mle[(b << 1) + 1]);
+                    let step = high - low;
+                    line[0] = low;
+                    for t in 1..=degree {
+                        line[t] = line[t - 1] + step;
+                    }
+                    product.iter_mut().zip(&line).for_each(|(p, l)| *p *= l);
+                }
+                sums.iter_mut().zip(&product).for_each(|(s, p)| *s += p);
+            }
+        }
+        sums
+    }
+
+    /// Fixes the lowest variable of `self` to `r` in place, reducing the
+    /// number of variables by one.
+    pub fn fix_lowest_variable(&mut self, r: F) {
+        assert!(self.num_vars > 0, "no variable to fix");
+        self.mles
+            .iter_mut()
+            .for_each(|mle| mle.fix_lowest_variable_in_place(r));
+        self.num_vars -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VirtualPolynomial;
+    use crate::{DenseMultilinearExtension, MultilinearExtension, Polynomial};
+    use ark_ff::{One, UniformRand, Zero};
+    use ark_std::{test_rng, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+
+    /// Evaluates the polynomial with the given evaluations at `0, 1, ...` at `r`.
+    fn interpolate_at(evals: &[Fr], r: Fr) -> Fr {
+        let points: Vec<Fr> = (0..evals.len() as u64).map(Fr::from).collect();
+        evals
+            .iter()
+            .zip(&points)
+            .map(|(eval, x_i)| {
+                points
+                    .iter()
+                    .filter(|x_j| *x_j != x_i)
+                    .fold(*eval, |acc, x_j| acc * (r - x_j) / (*x_i - x_j))
+            })
+            .sum()
+    }
+
+    fn rand_virtual_polynomial(num_vars: usize) -> VirtualPolynomial<Fr> {
+        let rng = &mut test_rng();
+        let mut poly = VirtualPolynomial::new(num_vars);
+        for _ in 0..4 {
+            poly.add_mle(DenseMultilinearExtension::rand(num_vars, rng));
+        }
+        poly.add_product(Fr::rand(rng), &[0, 1, 2]);
+        poly.add_product(Fr::rand(rng), &[3]);
+        poly.add_product(Fr::rand(rng), &[1, 1, 3, 0]);
+        poly
+    }
+
+    #[test]
+    fn sumcheck() {
+        let rng = &mut test_rng();
+        let num_vars = 6;
+        let mut poly = rand_virtual_polynomial(num_vars);
+        let original = poly.clone();
+        assert_eq!(poly.max_degree(), 4);
+
+        let mut claim = poly.sum_over_hypercube();
+        let mut challenges = Vec::new();
+        for _ in 0..num_vars {
+            let round = poly.round_polynomial();
+            assert_eq!(round.len(), 5);
+            assert_eq!(round[0] + round[1], claim);
+
+            let r = Fr::rand(rng);
+            claim = interpolate_at(&round, r);
+            challenges.push(r);
+            poly.fix_lowest_variable(r);
+            assert_eq!(poly.sum_over_hypercube(), claim);
+        }
+        assert_eq!(poly.num_vars(), 0);
+        assert_eq!(original.evaluate(&challenges), claim);
+    }
+
+    #[test]
+    fn evaluate_matches_mles() {
+        let rng = &mut test_rng();
+        let poly = rand_virtual_polynomial(5);
+        let point: Vec<Fr> = (0..5).map(|_| Fr::rand(rng)).collect();
+        let evals: Vec<Fr> = poly.mles().iter().map(|mle| mle.evaluate(&point)).collect();
+        let expected: Fr = poly
+            .products()
+            .iter()
+            .map(|(c, indices)| *c * indices.iter().map(|i| evals[*i]).product::<Fr>())
+            .sum();
+        assert_eq!(poly.evaluate(&point), expected);
+
+        // On the hypercube, the product of MLEs agrees with their element-wise product.
+        let product = &(&poly.mles()[0] * &poly.mles()[1]) * &poly.mles()[2];
+        let mut single = VirtualPolynomial::new(5);
+        let index = single.add_mle(product);
+        single.add_product(Fr::one(), &[index]);
+        let mut triple = VirtualPolynomial::new(5);
+        for mle in &poly.mles()[..3] {
+            triple.add_mle(mle.clone());
+        }
+        triple.add_product(Fr::one(), &[0, 1, 2]);
+        assert_eq!(single.sum_over_hypercube(), triple.sum_over_hypercube());
+        assert!(!single.sum_over_hypercube().is_zero());
+        assert_eq!(
+            VirtualPolynomial::<Fr>::new(3).sum_over_hypercube(),
+            Fr::zero()
+        );
+    }
+}
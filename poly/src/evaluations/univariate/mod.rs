@@ -14,6 +14,9 @@ use ark_std::{
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+mod reed_solomon;
+pub use reed_solomon::ReedSolomonCode;
+
 /// Stores a UV polynomial in evaluation form.
 #[derive(Clone, PartialEq, Eq, Hash, Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Evaluations<F: FftField, D: EvaluationDomain<F> = GeneralEvaluationDomain<F>> {
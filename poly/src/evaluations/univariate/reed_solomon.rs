@@ -0,0 +1,336 @@
+//! Reed–Solomon codes over the elements of an evaluation domain.
+
+use crate::{
+    univariate::{partial_xgcd, DensePolynomial, SubproductTree},
+    DenseUVPolynomial, EvaluationDomain, Evaluations, GeneralEvaluationDomain, Polynomial,
+};
+use ark_ff::FftField;
+use ark_std::{marker::PhantomData, vec, vec::*, Zero};
+
+/// The Reed–Solomon code of dimension `k` over a domain `D` of size `n`, whose
+/// codewords are the evaluations over `D` of the polynomials of degree less
+/// than `k`. Its rate is `k / n`, and its minimum distance is `n - k + 1`.
+///
+/// Encoding a polynomial given in evaluation form over a smaller domain is a
+/// low-degree extension (LDE), and using a coset of a subgroup as `D` gives a
+/// coset LDE.
+///
+/// # Example
+/// ```
+/// use ark_poly::{
+///     univariate::DensePolynomial, DenseUVPolynomial, EvaluationDomain, Radix2EvaluationDomain,
+///     ReedSolomonCode,
+/// };
+/// use ark_std::test_rng;
+/// use ark_test_curves::bls12_381::Fr;
+///
+/// // A code of rate 1/4 on a coset.
+/// let code = ReedSolomonCode::<Fr, Radix2EvaluationDomain<Fr>>::with_blowup_factor(16, 4)
+///     .unwrap()
+///     .get_coset(Fr::from(7u64))
+///     .unwrap();
+/// let poly = DensePolynomial::rand(15, &mut test_rng());
+/// let mut codeword = code.encode(&poly);
+///
+/// // Corrupt up to `(n - k) / 2` evaluations, and erase some others.
+/// codeword.evals[3] += Fr::from(1u64);
+/// codeword.evals[40] = Fr::from(0u64);
+/// let mut received: Vec<Option<Fr>> = codeword.evals.iter().copied().map(Some).collect();
+/// received[10] = None;
+/// assert_eq!(code.decode_with_erasures(&received), Some(poly));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ReedSolomonCode<F: FftField, D: EvaluationDomain<F> = GeneralEvaluationDomain<F>> {
+    domain: D,
+    dimension: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F: FftField, D: EvaluationDomain<F>> ReedSolomonCode<F, D> {
+    /// Returns the code of dimension `dimension` over `domain`, or `None` if
+    /// the dimension is zero or exceeds the size of the domain.
+    pub fn new(domain: D, dimension: usize) -> Option<Self> {
+        (dimension > 0 && dimension <= domain.size()).then_some(Self {
+            domain,
+            dimension,
+            _field: PhantomData,
+        })
+    }
+
+    /// Returns the code of dimension `dimension` over the domain of type `D`
+    /// for `dimension * blowup_factor` evaluations.
+    ///
+    /// The rate is at most `1 / blowup_factor`, and can be lower if the domain
+    /// is larger than requested.
+    pub fn with_blowup_factor(dimension: usize, blowup_factor: usize) -> Option<Self> {
+        Self::new(D::new(dimension.checked_mul(blowup_factor)?)?, dimension)
+    }
+
+    /// Returns the same code over the coset of the domain by `offset`.
+    pub fn get_coset(&self, offset: F) -> Option<Self> {
+        Self::new(self.domain.get_coset(offset)?, self.dimension)
+    }
+
+    /// Returns the domain of `self`.
+    pub const fn domain(&self) -> D {
+        self.domain
+    }
+
+    /// Returns the dimension `k` of `self`.
+    pub const fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Returns the length `n` of the codewords of `self`.
+    pub fn length(&self) -> usize {
+        self.domain.size()
+    }
+
+    /// Returns the minimum distance `n - k + 1` of `self`.
+    pub fn minimum_distance(&self) -> usize {
+        self.length() - self.dimension + 1
+    }
+
+    /// Returns the codeword of `poly`, which must have degree less than the
+    /// dimension.
+    pub fn encode(&self, poly: &DensePolynomial<F>) -> Evaluations<F, D> {
+        assert!(
+            poly.coeffs.len() <= self.dimension,
+            "the degree of the polynomial must be less than the dimension"
+        );
+        poly.evaluate_over_domain_by_ref(self.domain)
+    }
+
+    /// Returns the low-degree extension of `evals`, i.e. the codeword of the
+    /// polynomial interpolating them. Their domain must not be larger than the
+    /// dimension.
+    pub fn low_degree_extension<D2: EvaluationDomain<F>>(
+        &self,
+        evals: &Evaluations<F, D2>,
+    ) -> Evaluations<F, D> {
+        assert!(
+            evals.domain().size() <= self.dimension,
+            "the evaluation domain must not be larger than the dimension"
+        );
+        self.encode(&evals.interpolate_by_ref())
+    }
+
+    /// Recovers the polynomial from a codeword in which the evaluations given
+    /// as `None` are erased, using the vanishing polynomial `Z` of the erased
+    /// points.
+    ///
+    /// The evaluations of `P * Z` over the domain are those of the codeword
+    /// times `Z`, and zero at the erased points, so `P` is the quotient of
+    /// their interpolation by `Z`. This requires at least `k` evaluations, and
+    /// returns `None` if there are fewer or the evaluations are inconsistent.
+    pub fn decode_erasures(&self, received: &[Option<F>]) -> Option<DensePolynomial<F>> {
+        let (product, erasure_locator) = self.erasure_product(received)?;
+        divide_exactly(&product.interpolate(), &erasure_locator, self.dimension)
+    }
+
+    /// Recovers the polynomial from a codeword with at most `(n - k) / 2`
+    /// errors using Gao's algorithm, or returns `None` if there are more.
+    pub fn decode(&self, received: &Evaluations<F, D>) -> Option<DensePolynomial<F>> {
+        let received: Vec<Option<F>> = received.evals.iter().copied().map(Some).collect();
+        self.decode_with_erasures(&received)
+    }
+
+    /// Recovers the polynomial from a codeword with `e` erased evaluations,
+    /// given as `None`, and at most `(n - k - e) / 2` errors, or returns `None`
+    /// if there are more.
+    ///
+    /// As in [`Self::decode_erasures`], this decodes `P * Z` for the vanishing
+    /// polynomial `Z` of the erased points, in the code of dimension `k + e`,
+    /// using Gao's algorithm. That is, it runs the extended Euclidean algorithm
+    /// on the vanishing polynomial of the domain and the interpolation of the
+    /// received word until the remainder `g` has degree less than
+    /// `(n + k + e) / 2`, and `P * Z` is `g / v` for the Bézout coefficient
+    /// `v` of the received word, which is the error locator.
+    pub fn decode_with_erasures(&self, received: &[Option<F>]) -> Option<DensePolynomial<F>> {
+        let (product, erasure_locator) = self.erasure_product(received)?;
+        let product = product.interpolate();
+        let dimension = self.dimension + erasure_locator.degree();
+        let vanishing_polynomial = DensePolynomial::from(self.domain.vanishing_polynomial());
+
+        let degree_bound = (self.length() + dimension).div_ceil(2);
+        let (g, error_locator) = partial_xgcd(&vanishing_polynomial, &product, degree_bound);
+        let product = divide_exactly(&g, &error_locator, dimension)?;
+        divide_exactly(&product, &erasure_locator, self.dimension)
+    }
+
+    /// Returns the received word times the vanishing polynomial `Z` of the
+    /// erased points, which is zero at the erased points, together with `Z`.
+    /// Returns `None` if fewer than `k` evaluations are not erased.
+    fn erasure_product(
+        &self,
+        received: &[Option<F>],
+    ) -> Option<(Evaluations<F, D>, DensePolynomial<F>)> {
+        assert_eq!(
+            received.len(),
+            self.length(),
+            "the received word must have the length of the code"
+        );
+        let erased_points: Vec<F> = received
+            .iter()
+            .enumerate()
+            .filter(|(_, eval)| eval.is_none())
+            .map(|(i, _)| self.domain.element(i))
+            .collect();
+        if self.length() - erased_points.len() < self.dimension {
+            return None;
+        }
+
+        let erasure_locator = if erased_points.is_empty() {
+            DensePolynomial::from_coefficients_vec(vec![F::one()])
+        } else {
+            SubproductTree::new(&erased_points)
+                .vanishing_polynomial()
+                .clone()
+        };
+        let mut product = erasure_locator.evaluate_over_domain_by_ref(self.domain);
+        for (eval, received) in product.evals.iter_mut().zip(received) {
+            *eval = received.map_or(F::zero(), |received| *eval * received);
+        }
+        Some((product, erasure_locator))
+    }
+}
+
+/// Returns `a / b` if the division is exact and the quotient has degree less
+/// than `dimension`.
+fn divide_exactly<F: FftField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+    dimension: usize,
+) -> Option<DensePolynomial<F>> {
+    if b.is_zero() {
+        return None;
+    }
+    let (quotient, remainder) = a.divide_with_q_and_r_fast(b);
+    (remainder.is_zero() && quotient.coeffs.len() <= dimension).then_some(quotient)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReedSolomonCode;
+    use crate::{
+        univariate::DensePolynomial, DenseUVPolynomial, EvaluationDomain, Evaluations,
+        GeneralEvaluationDomain, Polynomial, Radix2EvaluationDomain,
+    };
+    use ark_ff::{Field, One};
+    use ark_std::{rand::seq::SliceRandom, test_rng, vec, vec::*};
+    use ark_test_curves::bls12_381::Fr;
+
+    type Code = ReedSolomonCode<Fr, Radix2EvaluationDomain<Fr>>;
+
+    /// Erases `num_erasures` and corrupts `num_errors` random evaluations of
+    /// `codeword`.
+    fn corrupt<F: Field>(codeword: &[F], num_erasures: usize, num_errors: usize) -> Vec<Option<F>> {
+        let rng = &mut test_rng();
+        let mut positions: Vec<usize> = (0..codeword.len()).collect();
+        positions.shuffle(rng);
+        let mut received: Vec<Option<F>> = codeword.iter().copied().map(Some).collect();
+        for i in &positions[..num_erasures] {
+            received[*i] = None;
+        }
+        for i in &positions[num_erasures..num_erasures + num_errors] {
+            received[*i] = Some(codeword[*i] + F::rand(rng));
+        }
+        received
+    }
+
+    #[test]
+    fn encode_and_low_degree_extension() {
+        let rng = &mut test_rng();
+        let code = Code::with_blowup_factor(16, 4).unwrap();
+        assert_eq!(code.length(), 64);
+        assert_eq!(code.minimum_distance(), 49);
+
+        let poly = DensePolynomial::<Fr>::rand(15, rng);
+        let codeword = code.encode(&poly);
+        for (x, eval) in code.domain().elements().zip(&codeword.evals) {
+            assert_eq!(poly.evaluate(&x), *eval);
+        }
+
+        let small = Radix2EvaluationDomain::<Fr>::new(16).unwrap();
+        let coset_code = code.get_coset(Fr::from(5u64)).unwrap();
+        let lde = coset_code.low_degree_extension(&poly.evaluate_over_domain_by_ref(small));
+        for (x, eval) in coset_code.domain().elements().zip(&lde.evals) {
+            assert_eq!(x.pow([64]), Fr::from(5u64).pow([64]));
+            assert_eq!(poly.evaluate(&x), *eval);
+        }
+
+        // Any domain works for the evaluations.
+        let general = GeneralEvaluationDomain::<Fr>::new(8).unwrap();
+        let low_poly = DensePolynomial::<Fr>::rand(7, rng);
+        let evals = Evaluations::from_vec_and_domain(general.fft(&low_poly.coeffs), general);
+        assert_eq!(code.low_degree_extension(&evals), code.encode(&low_poly));
+    }
+
+    #[test]
+    fn decode_erasures() {
+        let rng = &mut test_rng();
+        for code in [
+            Code::with_blowup_factor(16, 4).unwrap(),
+            Code::with_blowup_factor(16, 4)
+                .unwrap()
+                .get_coset(Fr::from(7u64))
+                .unwrap(),
+        ] {
+            let poly = DensePolynomial::<Fr>::rand(15, rng);
+            let codeword = code.encode(&poly);
+            for num_erasures in [0, 1, 20, 48] {
+                let received = corrupt(&codeword.evals, num_erasures, 0);
+                assert_eq!(code.decode_erasures(&received), Some(poly.clone()));
+            }
+            assert_eq!(code.decode_erasures(&corrupt(&codeword.evals, 49, 0)), None);
+            // More evaluations than needed must be consistent.
+            assert_eq!(code.decode_erasures(&corrupt(&codeword.evals, 40, 1)), None);
+        }
+    }
+
+    #[test]
+    fn decode_erasures_without_fft() {
+        // The multiplicative group of this field has two-adicity 6, so the
+        // code uses a domain of size `3 * 149`, and the erasure locator of
+        // many erasures is computed without FFTs.
+        use crate::ArbitrarySizeEvaluationDomain;
+        use ark_test_curves::secp256k1::Fr;
+
+        let rng = &mut test_rng();
+        let domain = ArbitrarySizeEvaluationDomain::<Fr>::new(3 * 149).unwrap();
+        let code = ReedSolomonCode::new(domain, 100).unwrap();
+        let poly = DensePolynomial::<Fr>::rand(99, rng);
+        let codeword = code.encode(&poly);
+        let received = corrupt(&codeword.evals, 300, 0);
+        assert_eq!(code.decode_erasures(&received), Some(poly));
+    }
+
+    #[test]
+    fn decode_errors() {
+        let rng = &mut test_rng();
+        let code = Code::with_blowup_factor(16, 4)
+            .unwrap()
+            .get_coset(Fr::from(3u64))
+            .unwrap();
+        let poly = DensePolynomial::<Fr>::rand(15, rng);
+        let codeword = code.encode(&poly);
+        assert_eq!(code.decode(&codeword), Some(poly.clone()));
+
+        for (num_erasures, num_errors) in [(0, 1), (0, 24), (10, 19), (47, 0), (45, 1)] {
+            let received = corrupt(&codeword.evals, num_erasures, num_errors);
+            assert_eq!(code.decode_with_erasures(&received), Some(poly.clone()));
+        }
+        for (num_erasures, num_errors) in [(0, 25), (10, 20), (48, 1)] {
+            let received = corrupt(&codeword.evals, num_erasures, num_errors);
+            assert_ne!(code.decode_with_erasures(&received), Some(poly.clone()));
+        }
+
+        // The zero polynomial and constants decode too.
+        let constant = DensePolynomial::from_coefficients_vec(vec![Fr::one()]);
+        let received = corrupt(&code.encode(&constant).evals, 5, 20);
+        assert_eq!(code.decode_with_erasures(&received), Some(constant));
+        let zero = DensePolynomial::from_coefficients_vec(vec![]);
+        let received = corrupt(&code.encode(&zero).evals, 0, 24);
+        assert_eq!(code.decode_with_erasures(&received), Some(zero));
+    }
+}
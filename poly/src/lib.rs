@@ -10,13 +10,15 @@ pub mod evaluations;
 pub mod polynomial;
 
 pub use domain::{
-    EvaluationDomain, GeneralEvaluationDomain, MixedRadixEvaluationDomain, Radix2EvaluationDomain,
+    ArbitrarySizeEvaluationDomain, EvaluationDomain, GeneralEvaluationDomain,
+    MixedRadixEvaluationDomain, Radix2EvaluationDomain,
 };
 pub use evaluations::{
     multivariate::multilinear::{
         DenseMultilinearExtension, MultilinearExtension, SparseMultilinearExtension,
+        VirtualPolynomial,
     },
-    univariate::Evaluations,
+    univariate::{Evaluations, ReedSolomonCode},
 };
 pub use polynomial::{multivariate, univariate, DenseMVPolynomial, DenseUVPolynomial, Polynomial};
 
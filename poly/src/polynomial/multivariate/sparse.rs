@@ -1,6 +1,7 @@
 //! A sparse multivariate polynomial represented in coefficient form.
 use crate::{
     multivariate::{SparseTerm, Term},
+    univariate::DensePolynomial,
     DenseMVPolynomial, Polynomial,
 };
 use ark_ff::{Field, Zero};
@@ -9,7 +10,7 @@ use ark_std::{
     cfg_into_iter,
     cmp::Ordering,
     fmt,
-    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     rand::Rng,
     vec,
     vec::*,
@@ -35,6 +36,203 @@ impl<F: Field, T: Term> SparsePolynomial<F, T> {
     fn remove_zeros(&mut self) {
         self.terms.retain(|(c, _)| !c.is_zero());
     }
+
+    /// Constructs a new polynomial from a list of terms in any order, adding
+    /// the coefficients of duplicate terms.
+    fn from_terms(num_vars: usize, mut terms: Vec<(F, T)>) -> Self {
+        // Ensure that terms are in ascending order.
+        terms.sort_by(|(_, t1), (_, t2)| t1.cmp(t2));
+        // If any terms are duplicated, add them together
+        let mut terms_dedup: Vec<(F, T)> = Vec::new();
+        for (coeff, term) in terms {
+            // Assert correct number of indeterminates
+            assert!(
+                term.iter().all(|(var, _)| *var < num_vars),
+                "Invalid number of indeterminates"
+            );
+
+            if let Some((prev_coeff, prev_term)) = terms_dedup.last_mut() {
+                // If terms match, add the coefficients.
+                if prev_term == &term {
+                    *prev_coeff += coeff;
+                    continue;
+                }
+            }
+
+            terms_dedup.push((coeff, term));
+        }
+        let mut result = Self {
+            num_vars,
+            terms: terms_dedup,
+        };
+        // Remove any terms with zero coefficients
+        result.remove_zeros();
+        result
+    }
+
+    /// Returns the constant polynomial `c` in `num_vars` variables.
+    fn constant(num_vars: usize, c: F) -> Self {
+        Self::from_terms(num_vars, vec![(c, T::new(vec![]))])
+    }
+
+    /// Returns the highest power of the variable `var` in `self`.
+    pub fn degree_in(&self, var: usize) -> usize {
+        self.terms
+            .iter()
+            .filter_map(|(_, term)| term.iter().find(|(v, _)| *v == var).map(|(_, p)| *p))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Returns the highest power of each of the `self.num_vars` variables in
+    /// `self`.
+    pub fn degrees(&self) -> Vec<usize> {
+        let mut degrees = vec![0; self.num_vars];
+        for (_, term) in &self.terms {
+            for (var, power) in term.iter() {
+                degrees[*var] = degrees[*var].max(*power);
+            }
+        }
+        degrees
+    }
+
+    /// Returns `self` raised to the power `exp`.
+    pub fn pow(&self, mut exp: usize) -> Self {
+        let mut result = Self::constant(self.num_vars, F::one());
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Fixes the variables `var` to `value` for each `(var, value)` in
+    /// `assignments`, and returns the resulting polynomial in the remaining
+    /// variables.
+    ///
+    /// The remaining variables keep their indices, so `num_vars` is unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use ark_poly::{
+    ///     polynomial::multivariate::{SparsePolynomial, SparseTerm, Term},
+    ///     DenseMVPolynomial,
+    /// };
+    /// use ark_test_curves::bls12_381::Fq;
+    ///
+    /// // x_0 * x_1 + x_2 at x_1 = 3 is 3 * x_0 + x_2
+    /// let poly = SparsePolynomial::from_coefficients_vec(
+    ///     3,
+    ///     vec![
+    ///         (Fq::from(1), SparseTerm::new(vec![(0, 1), (1, 1)])),
+    ///         (Fq::from(1), SparseTerm::new(vec![(2, 1)])),
+    ///     ],
+    /// );
+    /// let expected = SparsePolynomial::from_coefficients_vec(
+    ///     3,
+    ///     vec![
+    ///         (Fq::from(3), SparseTerm::new(vec![(0, 1)])),
+    ///         (Fq::from(1), SparseTerm::new(vec![(2, 1)])),
+    ///     ],
+    /// );
+    /// assert_eq!(poly.partial_evaluate(&[(1, Fq::from(3))]), expected);
+    /// ```
+    pub fn partial_evaluate(&self, assignments: &[(usize, F)]) -> Self {
+        let terms = self
+            .terms
+            .iter()
+            .map(|(coeff, term)| {
+                let mut coeff = *coeff;
+                let mut remaining = Vec::new();
+                for (var, power) in term.iter() {
+                    match assignments.iter().find(|(v, _)| v == var) {
+                        Some((_, value)) => coeff *= value.pow([*power as u64]),
+                        None => remaining.push((*var, *power)),
+                    }
+                }
+                (coeff, T::new(remaining))
+            })
+            .collect();
+        Self::from_terms(self.num_vars, terms)
+    }
+
+    /// Substitutes `poly` for the variable `var` in `self`.
+    pub fn substitute(&self, var: usize, poly: &Self) -> Self {
+        let num_vars = self.num_vars.max(poly.num_vars);
+        let mut powers = vec![Self::constant(num_vars, F::one())];
+        let mut result = Self::zero();
+        for (coeff, term) in &self.terms {
+            let mut rest = Vec::new();
+            let mut power = 0;
+            for (v, p) in term.iter() {
+                if *v == var {
+                    power = *p;
+                } else {
+                    rest.push((*v, *p));
+                }
+            }
+            while powers.len() <= power {
+                let next = powers.last().unwrap() * poly;
+                powers.push(next);
+            }
+            let summand =
+                &Self::from_terms(num_vars, vec![(*coeff, T::new(rest))]) * &powers[power];
+            result += &summand;
+        }
+        result.num_vars = num_vars;
+        result
+    }
+
+    /// Substitutes `poly(x_var)` for the variable `x_var` in `self`, where
+    /// `poly` is univariate.
+    pub fn substitute_univariate(&self, var: usize, poly: &DensePolynomial<F>) -> Self {
+        let poly = Self::from_terms(
+            self.num_vars.max(var + 1),
+            poly.coeffs
+                .iter()
+                .enumerate()
+                .map(|(i, c)| (*c, T::new(vec![(var, i)])))
+                .collect(),
+        );
+        self.substitute(var, &poly)
+    }
+
+    /// Substitutes `polys[i]` for each variable `x_i` of `self` at once, and
+    /// returns a polynomial in the variables of `polys`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `polys.len()` is less than `self.num_vars`.
+    pub fn compose(&self, polys: &[Self]) -> Self {
+        assert!(
+            polys.len() >= self.num_vars,
+            "a polynomial must be given for each variable"
+        );
+        let num_vars = polys.iter().map(|p| p.num_vars).max().unwrap_or(0);
+        // Lazily computed powers of each substituted polynomial.
+        let mut powers: Vec<Vec<Self>> =
+            vec![vec![Self::constant(num_vars, F::one())]; polys.len()];
+        let mut result = Self::zero();
+        for (coeff, term) in &self.terms {
+            let mut summand = Self::constant(num_vars, *coeff);
+            for (var, power) in term.iter() {
+                while powers[*var].len() <= *power {
+                    let next = powers[*var].last().unwrap() * &polys[*var];
+                    powers[*var].push(next);
+                }
+                summand = &summand * &powers[*var][*power];
+            }
+            result += &summand;
+        }
+        result.num_vars = num_vars;
+        result
+    }
 }
 
 impl<F: Field> Polynomial<F> for SparsePolynomial<F, SparseTerm> {
@@ -135,35 +333,8 @@ impl<F: Field> DenseMVPolynomial<F> for SparsePolynomial<F, SparseTerm> {
     ///     ],
     /// );
     /// ```
-    fn from_coefficients_vec(num_vars: usize, mut terms: Vec<(F, SparseTerm)>) -> Self {
-        // Ensure that terms are in ascending order.
-        terms.sort_by(|(_, t1), (_, t2)| t1.cmp(t2));
-        // If any terms are duplicated, add them together
-        let mut terms_dedup: Vec<(F, SparseTerm)> = Vec::new();
-        for (coeff, term) in terms {
-            // Assert correct number of indeterminates
-            assert!(
-                term.iter().all(|(var, _)| *var < num_vars),
-                "Invalid number of indeterminates"
-            );
-
-            if let Some((prev_coeff, prev_term)) = terms_dedup.last_mut() {
-                // If terms match, add the coefficients.
-                if prev_term == &term {
-                    *prev_coeff += coeff;
-                    continue;
-                }
-            }
-
-            terms_dedup.push((coeff, term));
-        }
-        let mut result = Self {
-            num_vars,
-            terms: terms_dedup,
-        };
-        // Remove any terms with zero coefficients
-        result.remove_zeros();
-        result
+    fn from_coefficients_vec(num_vars: usize, terms: Vec<(F, SparseTerm)>) -> Self {
+        Self::from_terms(num_vars, terms)
     }
 
     /// Returns the terms of a `self` as a list of tuples of the form `(coeff, Self::Term)`
@@ -269,6 +440,37 @@ impl<'a, F: Field, T: Term> SubAssign<&'a Self> for SparsePolynomial<F, T> {
     }
 }
 
+impl<F: Field, T: Term> Mul for SparsePolynomial<F, T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        &self * &other
+    }
+}
+
+impl<'a, F: Field, T: Term> Mul<&'a SparsePolynomial<F, T>> for &SparsePolynomial<F, T> {
+    type Output = SparsePolynomial<F, T>;
+
+    /// Perform a naive n^2 multiplication of `self` by `other`.
+    fn mul(self, other: &'a SparsePolynomial<F, T>) -> SparsePolynomial<F, T> {
+        let num_vars = core::cmp::max(self.num_vars, other.num_vars);
+        let mut result_terms = Vec::with_capacity(self.terms.len() * other.terms.len());
+        for (cur_coeff, cur_term) in &self.terms {
+            for (other_coeff, other_term) in &other.terms {
+                let term = cur_term.iter().chain(other_term.iter()).copied().collect();
+                result_terms.push((*cur_coeff * other_coeff, T::new(term)));
+            }
+        }
+        SparsePolynomial::from_terms(num_vars, result_terms)
+    }
+}
+
+impl<'a, F: Field, T: Term> MulAssign<&'a Self> for SparsePolynomial<F, T> {
+    fn mul_assign(&mut self, other: &'a Self) {
+        *self = &*self * other;
+    }
+}
+
 impl<F: Field, T: Term> fmt::Debug for SparsePolynomial<F, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         for (coeff, term) in self.terms.iter().filter(|(c, _)| !c.is_zero()) {
@@ -297,7 +499,8 @@ impl<F: Field, T: Term> Zero for SparsePolynomial<F, T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ark_ff::UniformRand;
+    use crate::DenseUVPolynomial;
+    use ark_ff::{One, UniformRand};
     use ark_std::test_rng;
     use ark_test_curves::bls12_381::Fr;
 
@@ -305,8 +508,19 @@ mod tests {
 
     /// Generate random `l`-variate polynomial of maximum individual degree `d`
     fn rand_poly<R: Rng>(l: usize, d: usize, rng: &mut R) -> SparsePolynomial<Fr, SparseTerm> {
-        let mut random_terms = Vec::new();
         let num_terms = rng.gen_range(1..1000);
+        rand_poly_with_terms(l, d, num_terms, rng)
+    }
+
+    /// Generate random `l`-variate polynomial of maximum individual degree `d`
+    /// with at most `num_terms` terms
+    fn rand_poly_with_terms<R: Rng>(
+        l: usize,
+        d: usize,
+        num_terms: usize,
+        rng: &mut R,
+    ) -> SparsePolynomial<Fr, SparseTerm> {
+        let mut random_terms = Vec::new();
         // For each term, randomly select up to `l` variables with degree
         // in [1,d] and random coefficient
         random_terms.push((Fr::rand(rng), SparseTerm::new(vec![])));
@@ -320,26 +534,6 @@ mod tests {
         SparsePolynomial::from_coefficients_slice(l, &random_terms)
     }
 
-    /// Perform a naive n^2 multiplication of `self` by `other`.
-    fn naive_mul(
-        cur: &SparsePolynomial<Fr, SparseTerm>,
-        other: &SparsePolynomial<Fr, SparseTerm>,
-    ) -> SparsePolynomial<Fr, SparseTerm> {
-        if cur.is_zero() || other.is_zero() {
-            SparsePolynomial::zero()
-        } else {
-            let mut result_terms = Vec::new();
-            for (cur_coeff, cur_term) in &cur.terms {
-                for (other_coeff, other_term) in &other.terms {
-                    let mut term = cur_term.0.clone();
-                    term.extend(other_term.0.clone());
-                    result_terms.push((*cur_coeff * *other_coeff, SparseTerm::new(term)));
-                }
-            }
-            SparsePolynomial::from_coefficients_slice(cur.num_vars, result_terms.as_slice())
-        }
-    }
-
     #[test]
     fn add_polynomials() {
         let rng = &mut test_rng();
@@ -419,9 +613,8 @@ mod tests {
     }
 
     #[test]
-    /// This is just to make sure naive_mul works as expected
     fn mul_polynomials_fixed() {
-        let a = SparsePolynomial::from_coefficients_slice(
+        let a = SparsePolynomial::<Fr, SparseTerm>::from_coefficients_slice(
             4,
             &[
                 ("2".parse().unwrap(), SparseTerm(vec![])),
@@ -438,7 +631,7 @@ mod tests {
                 ("1".parse().unwrap(), SparseTerm(vec![(3, 1)])),
             ],
         );
-        let result = naive_mul(&a, &b);
+        let result = &a * &b;
         let expected = SparsePolynomial::from_coefficients_slice(
             4,
             &[
@@ -562,4 +755,124 @@ mod tests {
         assert_eq!(poly2.degree(), 2, "Degree of poly2 should be 2");
         assert_eq!(poly3.degree(), 0, "Degree of poly3 should be 0");
     }
+
+    fn rand_point<R: Rng>(l: usize, rng: &mut R) -> Vec<Fr> {
+        (0..l).map(|_| Fr::rand(rng)).collect()
+    }
+
+    #[test]
+    fn mul_and_evaluate_polynomials() {
+        let rng = &mut test_rng();
+        for a_var_count in 1..6 {
+            for b_var_count in 1..6 {
+                let p1 = rand_poly_with_terms(a_var_count, 4, 20, rng);
+                let p2 = rand_poly_with_terms(b_var_count, 4, 20, rng);
+                let point = rand_point(core::cmp::max(a_var_count, b_var_count), rng);
+                let product = &p1 * &p2;
+                assert_eq!(product, &p2 * &p1);
+                assert_eq!(
+                    product.evaluate(&point),
+                    p1.evaluate(&point) * p2.evaluate(&point)
+                );
+
+                let mut p3 = p1.clone();
+                p3 *= &p2;
+                assert_eq!(p3, product);
+            }
+        }
+        let p = rand_poly_with_terms(3, 4, 20, rng);
+        assert!((&p * &SparsePolynomial::zero()).is_zero());
+    }
+
+    #[test]
+    fn pow_polynomials() {
+        let rng = &mut test_rng();
+        let p = rand_poly_with_terms(3, 2, 5, rng);
+        let point = rand_point(3, rng);
+        let mut expected =
+            SparsePolynomial::from_coefficients_vec(3, vec![(Fr::one(), SparseTerm::new(vec![]))]);
+        for exp in 0..6 {
+            assert_eq!(p.pow(exp), expected);
+            assert_eq!(
+                p.pow(exp).evaluate(&point),
+                p.evaluate(&point).pow([exp as u64])
+            );
+            expected = &expected * &p;
+        }
+    }
+
+    #[test]
+    fn degrees_per_variable() {
+        // x_0^3 * x_2 + x_0 * x_2^4 + x_3
+        let poly = SparsePolynomial::<Fr, SparseTerm>::from_coefficients_vec(
+            5,
+            vec![
+                (Fr::from(2), SparseTerm::new(vec![(0, 3), (2, 1)])),
+                (Fr::from(1), SparseTerm::new(vec![(0, 1), (2, 4)])),
+                (Fr::from(1), SparseTerm::new(vec![(3, 1)])),
+            ],
+        );
+        assert_eq!(poly.degrees(), vec![3, 0, 4, 1, 0]);
+        assert_eq!(poly.degree_in(2), 4);
+        assert_eq!(poly.degree_in(7), 0);
+    }
+
+    #[test]
+    fn partial_evaluate_polynomials() {
+        let rng = &mut test_rng();
+        for var_count in 1..8 {
+            let p = rand_poly_with_terms(var_count, 5, 50, rng);
+            let point = rand_point(var_count, rng);
+            let assignments: Vec<_> = (0..var_count)
+                .filter(|i| i % 2 == 0)
+                .map(|i| (i, point[i]))
+                .collect();
+            let partial = p.partial_evaluate(&assignments);
+            assert_eq!(partial.num_vars, var_count);
+            assert!(assignments
+                .iter()
+                .all(|(var, _)| partial.degree_in(*var) == 0));
+            assert_eq!(partial.evaluate(&point), p.evaluate(&point));
+
+            let all: Vec<_> = point.iter().copied().enumerate().collect();
+            let constant = p.partial_evaluate(&all);
+            assert_eq!(constant.degree(), 0);
+            assert_eq!(constant.evaluate(&point), p.evaluate(&point));
+        }
+    }
+
+    #[test]
+    fn substitute_polynomials() {
+        let rng = &mut test_rng();
+        let p = rand_poly_with_terms(4, 3, 20, rng);
+        let q = rand_poly_with_terms(5, 2, 6, rng);
+        let mut point = rand_point(5, rng);
+
+        let substituted = p.substitute(1, &q);
+        assert_eq!(substituted.num_vars, 5);
+        let result = substituted.evaluate(&point);
+        let q_value = q.evaluate(&point);
+        point[1] = q_value;
+        assert_eq!(result, p.evaluate(&point));
+
+        // x_2 -> 1 + 2 x_2 + x_2^2
+        let u = DensePolynomial::from_coefficients_vec(vec![Fr::from(1), Fr::from(2), Fr::from(1)]);
+        let substituted = p.substitute_univariate(2, &u);
+        assert_eq!(substituted.degree_in(2), 2 * p.degree_in(2));
+        let result = substituted.evaluate(&point);
+        point[2] = u.evaluate(&point[2]);
+        assert_eq!(result, p.evaluate(&point));
+    }
+
+    #[test]
+    fn compose_polynomials() {
+        let rng = &mut test_rng();
+        let p = rand_poly_with_terms(3, 3, 15, rng);
+        let polys: Vec<_> = (0..3).map(|_| rand_poly_with_terms(2, 2, 4, rng)).collect();
+        let point = rand_point(2, rng);
+        let composed = p.compose(&polys);
+        assert_eq!(composed.num_vars, 2);
+        let inner: Vec<_> = polys.iter().map(|q| q.evaluate(&point)).collect();
+        assert_eq!(composed.evaluate(&point), p.evaluate(&inner));
+    }
 }
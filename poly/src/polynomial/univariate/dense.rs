@@ -1,5 +1,6 @@
 //! A dense univariate polynomial represented in coefficient form.
 use crate::{
+    domain::utils::karatsuba,
     univariate::{DenseOrSparsePolynomial, SparsePolynomial},
     DenseUVPolynomial, EvaluationDomain, Evaluations, GeneralEvaluationDomain, Polynomial,
 };
@@ -209,6 +210,138 @@ impl<F: FftField> DensePolynomial<F> {
             (quotient, remainder)
         }
     }
+
+    /// Divide `self` by `divisor`, and return the quotient and remainder.
+    ///
+    /// The reversed quotient is computed as the product of the reversed
+    /// `self` and the inverse power series of the reversed `divisor`, which
+    /// is obtained by Newton iteration. All products use FFTs, so this takes
+    /// `O(n log n)` field operations instead of the `O(n m)` of
+    /// [`DenseOrSparsePolynomial::divide_with_q_and_r`], which it falls back to
+    /// if `F` has no evaluation domain of size `2 * self.len()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn divide_with_q_and_r_fast(&self, divisor: &Self) -> (Self, Self) {
+        if self.is_zero() {
+            return (Self::zero(), Self::zero());
+        } else if divisor.is_zero() {
+            panic!("Dividing by zero polynomial")
+        } else if self.degree() < divisor.degree() {
+            return (Self::zero(), self.clone());
+        } else if GeneralEvaluationDomain::<F>::new(2 * self.coeffs.len()).is_none() {
+            return long_division(self, divisor);
+        }
+
+        // Now we know that self.degree() >= divisor.degree();
+        let quotient_len = self.degree() - divisor.degree() + 1;
+
+        let rev_divisor: Vec<_> = divisor.coeffs[..=divisor.degree()]
+            .iter()
+            .rev()
+            .copied()
+            .collect();
+        let rev_divisor_inv =
+            Self::from_coefficients_vec(rev_divisor).inverse_mod_x_pow(quotient_len);
+
+        let rev_self: Vec<_> = self.coeffs[..=self.degree()]
+            .iter()
+            .rev()
+            .take(quotient_len)
+            .copied()
+            .collect();
+        let rev_quotient =
+            (&Self::from_coefficients_vec(rev_self) * &rev_divisor_inv).mod_x_pow(quotient_len);
+
+        let mut quotient = rev_quotient.coeffs;
+        quotient.resize(quotient_len, F::zero());
+        quotient.reverse();
+        let quotient = Self::from_coefficients_vec(quotient);
+
+        let remainder = self - &(&quotient * divisor);
+        (quotient, remainder)
+    }
+
+    /// Multiply `self` by `other`, using FFTs only if both are long enough
+    /// for it to be faster than [`Self::naive_mul`]. If `F` has no evaluation
+    /// domain large enough for the product, this uses Karatsuba
+    /// multiplication instead.
+    pub(crate) fn mul_by_size(&self, other: &Self) -> Self {
+        if self.len().min(other.len()) < NAIVE_MUL_THRESHOLD {
+            self.naive_mul(other)
+        } else if GeneralEvaluationDomain::<F>::new(self.len() + other.len() - 1).is_none() {
+            self.karatsuba_mul(other)
+        } else {
+            self * other
+        }
+    }
+
+    /// Multiply `self` by `other` with Karatsuba's algorithm, splitting the
+    /// longer of the two into chunks of the length of the shorter one.
+    fn karatsuba_mul(&self, other: &Self) -> Self {
+        let (short, long) = if self.len() <= other.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let n = short.len();
+        let mut result = vec![F::zero(); self.len() + other.len() - 1];
+        let mut chunk = Vec::with_capacity(n);
+        for (i, coeffs) in long.coeffs.chunks(n).enumerate() {
+            chunk.clear();
+            chunk.extend_from_slice(coeffs);
+            chunk.resize(n, F::zero());
+            // The product of the zero padding is zero, so the coefficients
+            // beyond the end of `result` vanish.
+            for (r, c) in result[i * n..]
+                .iter_mut()
+                .zip(karatsuba(&chunk, &short.coeffs))
+            {
+                *r += c;
+            }
+        }
+        Self::from_coefficients_vec(result)
+    }
+
+    /// Divide `self` by `divisor`, and return the quotient and remainder.
+    ///
+    /// This uses [`Self::divide_with_q_and_r_fast`] only if both the quotient
+    /// and the divisor are large enough for it to be faster than long
+    /// division. [`Div`] returns the quotient computed here.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        if self.len() >= divisor.len() + FAST_DIVISION_THRESHOLD
+            && divisor.len() >= FAST_DIVISION_THRESHOLD
+        {
+            self.divide_with_q_and_r_fast(divisor)
+        } else {
+            long_division(self, divisor)
+        }
+    }
+
+    /// Returns the inverse of `self` modulo `x^n`, which must have a non-zero
+    /// constant term.
+    ///
+    /// Each Newton step `g <- g * (2 - self * g)` doubles the number of
+    /// correct coefficients of `g`.
+    fn inverse_mod_x_pow(&self, n: usize) -> Self {
+        let mut inverse = Self::from_coefficients_vec(vec![self.coeffs[0].inverse().unwrap()]);
+        let mut precision = 1;
+        while precision < n {
+            precision = (2 * precision).min(n);
+            let truncated =
+                Self::from_coefficients_slice(&self.coeffs[..precision.min(self.coeffs.len())]);
+            // `self * g` is 1 modulo `x^(precision / 2)`, so its constant term is non-zero.
+            let mut correction = -(&truncated * &inverse).mod_x_pow(precision);
+            correction.coeffs[0] += F::from(2u8);
+            inverse = (&inverse * &correction).mod_x_pow(precision);
+        }
+        inverse
+    }
 }
 
 impl<F: Field> DensePolynomial<F> {
@@ -218,6 +351,13 @@ impl<F: Field> DensePolynomial<F> {
         }
     }
 
+    /// Reduces `self` modulo `x^n`.
+    fn mod_x_pow(mut self, n: usize) -> Self {
+        self.coeffs.truncate(n);
+        self.truncate_leading_zeros();
+        self
+    }
+
     /// Perform a naive n^2 multiplication of `self` by `other`.
     pub fn naive_mul(&self, other: &Self) -> Self {
         if self.is_zero() || other.is_zero() {
@@ -590,14 +730,36 @@ impl<'a, F: Field> SubAssign<&'a SparsePolynomial<F>> for DensePolynomial<F> {
     }
 }
 
-impl<'a, F: Field> Div<&'a DensePolynomial<F>> for &DensePolynomial<F> {
+/// Below this length, [`DensePolynomial::mul_by_size`] multiplies naively
+/// rather than with FFTs.
+const NAIVE_MUL_THRESHOLD: usize = 32;
+
+/// The minimum length of both the quotient and the divisor for which
+/// [`DensePolynomial::div_rem`] uses
+/// [`DensePolynomial::divide_with_q_and_r_fast`] instead of long division.
+const FAST_DIVISION_THRESHOLD: usize = 512;
+
+/// Divides `a` by `b` with long division, and returns the quotient and
+/// remainder.
+fn long_division<F: Field>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    // `divide_with_q_and_r` panics on a zero divisor rather than returning
+    // `None`.
+    DenseOrSparsePolynomial::from(a)
+        .divide_with_q_and_r(&b.into())
+        .expect("division failed")
+}
+
+/// Performs O(nlogn) division of large polynomials if F is smooth, see
+/// [`DensePolynomial::div_rem`].
+impl<'a, F: FftField> Div<&'a DensePolynomial<F>> for &DensePolynomial<F> {
     type Output = DensePolynomial<F>;
 
     #[inline]
     fn div(self, divisor: &'a DensePolynomial<F>) -> DensePolynomial<F> {
-        let a = DenseOrSparsePolynomial::from(self);
-        let b = DenseOrSparsePolynomial::from(divisor);
-        a.divide_with_q_and_r(&b).expect("division failed").0
+        self.div_rem(divisor).0
     }
 }
 
@@ -692,7 +854,7 @@ impl<F: Field> Zero for DensePolynomial<F> {
 impl_op!(Add, add, Field);
 impl_op!(Sub, sub, Field);
 impl_op!(Mul, mul, FftField);
-impl_op!(Div, div, Field);
+impl_op!(Div, div, FftField);
 
 #[cfg(test)]
 mod tests {
@@ -901,6 +1063,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn divide_polynomials_fast() {
+        let rng = &mut test_rng();
+
+        for a_degree in 0..50 {
+            for b_degree in 0..50 {
+                let dividend = DensePolynomial::<Fr>::rand(a_degree, rng);
+                let divisor = DensePolynomial::<Fr>::rand(b_degree, rng);
+                assert_eq!(
+                    Some(dividend.divide_with_q_and_r_fast(&divisor)),
+                    DenseOrSparsePolynomial::divide_with_q_and_r(
+                        &(&dividend).into(),
+                        &(&divisor).into()
+                    )
+                );
+            }
+        }
+
+        // Divisors with vanishing low-order coefficients.
+        let dividend = DensePolynomial::<Fr>::rand(100, rng);
+        let mut divisor = DensePolynomial::<Fr>::rand(20, rng);
+        divisor.coeffs[..5].iter_mut().for_each(|c| *c = Fr::zero());
+        assert_eq!(
+            Some(dividend.divide_with_q_and_r_fast(&divisor)),
+            DenseOrSparsePolynomial::divide_with_q_and_r(&(&dividend).into(), &(&divisor).into())
+        );
+    }
+
+    #[test]
+    fn divide_polynomials_above_threshold() {
+        let rng = &mut test_rng();
+
+        let divisor = DensePolynomial::<Fr>::rand(super::FAST_DIVISION_THRESHOLD + 10, rng);
+        let quotient = DensePolynomial::<Fr>::rand(super::FAST_DIVISION_THRESHOLD + 20, rng);
+        let remainder = DensePolynomial::<Fr>::rand(super::FAST_DIVISION_THRESHOLD, rng);
+        let dividend = &(&divisor * &quotient) + &remainder;
+
+        assert_eq!(&dividend / &divisor, quotient);
+        assert_eq!(
+            dividend.div_rem(&divisor),
+            (quotient.clone(), remainder.clone())
+        );
+        assert_eq!(
+            dividend.divide_with_q_and_r_fast(&divisor),
+            (quotient, remainder)
+        );
+    }
+
     #[test]
     fn evaluate_polynomials() {
         let rng = &mut test_rng();
@@ -940,6 +1150,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mul_polynomials_without_fft() {
+        // The multiplicative group of this field has two-adicity 6, so there
+        // are no evaluation domains for products of more than 64 coefficients.
+        use ark_test_curves::secp256k1::Fr;
+
+        let rng = &mut test_rng();
+        for (a_degree, b_degree) in [(40, 20), (40, 300), (100, 100), (257, 64)] {
+            let a = DensePolynomial::<Fr>::rand(a_degree, rng);
+            let b = DensePolynomial::<Fr>::rand(b_degree, rng);
+            assert_eq!(a.mul_by_size(&b), a.naive_mul(&b));
+            assert_eq!(b.mul_by_size(&a), a.naive_mul(&b));
+        }
+    }
+
     #[test]
     fn mul_by_vanishing_poly() {
         let rng = &mut test_rng();
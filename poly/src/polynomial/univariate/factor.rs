@@ -0,0 +1,472 @@
+//! Root finding and factorization of dense univariate polynomials over finite
+//! fields.
+//!
+//! The algorithms use FFT multiplication and fast division where `F` has
+//! evaluation domains of the required sizes, and Karatsuba multiplication and
+//! long division otherwise.
+use crate::{univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+use ark_ff::{BitIteratorBE, FftField, Zero};
+use ark_std::{
+    rand::{rngs::StdRng, Rng, SeedableRng},
+    vec,
+    vec::*,
+};
+
+impl<F: FftField> DensePolynomial<F> {
+    /// Returns the distinct roots of `self` in `F`, in no particular order.
+    ///
+    /// This computes the product of the distinct linear factors of `self` as
+    /// `gcd(self, x^q - x)`, where `q` is the size of `F`, and splits it with
+    /// [`Self::equal_degree_factorization`] using a fixed seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero, or if `F` has characteristic two.
+    pub fn roots(&self) -> Vec<F> {
+        assert!(!self.is_zero(), "the zero polynomial has every root");
+        if self.degree() == 0 {
+            return Vec::new();
+        }
+        let x = Self::from_coefficients_vec(vec![F::zero(), F::one()]);
+        let linear = self.gcd_fast(&(&frobenius_of_x(self) - &x));
+        linear
+            .equal_degree_factorization(1, &mut StdRng::seed_from_u64(0))
+            .into_iter()
+            .map(|factor| -factor.coeffs[0])
+            .collect()
+    }
+
+    /// Returns the monic irreducible factors of `self` together with their
+    /// multiplicities, so that `self` is the product of its leading
+    /// coefficient and the factors raised to their multiplicities.
+    ///
+    /// This chains [`Self::square_free_factorization`],
+    /// [`Self::distinct_degree_factorization`] and
+    /// [`Self::equal_degree_factorization`], the last of which uses a fixed
+    /// seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero, or if `F` has characteristic two.
+    pub fn factor(&self) -> Vec<(Self, usize)> {
+        assert!(!self.is_zero(), "cannot factor the zero polynomial");
+        let rng = &mut StdRng::seed_from_u64(0);
+        let mut factors = Vec::new();
+        for (square_free, multiplicity) in self.square_free_factorization() {
+            for (product, degree) in square_free.distinct_degree_factorization() {
+                factors.extend(
+                    product
+                        .equal_degree_factorization(degree, rng)
+                        .into_iter()
+                        .map(|factor| (factor, multiplicity)),
+                );
+            }
+        }
+        factors
+    }
+
+    /// Returns pairwise coprime, monic and square-free polynomials `f_i` with
+    /// distinct multiplicities `m_i`, such that `self` is the product of its
+    /// leading coefficient and all `f_i^m_i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    pub fn square_free_factorization(&self) -> Vec<(Self, usize)> {
+        assert!(!self.is_zero(), "cannot factor the zero polynomial");
+        let mut factors = Vec::new();
+        square_free_factorization_helper(&make_monic(self), 1, &mut factors);
+        factors
+    }
+
+    /// Given a monic square-free `self`, returns pairs `(g_d, d)` with
+    /// increasing `d`, where `g_d` is the product of all irreducible factors
+    /// of `self` of degree `d`, and is not constant.
+    pub fn distinct_degree_factorization(&self) -> Vec<(Self, usize)> {
+        let mut factors = Vec::new();
+        let mut f = self.clone();
+        let x = Self::from_coefficients_vec(vec![F::zero(), F::one()]);
+        let mut x_q = frobenius_of_x(&f);
+        // Holds `x^(q^d) mod f`.
+        let mut h = x.clone();
+        let mut degree = 0;
+        while f.degree() >= 2 * (degree + 1) {
+            degree += 1;
+            h = compose_mod(&h, &x_q, &f);
+            let g = f.gcd_fast(&(&h - &x));
+            if g.degree() > 0 {
+                f = f.div_rem(&g).0;
+                h = h.div_rem(&f).1;
+                x_q = x_q.div_rem(&f).1;
+                factors.push((g, degree));
+            }
+        }
+        if f.degree() > 0 {
+            let degree = f.degree();
+            factors.push((f, degree));
+        }
+        factors
+    }
+
+    /// Given a monic `self` that is the product of distinct irreducible
+    /// polynomials of degree `degree`, returns these irreducible factors.
+    ///
+    /// This uses the Cantor–Zassenhaus algorithm, which splits `self` using
+    /// random polynomials sampled from `rng`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `F` has characteristic two.
+    pub fn equal_degree_factorization<R: Rng>(&self, degree: usize, rng: &mut R) -> Vec<Self> {
+        assert!(
+            F::characteristic()[0] % 2 == 1,
+            "equal-degree factorization requires an odd characteristic"
+        );
+        let mut factors = Vec::new();
+        equal_degree_factorization_helper(self, degree, rng, &mut factors);
+        factors
+    }
+}
+
+/// Returns `p` scaled to have leading coefficient one.
+fn make_monic<F: FftField>(p: &DensePolynomial<F>) -> DensePolynomial<F> {
+    let lc_inv = p.last().unwrap().inverse().unwrap();
+    p * lc_inv
+}
+
+/// Returns `a * b mod modulus`.
+fn mul_mod<F: FftField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+    modulus: &DensePolynomial<F>,
+) -> DensePolynomial<F> {
+    a.mul_by_size(b).div_rem(modulus).1
+}
+
+/// Returns `base^exp mod modulus`, where `exp` is given in little-endian
+/// 64-bit limbs.
+fn pow_mod<F: FftField>(
+    base: &DensePolynomial<F>,
+    exp: &[u64],
+    modulus: &DensePolynomial<F>,
+) -> DensePolynomial<F> {
+    let base = base.div_rem(modulus).1;
+    let mut result = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+    for bit in BitIteratorBE::without_leading_zeros(exp) {
+        result = mul_mod(&result, &result, modulus);
+        if bit {
+            result = mul_mod(&result, &base, modulus);
+        }
+    }
+    result
+}
+
+/// Returns `p(q) mod modulus`.
+fn compose_mod<F: FftField>(
+    p: &DensePolynomial<F>,
+    q: &DensePolynomial<F>,
+    modulus: &DensePolynomial<F>,
+) -> DensePolynomial<F> {
+    p.coeffs
+        .iter()
+        .rev()
+        .fold(DensePolynomial::zero(), |acc, c| {
+            &mul_mod(&acc, q, modulus) + &DensePolynomial::from_coefficients_vec(vec![*c])
+        })
+}
+
+/// Returns `x^q mod modulus`, where `q` is the size of `F`.
+fn frobenius_of_x<F: FftField>(modulus: &DensePolynomial<F>) -> DensePolynomial<F> {
+    let x = DensePolynomial::from_coefficients_vec(vec![F::zero(), F::one()]);
+    (0..F::extension_degree()).fold(x, |acc, _| pow_mod(&acc, F::characteristic(), modulus))
+}
+
+/// Pushes the square-free factors of the monic `f`, with their
+/// multiplicities multiplied by `scale`, onto `factors`.
+fn square_free_factorization_helper<F: FftField>(
+    f: &DensePolynomial<F>,
+    scale: usize,
+    factors: &mut Vec<(DensePolynomial<F>, usize)>,
+) {
+    if f.degree() == 0 {
+        return;
+    }
+    let derivative = DensePolynomial::from_coefficients_vec(
+        f.coeffs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| F::from(i as u64) * c)
+            .collect(),
+    );
+
+    // Yun's algorithm, which only finds the factors whose multiplicity is not
+    // divisible by the characteristic. These remain in `c`, which is then a
+    // polynomial in `x^p`.
+    let mut c = f.gcd_fast(&derivative);
+    let mut w = f.div_rem(&c).0;
+    let mut multiplicity = 1;
+    while w.degree() > 0 {
+        let y = w.gcd_fast(&c);
+        let factor = w.div_rem(&y).0;
+        if factor.degree() > 0 {
+            factors.push((factor, multiplicity * scale));
+        }
+        c = c.div_rem(&y).0;
+        w = y;
+        multiplicity += 1;
+    }
+
+    if c.degree() > 0 {
+        // The characteristic is at most the degree of `c`, so it fits in a
+        // `usize`.
+        let p = F::characteristic()[0] as usize;
+        let root_power = F::extension_degree() as usize - 1;
+        let root = DensePolynomial::from_coefficients_vec(
+            c.coeffs
+                .iter()
+                .step_by(p)
+                .map(|c| c.frobenius_map(root_power))
+                .collect(),
+        );
+        square_free_factorization_helper(&root, scale * p, factors);
+    }
+}
+
+/// Pushes the irreducible factors of degree `degree` of the monic `f` onto
+/// `factors`.
+fn equal_degree_factorization_helper<F: FftField, R: Rng>(
+    f: &DensePolynomial<F>,
+    degree: usize,
+    rng: &mut R,
+    factors: &mut Vec<DensePolynomial<F>>,
+) {
+    if f.degree() <= degree {
+        if f.degree() > 0 {
+            factors.push(f.clone());
+        }
+        return;
+    }
+
+    // For a random `a`, `a^((q^d - 1) / 2)` is `1` modulo about half of the
+    // irreducible factors of `f`. We write the exponent as
+    // `(p - 1) / 2 * (1 + p + ... + p^(n - 1)) * (1 + q + ... + q^(d - 1))`,
+    // where `q = p^n`, and compute the `q`-th powers as compositions with
+    // `x^q`, which is valid as the coefficients lie in `F`.
+    let half_p_minus_one: Vec<u64> = {
+        let p = F::characteristic();
+        (0..p.len())
+            .map(|i| (p[i] >> 1) | p.get(i + 1).map_or(0, |next| next << 63))
+            .collect()
+    };
+    let x_q = frobenius_of_x(f);
+    let one = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+    loop {
+        let a = DensePolynomial::rand(f.degree() - 1, rng);
+        let mut power = pow_mod(&a, &half_p_minus_one, f);
+        let mut term = power.clone();
+        for _ in 1..F::extension_degree() {
+            term = pow_mod(&term, F::characteristic(), f);
+            power = mul_mod(&power, &term, f);
+        }
+        let mut term = power.clone();
+        for _ in 1..degree {
+            term = compose_mod(&term, &x_q, f);
+            power = mul_mod(&power, &term, f);
+        }
+
+        let g = f.gcd_fast(&(&power - &one));
+        if g.degree() > 0 && g.degree() < f.degree() {
+            equal_degree_factorization_helper(&f.div_rem(&g).0, degree, rng, factors);
+            equal_degree_factorization_helper(&g, degree, rng, factors);
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{polynomial::univariate::*, DenseUVPolynomial, Polynomial};
+    use ark_ff::{Field, Fp64, MontBackend, MontConfig, One, UniformRand, Zero};
+    use ark_std::{rand::Rng, test_rng};
+    use ark_test_curves::bls12_381::{Fq2, Fr};
+
+    #[derive(MontConfig)]
+    #[modulus = "7"]
+    #[generator = "3"]
+    pub(crate) struct F7Config;
+    pub(crate) type F7 = Fp64<MontBackend<F7Config, 1>>;
+
+    fn linear<F: Field>(root: F) -> DensePolynomial<F> {
+        DensePolynomial::from_coefficients_vec(vec![-root, F::one()])
+    }
+
+    fn product<F: Field>(factors: &[(DensePolynomial<F>, usize)]) -> DensePolynomial<F> {
+        factors.iter().fold(
+            DensePolynomial::from_coefficients_vec(vec![F::one()]),
+            |acc, (factor, multiplicity)| {
+                (0..*multiplicity).fold(acc, |acc, _| acc.naive_mul(factor))
+            },
+        )
+    }
+
+    /// Returns a random monic irreducible polynomial of degree `degree`.
+    fn rand_irreducible<F: ark_ff::FftField, R: Rng>(
+        degree: usize,
+        rng: &mut R,
+    ) -> DensePolynomial<F> {
+        loop {
+            let mut coeffs: Vec<F> = (0..degree).map(|_| F::rand(rng)).collect();
+            coeffs.push(F::one());
+            let poly = DensePolynomial::from_coefficients_vec(coeffs);
+            if poly.distinct_degree_factorization() == vec![(poly.clone(), degree)] {
+                return poly;
+            }
+        }
+    }
+
+    fn sorted_roots<F: ark_ff::FftField>(poly: &DensePolynomial<F>) -> Vec<F> {
+        let mut roots = poly.roots();
+        roots.sort();
+        roots
+    }
+
+    #[test]
+    fn roots_of_random_products() {
+        let rng = &mut test_rng();
+        for n in [1, 2, 5, 20] {
+            let mut roots: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+            let mut poly = roots
+                .iter()
+                .enumerate()
+                .fold(DensePolynomial::rand(0, rng), |acc, (i, r)| {
+                    (0..=i % 3).fold(acc, |acc, _| acc.naive_mul(&linear(*r)))
+                });
+            poly = poly.naive_mul(&rand_irreducible(3, rng));
+            roots.sort();
+            assert_eq!(sorted_roots(&poly), roots);
+        }
+        assert!(DensePolynomial::<Fr>::rand(0, rng).roots().is_empty());
+        assert!(rand_irreducible::<Fr, _>(2, rng).roots().is_empty());
+    }
+
+    #[test]
+    fn roots_in_extension_field() {
+        let rng = &mut test_rng();
+        let mut roots: Vec<Fq2> = (0..4).map(|_| Fq2::rand(rng)).collect();
+        let poly = roots.iter().fold(rand_irreducible(2, rng), |acc, r| {
+            acc.naive_mul(&linear(*r))
+        });
+        roots.sort();
+        assert_eq!(sorted_roots(&poly), roots);
+    }
+
+    #[test]
+    fn roots_in_small_field() {
+        let rng = &mut test_rng();
+        for _ in 0..20 {
+            let poly = DensePolynomial::<F7>::rand(rng.gen_range(1..12), rng);
+            let expected: Vec<F7> = (0..7u64)
+                .map(F7::from)
+                .filter(|x| poly.evaluate(x).is_zero())
+                .collect();
+            assert_eq!(sorted_roots(&poly), expected);
+        }
+    }
+
+    #[test]
+    fn roots_without_fft() {
+        // The multiplicative group of this field has two-adicity 6, so there
+        // are no evaluation domains for the larger products.
+        use ark_test_curves::secp256k1::Fr;
+
+        let rng = &mut test_rng();
+        let mut roots: Vec<Fr> = (0..100).map(|_| Fr::rand(rng)).collect();
+        let poly = roots.iter().fold(rand_irreducible(2, rng), |acc, r| {
+            acc.naive_mul(&linear(*r))
+        });
+        roots.sort();
+        assert_eq!(sorted_roots(&poly), roots);
+    }
+
+    fn check_factorization<F: ark_ff::FftField>(
+        poly: &DensePolynomial<F>,
+        expected: &[(DensePolynomial<F>, usize)],
+    ) {
+        let mut factors = poly.factor();
+        let mut expected = expected.to_vec();
+        factors.sort_by_key(|(f, m)| (*m, f.coeffs.clone()));
+        expected.sort_by_key(|(f, m)| (*m, f.coeffs.clone()));
+        assert_eq!(factors, expected);
+        assert_eq!(&product(&factors) * *poly.last().unwrap(), *poly);
+    }
+
+    #[test]
+    fn factor_random_products() {
+        let rng = &mut test_rng();
+        let expected = vec![
+            (linear(Fr::rand(rng)), 1),
+            (linear(Fr::rand(rng)), 4),
+            (rand_irreducible(2, rng), 1),
+            (rand_irreducible(2, rng), 1),
+            (rand_irreducible(2, rng), 2),
+            (rand_irreducible(3, rng), 1),
+            (rand_irreducible(5, rng), 3),
+        ];
+        let poly = &product(&expected) * Fr::from(5u64);
+        check_factorization(&poly, &expected);
+
+        let expected = vec![
+            (linear(Fq2::rand(rng)), 2),
+            (rand_irreducible(2, rng), 1),
+            (rand_irreducible(3, rng), 1),
+        ];
+        check_factorization(&product(&expected), &expected);
+    }
+
+    #[test]
+    fn factor_with_multiplicity_divisible_by_characteristic() {
+        let rng = &mut test_rng();
+        let poly = |coeffs: &[u64]| {
+            DensePolynomial::from_coefficients_vec(coeffs.iter().map(|c| F7::from(*c)).collect())
+        };
+        let expected = vec![
+            (linear(F7::from(1u64)), 7),
+            (linear(F7::from(2u64)), 8),
+            (poly(&[1, 0, 1]), 14),
+            (poly(&[1, 1, 0, 1]), 1),
+            (poly(&[2, 0, 0, 1]), 2),
+        ];
+        check_factorization(&product(&expected), &expected);
+
+        let poly = DensePolynomial::<F7>::rand(1, rng);
+        assert_eq!(
+            poly.factor(),
+            vec![(&poly * poly.coeffs[1].inverse().unwrap(), 1)]
+        );
+
+        let square_free = product(&expected).square_free_factorization();
+        let mut multiplicities: Vec<_> = square_free.iter().map(|(_, m)| *m).collect();
+        multiplicities.sort_unstable();
+        assert_eq!(multiplicities, vec![1, 2, 7, 8, 14]);
+        assert!(square_free.iter().all(|(f, _)| f.last().unwrap().is_one()));
+    }
+
+    #[test]
+    fn factor_without_fft() {
+        // `F7` has no evaluation domains of size more than 2, so this only
+        // uses Karatsuba multiplication and long division.
+        let poly = |coeffs: &[u64]| {
+            DensePolynomial::from_coefficients_vec(coeffs.iter().map(|c| F7::from(*c)).collect())
+        };
+        let expected = vec![
+            (linear(F7::from(1u64)), 100),
+            (linear(F7::from(2u64)), 60),
+            (poly(&[1, 0, 1]), 40),
+            (poly(&[1, 1, 0, 1]), 20),
+        ];
+        let poly = product(&expected);
+        assert_eq!(poly.degree(), 300);
+        check_factorization(&poly, &expected);
+    }
+}
@@ -0,0 +1,457 @@
+//! Greatest common divisors, Bézout coefficients and resultants of dense
+//! univariate polynomials.
+use crate::{
+    univariate::{DenseOrSparsePolynomial, DensePolynomial},
+    DenseUVPolynomial, Polynomial,
+};
+use ark_ff::{FftField, Field, Zero};
+use ark_std::{mem, vec};
+
+/// Below this degree, the half-GCD falls back to the Euclidean algorithm.
+const HALF_GCD_THRESHOLD: usize = 128;
+
+impl<F: Field> DensePolynomial<F> {
+    /// Returns the monic greatest common divisor of `self` and `other`, or
+    /// zero if both are zero.
+    ///
+    /// This uses the Euclidean algorithm, which takes `O(n^2)` field
+    /// operations. See [`Self::gcd_fast`] for large polynomials over an
+    /// [`FftField`].
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = euclidean_div_rem(&a, &b).1;
+            a = mem::replace(&mut b, r);
+        }
+        normalize(a, Self::zero(), Self::zero()).0
+    }
+
+    /// Returns `(g, s, t)` such that `g` is the monic greatest common divisor
+    /// of `self` and `other`, and `s * self + t * other = g`.
+    ///
+    /// The Bézout coefficients satisfy `deg(s) < deg(other) - deg(g)` and
+    /// `deg(t) < deg(self) - deg(g)` whenever the right-hand sides are
+    /// positive. If both inputs are zero, then `g` is zero.
+    pub fn xgcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (Self::from_coefficients_vec(vec![F::one()]), Self::zero());
+        let (mut t0, mut t1) = (Self::zero(), Self::from_coefficients_vec(vec![F::one()]));
+        while !r1.is_zero() {
+            let (q, r) = euclidean_div_rem(&r0, &r1);
+            r0 = mem::replace(&mut r1, r);
+            let s = &s0 - &q.naive_mul(&s1);
+            s0 = mem::replace(&mut s1, s);
+            let t = &t0 - &q.naive_mul(&t1);
+            t0 = mem::replace(&mut t1, t);
+        }
+        normalize(r0, s0, t0)
+    }
+
+    /// Returns the inverse of `self` in the quotient ring modulo `modulus`,
+    /// reduced modulo `modulus`, or `None` if `self` and `modulus` are not
+    /// coprime or `modulus` is zero.
+    pub fn inverse_mod(&self, modulus: &Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+        let (g, s, _) = self.xgcd(modulus);
+        (g.degree() == 0 && !g.is_zero()).then(|| euclidean_div_rem(&s, modulus).1)
+    }
+
+    /// Returns the resultant of `self` and `other`, which is zero if and only
+    /// if they have a common root in the algebraic closure of `F`.
+    ///
+    /// The resultant of a zero polynomial with any polynomial is zero, and
+    /// the resultant of a non-zero constant `c` with a polynomial of degree
+    /// `n` is `c^n`.
+    pub fn resultant(&self, other: &Self) -> F {
+        if self.is_zero() || other.is_zero() {
+            return F::zero();
+        }
+        let (mut a, mut b) = (self.clone(), other.clone());
+        let mut result = F::one();
+        // Uses `res(a, b) = (-1)^(deg(a) deg(b)) lc(b)^(deg(a) - deg(r)) res(b, r)`
+        // for `r = a mod b`.
+        loop {
+            let (n, m) = (a.degree() as u64, b.degree() as u64);
+            let lc_b = *b.last().unwrap();
+            if m == 0 {
+                return result * lc_b.pow([n]);
+            }
+            let r = euclidean_div_rem(&a, &b).1;
+            if r.is_zero() {
+                return F::zero();
+            }
+            result *= lc_b.pow([n - r.degree() as u64]);
+            if n % 2 == 1 && m % 2 == 1 {
+                result = -result;
+            }
+            a = mem::replace(&mut b, r);
+        }
+    }
+}
+
+impl<F: FftField> DensePolynomial<F> {
+    /// Returns the monic greatest common divisor of `self` and `other`, or
+    /// zero if both are zero.
+    ///
+    /// This is the same as [`Self::gcd`], but uses the half-GCD algorithm
+    /// with FFT multiplication, which takes `O(n log^2 n)` field operations.
+    /// Over fields without evaluation domains of the required sizes, the
+    /// products use Karatsuba multiplication instead.
+    pub fn gcd_fast(&self, other: &Self) -> Self {
+        self.xgcd_fast(other).0
+    }
+
+    /// Returns `(g, s, t)` such that `g` is the monic greatest common divisor
+    /// of `self` and `other`, and `s * self + t * other = g`.
+    ///
+    /// This returns the same as [`Self::xgcd`], but uses the half-GCD
+    /// algorithm with FFT multiplication, which takes `O(n log^2 n)` field
+    /// operations.
+    pub fn xgcd_fast(&self, other: &Self) -> (Self, Self, Self) {
+        // Reduce to the case `deg(a) > deg(b)`, where the zero polynomial has
+        // the lowest degree.
+        let (start, a, b) =
+            if other.is_zero() || (!self.is_zero() && self.degree() > other.degree()) {
+                (Matrix::identity(), self.clone(), other.clone())
+            } else {
+                let (q, r) = self.div_rem(other);
+                (Matrix::quotient(q), other.clone(), r)
+            };
+
+        let (matrix, g) = gcd_matrix(a, b);
+        let [[s, t], _] = matrix.mul(&start).0;
+        normalize(g, s, t)
+    }
+}
+
+/// Runs the extended Euclidean algorithm on `a` and `b` until the first
+/// remainder `r` that is zero or of degree less than `degree_bound`, and returns
+/// `(r, t)` such that `s * a + t * b = r` for some `s`.
+///
+/// This is the rational reconstruction step of Gao's decoding algorithm.
+pub(crate) fn partial_xgcd<F: Field>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+    degree_bound: usize,
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut t0, mut t1) = (
+        DensePolynomial::zero(),
+        DensePolynomial::from_coefficients_vec(vec![F::one()]),
+    );
+    while !degree_below(&r1, degree_bound) {
+        let (q, r) = euclidean_div_rem(&r0, &r1);
+        r0 = mem::replace(&mut r1, r);
+        let t = &t0 - &q.naive_mul(&t1);
+        t0 = mem::replace(&mut t1, t);
+    }
+    (r1, t1)
+}
+
+/// Returns the quotient and remainder of `a` divided by the non-zero `b`.
+fn euclidean_div_rem<F: Field>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    DenseOrSparsePolynomial::from(a)
+        .divide_with_q_and_r(&b.into())
+        .unwrap()
+}
+
+/// Returns whether `p` is zero or of degree less than `m`.
+fn degree_below<F: Field>(p: &DensePolynomial<F>, m: usize) -> bool {
+    p.is_zero() || p.degree() < m
+}
+
+/// Returns `p` divided by `x^k`, dropping the remainder.
+fn shift_down<F: Field>(p: &DensePolynomial<F>, k: usize) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_slice(p.coeffs.get(k..).unwrap_or(&[]))
+}
+
+/// Scales `g`, `s` and `t` such that `g` becomes monic.
+fn normalize<F: Field>(
+    g: DensePolynomial<F>,
+    s: DensePolynomial<F>,
+    t: DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>, DensePolynomial<F>) {
+    match g.last() {
+        Some(lc) => {
+            let lc_inv = lc.inverse().unwrap();
+            (g * lc_inv, s * lc_inv, t * lc_inv)
+        },
+        None => (g, s, t),
+    }
+}
+
+/// A 2x2 matrix of polynomials, acting on pairs of polynomials.
+struct Matrix<F: Field>([[DensePolynomial<F>; 2]; 2]);
+
+impl<F: FftField> Matrix<F> {
+    fn identity() -> Self {
+        let one = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+        Self([
+            [one.clone(), DensePolynomial::zero()],
+            [DensePolynomial::zero(), one],
+        ])
+    }
+
+    /// Returns the matrix mapping `(a, b)` to `(b, a - q * b)`.
+    fn quotient(q: DensePolynomial<F>) -> Self {
+        let one = DensePolynomial::from_coefficients_vec(vec![F::one()]);
+        Self([[DensePolynomial::zero(), one.clone()], [one, -q]])
+    }
+
+    fn apply(
+        &self,
+        a: &DensePolynomial<F>,
+        b: &DensePolynomial<F>,
+    ) -> (DensePolynomial<F>, DensePolynomial<F>) {
+        let [[m00, m01], [m10, m11]] = &self.0;
+        (
+            &m00.mul_by_size(a) + &m01.mul_by_size(b),
+            &m10.mul_by_size(a) + &m11.mul_by_size(b),
+        )
+    }
+
+    /// Returns `self * other`.
+    fn mul(&self, other: &Self) -> Self {
+        let [[a00, a01], [a10, a11]] = &self.0;
+        let [[b00, b01], [b10, b11]] = &other.0;
+        Self([
+            [
+                &a00.mul_by_size(b00) + &a01.mul_by_size(b10),
+                &a00.mul_by_size(b01) + &a01.mul_by_size(b11),
+            ],
+            [
+                &a10.mul_by_size(b00) + &a11.mul_by_size(b10),
+                &a10.mul_by_size(b01) + &a11.mul_by_size(b11),
+            ],
+        ])
+    }
+}
+
+/// For `deg(a) > deg(b)`, returns the product of the quotient matrices of
+/// the Euclidean algorithm on `a` and `b`, up to the first remainder of
+/// degree less than `(deg(a) + 1) / 2`.
+fn half_gcd<F: FftField>(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> Matrix<F> {
+    let m = a.degree().div_ceil(2);
+    if degree_below(b, m) {
+        return Matrix::identity();
+    }
+    if a.degree() < HALF_GCD_THRESHOLD {
+        return half_gcd_euclidean(a.clone(), b.clone(), m);
+    }
+
+    // The quotients of the Euclidean algorithm only depend on the high
+    // coefficients of `a` and `b`, so the first half of them can be computed
+    // from `a / x^m` and `b / x^m`.
+    let r = half_gcd(&shift_down(a, m), &shift_down(b, m));
+    let (a, b) = r.apply(a, b);
+    if degree_below(&b, m) {
+        return r;
+    }
+
+    let (q, rem) = a.div_rem(&b);
+    let (a, b) = (b, rem);
+    let k = 2 * m - a.degree();
+    let s = half_gcd(&shift_down(&a, k), &shift_down(&b, k));
+    s.mul(&Matrix::quotient(q)).mul(&r)
+}
+
+/// Computes [`half_gcd`] with the Euclidean algorithm.
+fn half_gcd_euclidean<F: FftField>(
+    mut a: DensePolynomial<F>,
+    mut b: DensePolynomial<F>,
+    m: usize,
+) -> Matrix<F> {
+    let mut matrix = Matrix::identity();
+    while !degree_below(&b, m) {
+        let (q, r) = euclidean_div_rem(&a, &b);
+        matrix = Matrix::quotient(q).mul(&matrix);
+        a = mem::replace(&mut b, r);
+    }
+    matrix
+}
+
+/// For `deg(a) > deg(b)`, returns a matrix `M` and the (not necessarily
+/// monic) greatest common divisor `g` of `a` and `b`, such that `M` maps
+/// `(a, b)` to `(g, 0)`.
+fn gcd_matrix<F: FftField>(
+    mut a: DensePolynomial<F>,
+    mut b: DensePolynomial<F>,
+) -> (Matrix<F>, DensePolynomial<F>) {
+    let mut matrix = Matrix::identity();
+    while !b.is_zero() {
+        let r = half_gcd(&a, &b);
+        (a, b) = r.apply(&a, &b);
+        matrix = r.mul(&matrix);
+        if b.is_zero() {
+            break;
+        }
+        let (q, rem) = a.div_rem(&b);
+        matrix = Matrix::quotient(q).mul(&matrix);
+        a = mem::replace(&mut b, rem);
+    }
+    (matrix, a)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{polynomial::univariate::*, DenseUVPolynomial, Polynomial};
+    use ark_ff::{Field, One, UniformRand, Zero};
+    use ark_std::{rand::Rng, test_rng};
+    use ark_test_curves::bls12_381::Fr;
+
+    /// Returns two random polynomials with a common factor of degree `common`.
+    fn rand_pair<F: Field, R: Rng>(
+        a_degree: usize,
+        b_degree: usize,
+        common: usize,
+        rng: &mut R,
+    ) -> (DensePolynomial<F>, DensePolynomial<F>) {
+        let c = DensePolynomial::rand(common, rng);
+        let a = DensePolynomial::rand(a_degree, rng).naive_mul(&c);
+        let b = DensePolynomial::rand(b_degree, rng).naive_mul(&c);
+        (a, b)
+    }
+
+    fn check_xgcd<F: Field>(
+        a: &DensePolynomial<F>,
+        b: &DensePolynomial<F>,
+        (g, s, t): &(DensePolynomial<F>, DensePolynomial<F>, DensePolynomial<F>),
+    ) {
+        assert_eq!(&(&s.naive_mul(a) + &t.naive_mul(b)), g);
+        if !g.is_zero() {
+            assert!(g.last().unwrap().is_one());
+            assert!(super::euclidean_div_rem(a, g).1.is_zero());
+            assert!(super::euclidean_div_rem(b, g).1.is_zero());
+        }
+    }
+
+    #[test]
+    fn gcd_small() {
+        let rng = &mut test_rng();
+        for a_degree in 0..12 {
+            for b_degree in 0..12 {
+                for common in 0..4 {
+                    let (a, b) = rand_pair::<Fr, _>(a_degree, b_degree, common, rng);
+                    let xgcd = a.xgcd(&b);
+                    check_xgcd(&a, &b, &xgcd);
+                    assert!(xgcd.0.degree() >= common);
+                    assert_eq!(a.gcd(&b), xgcd.0);
+                    assert_eq!(a.xgcd_fast(&b), xgcd);
+                }
+            }
+        }
+
+        let zero = DensePolynomial::<Fr>::zero();
+        let a = DensePolynomial::<Fr>::rand(5, rng);
+        assert_eq!(zero.gcd(&zero), zero);
+        assert_eq!(zero.xgcd_fast(&zero).0, zero);
+        for (x, y) in [(&a, &zero), (&zero, &a)] {
+            let xgcd = x.xgcd(y);
+            check_xgcd(x, y, &xgcd);
+            assert_eq!(x.xgcd_fast(y), xgcd);
+        }
+    }
+
+    #[test]
+    fn gcd_fast_matches_euclidean() {
+        let rng = &mut test_rng();
+        for (a_degree, b_degree, common) in [
+            (300, 200, 0),
+            (250, 250, 10),
+            (200, 300, 150),
+            (400, 3, 40),
+            (500, 499, 1),
+        ] {
+            let (a, b) = rand_pair::<Fr, _>(a_degree, b_degree, common, rng);
+            let xgcd = a.xgcd(&b);
+            check_xgcd(&a, &b, &xgcd);
+            assert_eq!(a.xgcd_fast(&b), xgcd);
+            assert_eq!(a.gcd_fast(&b), xgcd.0);
+        }
+    }
+
+    #[test]
+    fn gcd_fast_without_fft() {
+        // The multiplicative group of this field has two-adicity 6, so there
+        // are no evaluation domains for the larger products.
+        use ark_test_curves::secp256k1::Fr;
+
+        let rng = &mut test_rng();
+        for (a_degree, b_degree, common) in [(300, 200, 0), (200, 300, 100)] {
+            let (a, b) = rand_pair::<Fr, _>(a_degree, b_degree, common, rng);
+            let xgcd = a.xgcd(&b);
+            check_xgcd(&a, &b, &xgcd);
+            assert_eq!(a.xgcd_fast(&b), xgcd);
+            assert_eq!(a.gcd_fast(&b), xgcd.0);
+        }
+    }
+
+    #[test]
+    fn resultant() {
+        let rng = &mut test_rng();
+        for n in 1..8 {
+            for m in 0..8 {
+                // For `a = lc(a) * prod (x - r_i)`, `res(a, b) = lc(a)^m * prod b(r_i)`.
+                let roots: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+                let lc = Fr::rand(rng);
+                let a = roots.iter().fold(
+                    DensePolynomial::from_coefficients_vec(vec![lc]),
+                    |acc, r| {
+                        acc.naive_mul(&DensePolynomial::from_coefficients_vec(vec![
+                            -*r,
+                            Fr::one(),
+                        ]))
+                    },
+                );
+                let b = DensePolynomial::<Fr>::rand(m, rng);
+                let expected = roots
+                    .iter()
+                    .fold(lc.pow([m as u64]), |acc, r| acc * b.evaluate(r));
+                assert_eq!(a.resultant(&b), expected);
+
+                let sign = if (n * m) % 2 == 1 {
+                    -Fr::one()
+                } else {
+                    Fr::one()
+                };
+                assert_eq!(b.resultant(&a), sign * expected);
+
+                // A shared root makes the resultant vanish.
+                let shifted =
+                    &b - &DensePolynomial::from_coefficients_vec(vec![b.evaluate(&roots[0])]);
+                if m > 0 {
+                    assert!(a.resultant(&shifted).is_zero());
+                }
+            }
+        }
+        let zero = DensePolynomial::<Fr>::zero();
+        assert!(zero.resultant(&DensePolynomial::rand(3, rng)).is_zero());
+    }
+
+    #[test]
+    fn inverse_mod() {
+        let rng = &mut test_rng();
+        let modulus = DensePolynomial::<Fr>::rand(20, rng);
+        for degree in [0, 5, 19, 40] {
+            let a = DensePolynomial::<Fr>::rand(degree, rng);
+            let inverse = a.inverse_mod(&modulus).unwrap();
+            assert!(inverse.degree() < modulus.degree());
+            let product =
+                &a.naive_mul(&inverse) - &DensePolynomial::from_coefficients_vec(vec![Fr::one()]);
+            assert!((&product / &modulus).naive_mul(&modulus) == product);
+        }
+
+        let factor = DensePolynomial::<Fr>::rand(3, rng);
+        let a = factor.naive_mul(&DensePolynomial::rand(4, rng));
+        assert!(a.inverse_mod(&factor.naive_mul(&modulus)).is_none());
+
+        let constant = DensePolynomial::from_coefficients_vec(vec![Fr::one()]);
+        assert!(constant.inverse_mod(&DensePolynomial::zero()).is_none());
+        assert!(a.inverse_mod(&DensePolynomial::zero()).is_none());
+    }
+}
@@ -6,10 +6,15 @@ use ark_std::{borrow::Cow, cfg_iter_mut, vec, vec::*};
 use DenseOrSparsePolynomial::{DPolynomial, SPolynomial};
 
 mod dense;
+mod factor;
+mod gcd;
 mod sparse;
+mod subproduct_tree;
 
 pub use dense::DensePolynomial;
+pub(crate) use gcd::partial_xgcd;
 pub use sparse::SparsePolynomial;
+pub use subproduct_tree::SubproductTree;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
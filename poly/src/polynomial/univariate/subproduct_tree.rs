@@ -0,0 +1,275 @@
+//! A subproduct tree for evaluating and interpolating polynomials at arbitrary
+//! points.
+use crate::{univariate::DensePolynomial, DenseUVPolynomial, Polynomial};
+use ark_ff::FftField;
+use ark_std::{boxed::Box, vec, vec::*};
+
+/// A binary tree whose leaves are the linear polynomials `x - a_i` for a list
+/// of points `a_i`, and whose inner nodes are the products of their children.
+///
+/// Building the tree takes `O(n log^2 n)` field operations using FFT
+/// multiplication. It can then be used to evaluate a polynomial at all of the
+/// points, or to interpolate a polynomial from its values at them, with the
+/// same complexity. The root is the vanishing polynomial of the points.
+///
+/// Over fields without evaluation domains of the required sizes, such as
+/// the scalar field of secp256k1, the products use Karatsuba multiplication
+/// instead, which takes `O(n^1.59)` field operations.
+///
+/// # Example
+///
+/// ```
+/// use ark_poly::{univariate::{DensePolynomial, SubproductTree}, DenseUVPolynomial, Polynomial};
+/// use ark_test_curves::bls12_381::Fr;
+///
+/// let points = [Fr::from(1u64), Fr::from(5u64), Fr::from(7u64)];
+/// let tree = SubproductTree::new(&points);
+///
+/// let poly = DensePolynomial::from_coefficients_slice(&[Fr::from(2u64), Fr::from(3u64)]);
+/// let evals = tree.evaluate(&poly);
+/// assert_eq!(evals, points.iter().map(|p| poly.evaluate(p)).collect::<Vec<_>>());
+/// assert_eq!(tree.interpolate(&evals), poly);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubproductTree<F: FftField> {
+    /// The product of `x - a_i` over the points `a_i` below this node.
+    poly: DensePolynomial<F>,
+    /// The subtrees for the two halves of the points, or `None` at a leaf.
+    children: Option<Box<(Self, Self)>>,
+}
+
+impl<F: FftField> SubproductTree<F> {
+    /// Builds the subproduct tree of `points`, which must not be empty.
+    pub fn new(points: &[F]) -> Self {
+        assert!(
+            !points.is_empty(),
+            "cannot build a subproduct tree of no points"
+        );
+        if let [point] = points {
+            return Self {
+                poly: DensePolynomial::from_coefficients_vec(vec![-*point, F::one()]),
+                children: None,
+            };
+        }
+        let (left, right) = points.split_at(points.len() / 2);
+        let (left, right) = (Self::new(left), Self::new(right));
+        Self {
+            poly: left.poly.mul_by_size(&right.poly),
+            children: Some(Box::new((left, right))),
+        }
+    }
+
+    /// Returns the number of points of the tree.
+    pub fn len(&self) -> usize {
+        self.poly.degree()
+    }
+
+    /// Returns `false`, as a subproduct tree always has at least one point.
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns the points of the tree, in the order they were given.
+    pub fn points(&self) -> Vec<F> {
+        let mut points = Vec::with_capacity(self.len());
+        self.collect_points(&mut points);
+        points
+    }
+
+    fn collect_points(&self, points: &mut Vec<F>) {
+        match &self.children {
+            Some(children) => {
+                children.0.collect_points(points);
+                children.1.collect_points(points);
+            },
+            None => points.push(-self.poly.coeffs[0]),
+        }
+    }
+
+    /// Returns the vanishing polynomial of the points, i.e. the product of
+    /// `x - a_i`.
+    pub const fn vanishing_polynomial(&self) -> &DensePolynomial<F> {
+        &self.poly
+    }
+
+    /// Evaluates `poly` at all points of the tree, in their original order.
+    ///
+    /// This successively reduces `poly` modulo the polynomials along the
+    /// paths from the root to the leaves, where `p mod (x - a_i) = p(a_i)`.
+    pub fn evaluate(&self, poly: &DensePolynomial<F>) -> Vec<F> {
+        let mut evals = Vec::with_capacity(self.len());
+        self.evaluate_helper(poly, &mut evals);
+        evals
+    }
+
+    fn evaluate_helper(&self, poly: &DensePolynomial<F>, evals: &mut Vec<F>) {
+        let remainder = if poly.len() > self.poly.degree() {
+            poly.div_rem(&self.poly).1
+        } else {
+            poly.clone()
+        };
+        match &self.children {
+            Some(children) => {
+                children.0.evaluate_helper(&remainder, evals);
+                children.1.evaluate_helper(&remainder, evals);
+            },
+            None => evals.push(remainder.coeffs.first().copied().unwrap_or_else(F::zero)),
+        }
+    }
+
+    /// Returns the unique polynomial of degree less than `self.len()` that
+    /// takes the value `values[i]` at the `i`-th point of the tree.
+    ///
+    /// The points must be distinct.
+    pub fn interpolate(&self, values: &[F]) -> DensePolynomial<F> {
+        assert_eq!(
+            values.len(),
+            self.len(),
+            "the number of values must equal the number of points"
+        );
+
+        // The Lagrange basis polynomial of `a_i` is `m(x) / ((x - a_i) m'(a_i))`,
+        // where `m` is the vanishing polynomial of all points.
+        let derivative = DensePolynomial::from_coefficients_vec(
+            self.poly
+                .coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| F::from(i as u64) * c)
+                .collect(),
+        );
+        let mut weights = self.evaluate(&derivative);
+        assert!(
+            weights.iter().all(|w| !w.is_zero()),
+            "the points of the subproduct tree must be distinct"
+        );
+        ark_ff::batch_inversion(&mut weights);
+        weights.iter_mut().zip(values).for_each(|(w, v)| *w *= v);
+
+        let (result, rest) = self.linear_combination(&weights);
+        debug_assert!(rest.is_empty());
+        result
+    }
+
+    /// Returns `sum_i weights[i] * m(x) / (x - a_i)` for the points `a_i` and
+    /// vanishing polynomial `m` of this subtree, together with the unused
+    /// weights.
+    fn linear_combination<'a>(&self, weights: &'a [F]) -> (DensePolynomial<F>, &'a [F]) {
+        match &self.children {
+            Some(children) => {
+                let (left, weights) = children.0.linear_combination(weights);
+                let (right, weights) = children.1.linear_combination(weights);
+                let result =
+                    &left.mul_by_size(&children.1.poly) + &right.mul_by_size(&children.0.poly);
+                (result, weights)
+            },
+            None => (
+                DensePolynomial::from_coefficients_vec(vec![weights[0]]),
+                &weights[1..],
+            ),
+        }
+    }
+
+    /// Interpolates the unique polynomial of degree less than
+    /// `points_and_values.len()` through the given `(point, value)` pairs,
+    /// whose points must be distinct.
+    pub fn interpolate_pairs(points_and_values: &[(F, F)]) -> DensePolynomial<F> {
+        let (points, values): (Vec<_>, Vec<_>) = points_and_values.iter().copied().unzip();
+        Self::new(&points).interpolate(&values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubproductTree;
+    use crate::{
+        polynomial::univariate::*, DenseUVPolynomial, EvaluationDomain, Evaluations,
+        GeneralEvaluationDomain, Polynomial,
+    };
+    use ark_ff::{UniformRand, Zero};
+    use ark_std::test_rng;
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn evaluate_and_interpolate_random_points() {
+        let rng = &mut test_rng();
+        for n in [1, 2, 3, 7, 33, 100] {
+            let points: Vec<Fr> = (0..n).map(|_| Fr::rand(rng)).collect();
+            let tree = SubproductTree::new(&points);
+            assert_eq!(tree.len(), n);
+            assert_eq!(tree.points(), points);
+
+            for degree in [0, n / 2, n - 1, 2 * n] {
+                let poly = DensePolynomial::<Fr>::rand(degree, rng);
+                let evals = tree.evaluate(&poly);
+                let expected: Vec<_> = points.iter().map(|p| poly.evaluate(p)).collect();
+                assert_eq!(evals, expected);
+                if degree < n {
+                    assert_eq!(tree.interpolate(&evals), poly);
+                }
+            }
+
+            let vanishing = tree.vanishing_polynomial();
+            assert_eq!(vanishing.degree(), n);
+            assert!(points.iter().all(|p| vanishing.evaluate(p).is_zero()));
+
+            let pairs: Vec<_> = points.iter().map(|p| (*p, p.square())).collect();
+            let poly = SubproductTree::interpolate_pairs(&pairs);
+            assert!(pairs.iter().all(|(p, v)| poly.evaluate(p) == *v));
+        }
+        assert!(SubproductTree::new(&[Fr::from(3u64)])
+            .evaluate(&DensePolynomial::zero())
+            .iter()
+            .all(Fr::is_zero));
+    }
+
+    #[test]
+    fn matches_evaluation_domain() {
+        let rng = &mut test_rng();
+        for log_size in [0, 1, 4, 7] {
+            let domain = GeneralEvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let tree = SubproductTree::new(&domain.elements().collect::<Vec<_>>());
+
+            let vanishing: DensePolynomial<Fr> = domain.vanishing_polynomial().into();
+            assert_eq!(tree.vanishing_polynomial(), &vanishing);
+
+            let poly = DensePolynomial::<Fr>::rand(domain.size() - 1, rng);
+            let evals = poly.evaluate_over_domain_by_ref(domain);
+            assert_eq!(tree.evaluate(&poly), evals.evals);
+
+            let values: Vec<Fr> = (0..domain.size()).map(|_| Fr::rand(rng)).collect();
+            assert_eq!(
+                tree.interpolate(&values),
+                Evaluations::from_vec_and_domain(values, domain).interpolate()
+            );
+        }
+    }
+
+    #[test]
+    fn evaluate_and_interpolate_without_fft() {
+        // The multiplicative group of this field has two-adicity 6, so there
+        // are no evaluation domains for the larger products.
+        use ark_test_curves::secp256k1::Fr;
+
+        let rng = &mut test_rng();
+        let points: Vec<Fr> = (0..200).map(|_| Fr::rand(rng)).collect();
+        let tree = SubproductTree::new(&points);
+        assert!(points
+            .iter()
+            .all(|p| tree.vanishing_polynomial().evaluate(p).is_zero()));
+
+        let poly = DensePolynomial::<Fr>::rand(199, rng);
+        let evals = tree.evaluate(&poly);
+        let expected: Vec<_> = points.iter().map(|p| poly.evaluate(p)).collect();
+        assert_eq!(evals, expected);
+        assert_eq!(tree.interpolate(&evals), poly);
+    }
+
+    #[test]
+    #[should_panic(expected = "distinct")]
+    fn interpolate_repeated_points() {
+        let points = [Fr::from(1u64), Fr::from(2u64), Fr::from(1u64)];
+        SubproductTree::new(&points).interpolate(&[Fr::zero(); 3]);
+    }
+}
@@ -1,7 +1,7 @@
-use crate::serialize::IdentOrIndex;
+use crate::serialize::{enum_tag, enum_tag_type, variant_pattern, IdentOrIndex};
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Data, Index, Type};
+use syn::{Data, DataEnum, Fields, Index, Type};
 
 fn impl_valid_field(
     check_body: &mut Vec<TokenStream>,
@@ -44,55 +44,83 @@ fn impl_valid_field(
     }
 }
 
+fn impl_valid_enum(ast: &syn::DeriveInput, data_enum: &DataEnum) -> TokenStream {
+    let name = &ast.ident;
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let field_types = data_enum
+        .variants
+        .iter()
+        .flat_map(|variant| variant.fields.iter().map(|field| &field.ty));
+
+    let check_arms = data_enum.variants.iter().map(|variant| {
+        let (pattern, bindings) = variant_pattern(variant);
+        quote! {
+            #pattern => {
+                #( ark_serialize::Valid::check(#bindings)?; )*
+            },
+        }
+    });
+
+    quote! {
+        impl #impl_generics ark_serialize::Valid for #name #ty_generics #where_clause {
+            const TRIVIAL_CHECK: bool = true #( && <#field_types as ark_serialize::Valid>::TRIVIAL_CHECK )*;
+            fn check(&self) -> Result<(), ark_serialize::SerializationError> {
+                if Self::TRIVIAL_CHECK {
+                    return Ok(());
+                }
+                match *self {
+                    #(#check_arms)*
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
 fn impl_valid(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
 
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
-    let len = if let Data::Struct(ref data_struct) = ast.data {
-        data_struct.fields.len()
-    } else {
-        panic!(
-            "`Valid` can only be derived for structs, {} is not a struct",
+    let data_struct = match ast.data {
+        Data::Struct(ref data_struct) => data_struct,
+        Data::Enum(ref data_enum) => return impl_valid_enum(ast, data_enum),
+        Data::Union(_) => panic!(
+            "`Valid` can only be derived for structs and enums, {} is a union",
             name
-        );
+        ),
     };
+    let len = data_struct.fields.len();
 
     let mut check_body = Vec::<TokenStream>::with_capacity(len);
     let mut batch_body = Vec::<TokenStream>::with_capacity(len);
     let mut trivial_check_body = Vec::<TokenStream>::with_capacity(len);
 
-    match ast.data {
-        Data::Struct(ref data_struct) => {
-            let mut idents = Vec::<IdentOrIndex>::new();
+    let mut idents = Vec::<IdentOrIndex>::new();
 
-            for (i, field) in data_struct.fields.iter().enumerate() {
-                match field.ident {
-                    None => {
-                        let index = Index::from(i);
-                        idents.push(IdentOrIndex::Index(index));
-                    },
-                    Some(ref ident) => {
-                        idents.push(IdentOrIndex::Ident(ident.clone()));
-                    },
-                }
+    for (i, field) in data_struct.fields.iter().enumerate() {
+        match field.ident {
+            None => {
+                let index = Index::from(i);
+                idents.push(IdentOrIndex::Index(index));
+            },
+            Some(ref ident) => {
+                idents.push(IdentOrIndex::Ident(ident.clone()));
+            },
+        }
 
-                impl_valid_field(
-                    &mut check_body,
-                    &mut batch_body,
-                    &mut trivial_check_body,
-                    &mut idents,
-                    &field.ty,
-                );
+        impl_valid_field(
+            &mut check_body,
+            &mut batch_body,
+            &mut trivial_check_body,
+            &mut idents,
+            &field.ty,
+        );
 
-                idents.clear();
-            }
-        },
-        _ => panic!(
-            "`Valid` can only be derived for structs, {} is not a struct",
-            name
-        ),
-    };
+        idents.clear();
+    }
 
     let check_body = if check_body.len() == 1 {
         quote! { #(#check_body)* }
@@ -146,7 +174,7 @@ fn impl_deserialize_field(ty: &Type) -> TokenStream {
     }
 }
 
-pub(super) fn impl_canonical_deserialize(ast: &syn::DeriveInput) -> TokenStream {
+pub(super) fn impl_canonical_deserialize(ast: &syn::DeriveInput) -> syn::Result<TokenStream> {
     let valid_impl = impl_valid(ast);
     let name = &ast.ident;
 
@@ -187,8 +215,41 @@ pub(super) fn impl_canonical_deserialize(ast: &syn::DeriveInput) -> TokenStream
                 })
             };
         },
-        _ => panic!(
-            "`CanonicalDeserialize` can only be derived for structs, {} is not a Struct",
+        Data::Enum(ref data_enum) => {
+            let tag_ty = enum_tag_type(ast, data_enum)?;
+            let variant_cases = data_enum.variants.iter().enumerate().map(|(i, variant)| {
+                let tag = enum_tag(i);
+                let variant_name = &variant.ident;
+                match &variant.fields {
+                    Fields::Named(fields) => {
+                        let field_cases = fields.named.iter().map(|field| {
+                            let ident = &field.ident;
+                            let compressed = impl_deserialize_field(&field.ty);
+                            quote! { #ident: #compressed }
+                        });
+                        quote! { #tag => Ok(Self::#variant_name { #(#field_cases)* }), }
+                    },
+                    Fields::Unnamed(fields) => {
+                        let field_cases = fields
+                            .unnamed
+                            .iter()
+                            .map(|field| impl_deserialize_field(&field.ty));
+                        quote! { #tag => Ok(Self::#variant_name ( #(#field_cases)* )), }
+                    },
+                    Fields::Unit => quote! { #tag => Ok(Self::#variant_name), },
+                }
+            });
+
+            deserialize_body = quote!({
+                let tag = <#tag_ty as CanonicalDeserialize>::deserialize_with_mode(&mut reader, compress, validate)?;
+                match tag {
+                    #(#variant_cases)*
+                    _ => Err(ark_serialize::SerializationError::InvalidData),
+                }
+            });
+        },
+        Data::Union(_) => panic!(
+            "`CanonicalDeserialize` can only be derived for structs and enums, {} is a union",
             name
         ),
     };
@@ -205,5 +266,5 @@ pub(super) fn impl_canonical_deserialize(ast: &syn::DeriveInput) -> TokenStream
         }
     };
     gen.extend(valid_impl);
-    gen
+    Ok(gen)
 }
@@ -7,7 +7,7 @@
 )]
 #![forbid(unsafe_code)]
 
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields};
 
 mod serialize;
 use serialize::impl_canonical_serialize;
@@ -15,14 +15,80 @@ use serialize::impl_canonical_serialize;
 mod deserialize;
 use deserialize::impl_canonical_deserialize;
 
-#[proc_macro_derive(CanonicalSerialize)]
+/// Derives `CanonicalSerialize` for a struct or an enum.
+///
+/// Enums are serialized as the index of the variant, followed by its fields.
+/// The index is a `u8` by default, and can be set to any of `u8`, `u16`,
+/// `u32` or `u64` with `#[ark_serialize(tag = u16)]`. Enums without variants,
+/// or with more variants than the tag can represent, are rejected, as is
+/// `#[ark_serialize(...)]` anywhere other than on an enum.
+#[proc_macro_derive(CanonicalSerialize, attributes(ark_serialize))]
 pub fn derive_canonical_serialize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
-    proc_macro::TokenStream::from(impl_canonical_serialize(&ast))
+    check_not_empty_enum(&ast)
+        .and_then(|()| check_attributes(&ast))
+        .and_then(|()| impl_canonical_serialize(&ast))
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
 }
 
-#[proc_macro_derive(CanonicalDeserialize)]
+/// Derives `CanonicalDeserialize` and `Valid` for a struct or an enum.
+///
+/// See [`CanonicalSerialize`](derive@CanonicalSerialize) for the encoding of
+/// enums. Unknown variant indices are rejected with
+/// `SerializationError::InvalidData`.
+#[proc_macro_derive(CanonicalDeserialize, attributes(ark_serialize))]
 pub fn derive_canonical_deserialize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
-    proc_macro::TokenStream::from(impl_canonical_deserialize(&ast))
+    check_not_empty_enum(&ast)
+        .and_then(|()| check_attributes(&ast))
+        .and_then(|()| impl_canonical_deserialize(&ast))
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Rejects enums without variants, which have no values to serialize.
+fn check_not_empty_enum(ast: &DeriveInput) -> syn::Result<()> {
+    match ast.data {
+        Data::Enum(ref data_enum) if data_enum.variants.is_empty() => Err(syn::Error::new_spanned(
+            &ast.ident,
+            "`CanonicalSerialize` and `CanonicalDeserialize` cannot be derived for enums without variants",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Rejects `#[ark_serialize(...)]` everywhere except on enums, where it sets
+/// the type of the variant index.
+fn check_attributes(ast: &DeriveInput) -> syn::Result<()> {
+    let fields: Vec<&Fields> = match ast.data {
+        Data::Struct(ref data_struct) => {
+            reject_ark_serialize(&ast.attrs, "structs")?;
+            vec![&data_struct.fields]
+        },
+        Data::Enum(ref data_enum) => {
+            for variant in &data_enum.variants {
+                reject_ark_serialize(&variant.attrs, "enum variants")?;
+            }
+            data_enum.variants.iter().map(|v| &v.fields).collect()
+        },
+        Data::Union(_) => {
+            reject_ark_serialize(&ast.attrs, "unions")?;
+            Vec::new()
+        },
+    };
+    for field in fields.into_iter().flatten() {
+        reject_ark_serialize(&field.attrs, "fields")?;
+    }
+    Ok(())
+}
+
+fn reject_ark_serialize(attrs: &[Attribute], target: &str) -> syn::Result<()> {
+    match attrs.iter().find(|a| a.path().is_ident("ark_serialize")) {
+        Some(attr) => Err(syn::Error::new_spanned(
+            attr,
+            format!("`ark_serialize` attributes are not supported on {target}"),
+        )),
+        None => Ok(()),
+    }
 }
@@ -1,6 +1,6 @@
-use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
-use syn::{Data, Index, Type};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Data, DataEnum, Fields, Index, Type};
 
 pub(crate) enum IdentOrIndex {
     Ident(proc_macro2::Ident),
@@ -16,6 +16,77 @@ impl ToTokens for IdentOrIndex {
     }
 }
 
+/// Returns the type of the variant index of an enum, as set by
+/// `#[ark_serialize(tag = ...)]`, and `u8` otherwise.
+pub(crate) fn enum_tag_type(ast: &syn::DeriveInput, data_enum: &DataEnum) -> syn::Result<Ident> {
+    let mut tag = Ident::new("u8", Span::call_site());
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("ark_serialize"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag") {
+                tag = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported `ark_serialize` attribute"))
+            }
+        })?;
+    }
+
+    let max_variants: u128 = match tag.to_string().as_str() {
+        "u8" => 1 << 8,
+        "u16" => 1 << 16,
+        "u32" => 1 << 32,
+        "u64" => 1 << 64,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &tag,
+                format!(
+                    "the tag of {} must be one of `u8`, `u16`, `u32` or `u64`, not `{}`",
+                    ast.ident, tag
+                ),
+            ))
+        },
+    };
+    if (data_enum.variants.len() as u128) > max_variants {
+        return Err(syn::Error::new_spanned(
+            &ast.ident,
+            format!("{} has too many variants for a `{}` tag", ast.ident, tag),
+        ));
+    }
+    Ok(tag)
+}
+
+/// Returns the literal for the variant index `i`.
+pub(crate) fn enum_tag(i: usize) -> Literal {
+    Literal::usize_unsuffixed(i)
+}
+
+/// Returns a pattern binding all fields of a variant by reference, together
+/// with the names of the bindings.
+pub(crate) fn variant_pattern(variant: &syn::Variant) -> (TokenStream, Vec<Ident>) {
+    let name = &variant.ident;
+    match &variant.fields {
+        Fields::Named(fields) => {
+            let bindings: Vec<_> = fields
+                .named
+                .iter()
+                .map(|f| f.ident.clone().unwrap())
+                .collect();
+            (quote! { Self::#name { #(ref #bindings),* } }, bindings)
+        },
+        Fields::Unnamed(fields) => {
+            let bindings: Vec<_> = (0..fields.unnamed.len())
+                .map(|i| format_ident!("__field_{}", i))
+                .collect();
+            (quote! { Self::#name ( #(ref #bindings),* ) }, bindings)
+        },
+        Fields::Unit => (quote! { Self::#name }, Vec::new()),
+    }
+}
+
 fn impl_serialize_field(
     serialize_body: &mut Vec<TokenStream>,
     serialized_size_body: &mut Vec<TokenStream>,
@@ -41,25 +112,54 @@ fn impl_serialize_field(
     }
 }
 
-pub(super) fn impl_canonical_serialize(ast: &syn::DeriveInput) -> TokenStream {
-    let name = &ast.ident;
+fn impl_serialize_enum(
+    ast: &syn::DeriveInput,
+    data_enum: &DataEnum,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    let tag_ty = enum_tag_type(ast, data_enum)?;
 
-    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let mut serialize_arms = Vec::<TokenStream>::with_capacity(data_enum.variants.len());
+    let mut serialized_size_arms = Vec::<TokenStream>::with_capacity(data_enum.variants.len());
+    for (i, variant) in data_enum.variants.iter().enumerate() {
+        let tag = enum_tag(i);
+        let (pattern, bindings) = variant_pattern(variant);
+        serialize_arms.push(quote! {
+            #pattern => {
+                CanonicalSerialize::serialize_with_mode(&(#tag as #tag_ty), &mut writer, compress)?;
+                #( CanonicalSerialize::serialize_with_mode(#bindings, &mut writer, compress)?; )*
+            },
+        });
+        serialized_size_arms.push(quote! {
+            #pattern => {
+                #( size += CanonicalSerialize::serialized_size(#bindings, compress); )*
+            },
+        });
+    }
 
-    let len = if let Data::Struct(ref data_struct) = ast.data {
-        data_struct.fields.len()
-    } else {
-        panic!(
-            "`CanonicalSerialize` can only be derived for structs, {} is not a struct",
-            name
-        );
+    let serialize_body = quote! {
+        match *self {
+            #(#serialize_arms)*
+        }
+    };
+    let serialized_size_body = quote! {
+        size += CanonicalSerialize::serialized_size(&(0 as #tag_ty), compress);
+        match *self {
+            #(#serialized_size_arms)*
+        }
     };
+    Ok((serialize_body, serialized_size_body))
+}
 
-    let mut serialize_body = Vec::<TokenStream>::with_capacity(len);
-    let mut serialized_size_body = Vec::<TokenStream>::with_capacity(len);
+pub(super) fn impl_canonical_serialize(ast: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
-    match ast.data {
+    let (serialize_body, serialized_size_body) = match ast.data {
         Data::Struct(ref data_struct) => {
+            let len = data_struct.fields.len();
+            let mut serialize_body = Vec::<TokenStream>::with_capacity(len);
+            let mut serialized_size_body = Vec::<TokenStream>::with_capacity(len);
             let mut idents = Vec::<IdentOrIndex>::new();
 
             for (i, field) in data_struct.fields.iter().enumerate() {
@@ -82,9 +182,14 @@ pub(super) fn impl_canonical_serialize(ast: &syn::DeriveInput) -> TokenStream {
 
                 idents.clear();
             }
+            (
+                quote! { #(#serialize_body)* },
+                quote! { #(#serialized_size_body)* },
+            )
         },
-        _ => panic!(
-            "`CanonicalSerialize` can only be derived for structs, {} is not a struct",
+        Data::Enum(ref data_enum) => impl_serialize_enum(ast, data_enum)?,
+        Data::Union(_) => panic!(
+            "`CanonicalSerialize` can only be derived for structs and enums, {} is a union",
             name
         ),
     };
@@ -92,15 +197,15 @@ pub(super) fn impl_canonical_serialize(ast: &syn::DeriveInput) -> TokenStream {
     let gen = quote! {
         impl #impl_generics ark_serialize::CanonicalSerialize for #name #ty_generics #where_clause {
             fn serialize_with_mode<W: ark_serialize::Write>(&self, mut writer: W, compress: ark_serialize::Compress) -> Result<(), ark_serialize::SerializationError> {
-                #(#serialize_body)*
+                #serialize_body
                 Ok(())
             }
             fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
                 let mut size = 0;
-                #(#serialized_size_body)*
+                #serialized_size_body
                 size
             }
         }
     };
-    gen
+    Ok(gen)
 }
@@ -110,7 +110,7 @@ pub trait Valid: Sync {
 }
 
 /// Serializer in little endian format.
-/// This trait can be derived if all fields of a struct implement
+/// This trait can be derived if all fields of a struct or an enum implement
 /// `CanonicalSerialize` and the `derive` feature is enabled.
 ///
 /// # Example
@@ -158,7 +158,7 @@ pub trait CanonicalSerialize {
 }
 
 /// Deserializer in little endian format.
-/// This trait can be derived if all fields of a struct implement
+/// This trait can be derived if all fields of a struct or an enum implement
 /// `CanonicalDeserialize` and the `derive` feature is enabled.
 ///
 /// # Example
@@ -0,0 +1,131 @@
+#![cfg(feature = "derive")]
+
+use ark_serialize::*;
+
+#[derive(Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+enum Message {
+    Empty,
+    Pair(u64, (u8, u32)),
+    Named { a: u16, b: Vec<u8> },
+}
+
+#[derive(Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+#[ark_serialize(tag = u16)]
+enum WideTag<T: CanonicalSerialize + CanonicalDeserialize> {
+    First(T),
+    Second,
+}
+
+/// A value that is only valid if it is even.
+#[derive(Debug, PartialEq, Eq)]
+struct Even(u8);
+
+impl CanonicalSerialize for Even {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.0.serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.0.serialized_size(compress)
+    }
+}
+
+impl Valid for Even {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.0 % 2 == 0 {
+            Ok(())
+        } else {
+            Err(SerializationError::InvalidData)
+        }
+    }
+}
+
+impl CanonicalDeserialize for Even {
+    fn deserialize_with_mode<R: Read>(
+        reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let value = Self(u8::deserialize_with_mode(reader, compress, Validate::No)?);
+        if validate == Validate::Yes {
+            value.check()?;
+        }
+        Ok(value)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+enum Checked {
+    Unchecked(u8),
+    Checked { value: Even },
+}
+
+fn test_roundtrip<T>(value: T, expected: &[u8])
+where
+    T: CanonicalSerialize + CanonicalDeserialize + PartialEq + core::fmt::Debug,
+{
+    for compress in [Compress::Yes, Compress::No] {
+        let mut bytes = Vec::new();
+        value.serialize_with_mode(&mut bytes, compress).unwrap();
+        assert_eq!(bytes, expected);
+        assert_eq!(value.serialized_size(compress), expected.len());
+
+        let de = T::deserialize_with_mode(&bytes[..], compress, Validate::Yes).unwrap();
+        assert_eq!(value, de);
+    }
+}
+
+#[test]
+fn test_enum_variants() {
+    test_roundtrip(Message::Empty, &[0]);
+    test_roundtrip(
+        Message::Pair(1, (2, 3)),
+        &[1, 1, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0],
+    );
+    test_roundtrip(
+        Message::Named {
+            a: 4,
+            b: vec![5, 6],
+        },
+        &[2, 4, 0, 2, 0, 0, 0, 0, 0, 0, 0, 5, 6],
+    );
+}
+
+#[test]
+fn test_enum_tag_type() {
+    test_roundtrip(WideTag::First(7u8), &[0, 0, 7]);
+    test_roundtrip(WideTag::<u8>::Second, &[1, 0]);
+}
+
+#[test]
+fn test_enum_unknown_tag() {
+    assert!(matches!(
+        Message::deserialize_compressed(&[3u8][..]),
+        Err(SerializationError::InvalidData)
+    ));
+    assert!(matches!(
+        WideTag::<u8>::deserialize_compressed(&[0u8, 1][..]),
+        Err(SerializationError::InvalidData)
+    ));
+}
+
+#[test]
+fn test_enum_valid() {
+    const _: () = assert!(!Checked::TRIVIAL_CHECK);
+    const _: () = assert!(Message::TRIVIAL_CHECK);
+
+    assert!(Checked::Unchecked(1).check().is_ok());
+    assert!(Checked::Checked { value: Even(2) }.check().is_ok());
+    assert!(Checked::Checked { value: Even(3) }.check().is_err());
+
+    let bytes = [1u8, 3];
+    assert!(Checked::deserialize_compressed(&bytes[..]).is_err());
+    assert_eq!(
+        Checked::deserialize_compressed_unchecked(&bytes[..]).unwrap(),
+        Checked::Checked { value: Even(3) }
+    );
+}
@@ -0,0 +1,7 @@
+use ark_algebra_bench_templates::{criterion_main, glv_msm_bench};
+use ark_test_curves::{bls12_381::G1Projective as G1, secp256k1::G1Projective as Secp256k1};
+
+glv_msm_bench!("Bls12_381", G1);
+glv_msm_bench!("Secp256k1", Secp256k1);
+
+criterion_main!(g1_glv_msm::benches, secp256k1_glv_msm::benches);
@@ -58,6 +58,11 @@ impl short_weierstrass::SWCurveConfig for Config {
         let h_eff: &[u64] = &[0xd201000000010001];
         Self::mul_affine(p, h_eff).into()
     }
+
+    #[inline]
+    fn msm(bases: &[G1Affine], scalars: &[Self::ScalarField]) -> Result<G1Projective, usize> {
+        GLVConfig::glv_msm(bases, scalars)
+    }
 }
 
 // Config from the [IETF draft v16, section E.2](https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-16.html#name-11-isogeny-map-for-bls12-381).
@@ -95,6 +100,10 @@ impl GLVConfig for Config {
         (true, BigInt!("228988810152649578064853576960394133503")),
     ];
 
+    /// `msm_glv` was measured to be faster than the generic MSM up to this
+    /// size.
+    const GLV_MSM_MAX_SIZE: usize = 1 << 12;
+
     fn endomorphism(p: &Projective<Self>) -> Projective<Self> {
         let mut res = *p;
         res.x *= Self::ENDO_COEFFS[0];
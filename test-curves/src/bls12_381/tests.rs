@@ -25,3 +25,7 @@ test_pairing!(pairing; crate::bls12_381::Bls12_381);
 test_h2c!(g1_h2c; "./src/testdata"; "BLS12381G1"; crate::bls12_381::g1::Config; crate::bls12_381::Fq; crate::bls12_381::Fq; 1);
 #[cfg(feature = "bls12_381_curve")]
 test_h2c!(g2_hc2; "./src/testdata"; "BLS12381G2"; crate::bls12_381::g2::Config; crate::bls12_381::Fq2; crate::bls12_381::Fq; 2);
+#[cfg(feature = "bls12_381_curve")]
+test_h2c!(g1_h2c_nu; "./src/testdata"; "BLS12381G1"; crate::bls12_381::g1::Config; crate::bls12_381::Fq; crate::bls12_381::Fq; 1; nu);
+#[cfg(feature = "bls12_381_curve")]
+test_h2c!(g2_h2c_nu; "./src/testdata"; "BLS12381G2"; crate::bls12_381::g2::Config; crate::bls12_381::Fq2; crate::bls12_381::Fq; 2; nu);
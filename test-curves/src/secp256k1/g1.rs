@@ -1,12 +1,15 @@
 use crate::secp256k1::{Fq, Fr};
 use ark_ec::{
     models::CurveConfig,
-    short_weierstrass::{Affine, Projective, SWCurveConfig},
+    scalar_mul::glv::GLVConfig,
+    short_weierstrass::{complete, Affine, CompleteSWCurveConfig, Projective, SWCurveConfig},
 };
-use ark_ff::{AdditiveGroup, Field, MontFp, Zero};
+use ark_ff::{AdditiveGroup, BigInt, Field, MontFp, PrimeField, Zero};
 
 pub type G1Affine = Affine<Config>;
 pub type G1Projective = Projective<Config>;
+pub type G1CompleteAffine = complete::Affine<Config>;
+pub type G1CompleteProjective = complete::Projective<Config>;
 
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct Config;
@@ -39,6 +42,53 @@ impl SWCurveConfig for Config {
 
     /// We use `()` because `(0, 0)` cannot be on the curve.
     type ZeroFlag = ();
+
+    #[inline]
+    fn mul_projective(p: &G1Projective, scalar: &[u64]) -> G1Projective {
+        let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+        GLVConfig::glv_mul_projective(*p, s)
+    }
+
+    #[inline]
+    fn mul_affine(base: &G1Affine, scalar: &[u64]) -> G1Projective {
+        let s = Self::ScalarField::from_sign_and_limbs(true, scalar);
+        GLVConfig::glv_mul_projective((*base).into(), s)
+    }
+
+    #[inline]
+    fn msm(bases: &[G1Affine], scalars: &[Self::ScalarField]) -> Result<G1Projective, usize> {
+        GLVConfig::glv_msm(bases, scalars)
+    }
+}
+
+impl CompleteSWCurveConfig for Config {}
+
+impl GLVConfig for Config {
+    const ENDO_COEFFS: &[Self::BaseField] = &[MontFp!(
+        "55594575648329892869085402983802832744385952214688224221778511981742606582254"
+    )];
+
+    const LAMBDA: Self::ScalarField =
+        MontFp!("37718080363155996902926221483475020450927657555482586988616620542887997980018");
+
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4] = [
+        (true, BigInt!("64502973549206556628585045361533709077")),
+        (false, BigInt!("303414439467246543595250775667605759171")),
+        (true, BigInt!("367917413016453100223835821029139468248")),
+        (true, BigInt!("64502973549206556628585045361533709077")),
+    ];
+
+    fn endomorphism(p: &G1Projective) -> G1Projective {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
+
+    fn endomorphism_affine(p: &G1Affine) -> G1Affine {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFFS[0];
+        res
+    }
 }
 
 /// G_GENERATOR_X = 55066263022277343669578718895168534326250603453777594175500187360389116729240
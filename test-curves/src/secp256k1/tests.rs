@@ -1,6 +1,8 @@
-use crate::secp256k1::{Fq, Fr, G1Projective};
+use crate::secp256k1::{Fq, Fr, G1CompleteProjective, G1Projective};
 use ark_algebra_test_templates::{test_field, test_group};
 
 test_field!(fq; Fq; mont_prime_field);
 test_field!(fr; Fr; mont_prime_field);
 test_group!(g1; G1Projective);
+test_group!(g1_complete; G1CompleteProjective; complete);
+test_group!(g1_glv; G1Projective; glv);
@@ -0,0 +1,79 @@
+#[macro_export]
+macro_rules! test_cycle {
+    ($mod_name: ident; $Cycle: ty) => {
+        mod $mod_name {
+            use ark_ec::{CurveCycle, CurveGroup, PrimeGroup};
+            use ark_ff::{AdditiveGroup, BitIteratorBE, Field};
+            use ark_serialize::Valid;
+            use ark_std::Zero;
+
+            type E1 = <$Cycle as CurveCycle>::E1;
+            type E2 = <$Cycle as CurveCycle>::E2;
+
+            /// Computes `k * g` by double-and-add, without reducing `k`
+            /// modulo the order of `g` as `mul_bigint` may do.
+            fn mul_unreduced<G: PrimeGroup>(g: G, k: &[u64]) -> G {
+                let mut res = G::zero();
+                for bit in BitIteratorBE::without_leading_zeros(k) {
+                    res.double_in_place();
+                    if bit {
+                        res += g;
+                    }
+                }
+                res
+            }
+
+            #[test]
+            fn test_generators() {
+                let g1 = E1::generator();
+                let g2 = E2::generator();
+                assert!(!g1.is_zero());
+                assert!(!g2.is_zero());
+                // Checks that the generators are on the curves and in the
+                // prime-order subgroups.
+                assert!(g1.into_affine().check().is_ok());
+                assert!(g2.into_affine().check().is_ok());
+            }
+
+            #[test]
+            fn test_generator_orders() {
+                // The order of each generator is the characteristic of the
+                // other curve's base field.
+                let g1 = E1::generator();
+                let g2 = E2::generator();
+                let order1 = <E2 as CurveGroup>::BaseField::characteristic();
+                let order2 = <E1 as CurveGroup>::BaseField::characteristic();
+                assert!(mul_unreduced(g1, order1).is_zero());
+                assert!(mul_unreduced(g2, order2).is_zero());
+            }
+        }
+    };
+    ($mod_name: ident; $Cycle: ty; pairing_friendly) => {
+        mod $mod_name {
+            $crate::test_cycle!(cycle; $Cycle);
+
+            use ark_ec::{
+                pairing::{Pairing, PairingOutput},
+                PairingFriendlyCycle, PrimeGroup,
+            };
+            use ark_std::Zero;
+
+            type Engine1 = <$Cycle as PairingFriendlyCycle>::Engine1;
+            type Engine2 = <$Cycle as PairingFriendlyCycle>::Engine2;
+
+            #[test]
+            fn test_non_degeneracy() {
+                let e1 = Engine1::pairing(
+                    <Engine1 as Pairing>::G1::generator(),
+                    <Engine1 as Pairing>::G2::generator(),
+                );
+                let e2 = Engine2::pairing(
+                    <Engine2 as Pairing>::G1::generator(),
+                    <Engine2 as Pairing>::G2::generator(),
+                );
+                assert_ne!(e1, PairingOutput::zero());
+                assert_ne!(e2, PairingOutput::zero());
+            }
+        }
+    };
+}
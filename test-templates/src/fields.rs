@@ -271,6 +271,65 @@ macro_rules! __test_field {
             }
         }
 
+        #[test]
+        fn test_inverse_secret() {
+            use ark_std::UniformRand;
+            let mut rng = test_rng();
+            let zero = <$field>::zero();
+            let one = <$field>::one();
+            assert!(zero.inverse_secret().is_none(), "Zero has an inverse");
+            assert_eq!(one.inverse_secret().unwrap(), one, "One inverse failed");
+            assert_eq!(<$field>::NEG_ONE.inverse_secret(), <$field>::NEG_ONE.inverse());
+
+            for _ in 0..ITERATIONS {
+                let a = <$field>::rand(&mut rng);
+                assert_eq!(a.inverse_secret(), a.inverse(), "Constant-time inverse failed");
+            }
+            // Exercise elements with few bits set, too.
+            for i in 1..=ITERATIONS as u64 {
+                let a = <$field>::from(i);
+                assert_eq!(a.inverse_secret(), a.inverse(), "Constant-time inverse failed");
+                assert_eq!((-a).inverse_secret(), (-a).inverse(), "Constant-time inverse failed");
+            }
+        }
+
+        $crate::__if_subtle! {
+            #[test]
+            fn test_constant_time_ops() {
+                use ark_ff::CtField;
+                use ark_std::UniformRand;
+                use $crate::subtle::{
+                    Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq,
+                    ConstantTimeGreater, ConstantTimeLess,
+                };
+                let mut rng = test_rng();
+                let zero = <$field>::zero();
+                assert!(bool::from(zero.ct_inverse().is_none()), "Zero has an inverse");
+
+                for _ in 0..ITERATIONS {
+                    let a = <$field>::rand(&mut rng);
+                    let b = <$field>::rand(&mut rng);
+
+                    assert!(bool::from(a.ct_eq(&a)));
+                    assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+                    assert_eq!(bool::from(a.ct_gt(&b)), a > b);
+                    assert_eq!(bool::from(a.ct_lt(&b)), a < b);
+                    assert!(!bool::from(a.ct_gt(&a)));
+
+                    assert_eq!(<$field>::conditional_select(&a, &b, Choice::from(0)), a);
+                    assert_eq!(<$field>::conditional_select(&a, &b, Choice::from(1)), b);
+
+                    let mut c = a;
+                    c.conditional_negate(Choice::from(0));
+                    assert_eq!(c, a);
+                    c.conditional_negate(Choice::from(1));
+                    assert_eq!(c, -a);
+
+                    assert_eq!(Option::from(a.ct_inverse()), a.inverse());
+                }
+            }
+        }
+
         #[test]
         fn test_pow() {
             use ark_std::UniformRand;
@@ -357,6 +416,32 @@ macro_rules! __test_field {
             }
         }
 
+        $crate::__if_subtle! {
+            #[test]
+            fn test_ct_sqrt() {
+                use ark_ff::CtField;
+                if <$field>::SQRT_PRECOMP.is_some() {
+                    use ark_std::UniformRand;
+                    let rng = &mut test_rng();
+
+                    assert!(<$field>::zero().ct_sqrt().unwrap().is_zero());
+
+                    for _ in 0..ITERATIONS {
+                        let a = <$field>::rand(rng);
+                        let sqrt = a.square().ct_sqrt().unwrap();
+                        assert!(a == sqrt || -a == sqrt);
+
+                        let b = <$field>::rand(rng);
+                        let sqrt = b.ct_sqrt();
+                        assert_eq!(bool::from(sqrt.is_some()), b.sqrt().is_some());
+                        if let Some(sqrt) = Option::<$field>::from(sqrt) {
+                            assert_eq!(sqrt.square(), b);
+                        }
+                    }
+                }
+            }
+        }
+
 
         #[test]
         fn test_mul_by_base_field_elem() {
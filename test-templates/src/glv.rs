@@ -1,34 +1,33 @@
 use ark_ec::{
-    scalar_mul::{glv::GLVConfig, sw_double_and_add_affine, sw_double_and_add_projective},
+    scalar_mul::{
+        glv::{GLVConfig, TEGLVConfig},
+        sw_double_and_add_affine, sw_double_and_add_projective,
+        variable_base::{msm_glv, VariableBaseMSM},
+    },
     short_weierstrass::{Affine, Projective},
-    AffineRepr, CurveGroup, PrimeGroup,
+    twisted_edwards as te, AffineRepr, CurveGroup, PrimeGroup,
 };
 use ark_ff::{BigInteger, PrimeField};
-use ark_std::{ops::Mul, UniformRand};
+use ark_std::{fmt::Debug, ops::Mul, vec::*, UniformRand, Zero};
 
-pub fn glv_scalar_decomposition<P: GLVConfig>() {
+/// Checks that `decompose` splits random scalars `k` into `±k1 ± k2 * lambda`,
+/// where `k1` and `k2` have at most half the bit size of the scalar field.
+fn check_scalar_decomposition<F: PrimeField>(
+    lambda: F,
+    decompose: impl Fn(F) -> ((bool, F), (bool, F)),
+) {
     let mut rng = ark_std::test_rng();
     for _i in 0..100 {
-        let k = P::ScalarField::rand(&mut rng);
+        let k = F::rand(&mut rng);
 
-        let ((is_k1_positive, k1), (is_k2_positive, k2)) =
-            <P as GLVConfig>::scalar_decomposition(k);
+        let ((is_k1_positive, k1), (is_k2_positive, k2)) = decompose(k);
 
-        if is_k1_positive && is_k2_positive {
-            assert_eq!(k1 + k2 * P::LAMBDA, k);
-        }
-        if is_k1_positive && !is_k2_positive {
-            assert_eq!(k1 - k2 * P::LAMBDA, k);
-        }
-        if !is_k1_positive && is_k2_positive {
-            assert_eq!(-k1 + k2 * P::LAMBDA, k);
-        }
-        if !is_k1_positive && !is_k2_positive {
-            assert_eq!(-k1 - k2 * P::LAMBDA, k);
-        }
+        let signed_k1 = if is_k1_positive { k1 } else { -k1 };
+        let signed_k2 = if is_k2_positive { k2 } else { -k2 };
+        assert_eq!(signed_k1 + signed_k2 * lambda, k);
 
         // check if k1 and k2 are indeed small.
-        let expected_max_bits = P::ScalarField::MODULUS_BIT_SIZE.div_ceil(2);
+        let expected_max_bits = F::MODULUS_BIT_SIZE.div_ceil(2);
         assert!(
             k1.into_bigint().num_bits() <= expected_max_bits,
             "k1 has {} bits",
@@ -42,36 +41,143 @@ pub fn glv_scalar_decomposition<P: GLVConfig>() {
     }
 }
 
-pub fn glv_endomorphism_eigenvalue<P: GLVConfig>() {
-    let g = Projective::generator();
-    let endo_g = <P as GLVConfig>::endomorphism(&g);
-    assert_eq!(endo_g, g.mul(P::LAMBDA));
+/// Checks that `endomorphism` and `endomorphism_affine` act as
+/// multiplication by `lambda`, and map the identity to itself.
+fn check_endomorphism<G: CurveGroup>(
+    lambda: G::ScalarField,
+    endomorphism: impl Fn(&G) -> G,
+    endomorphism_affine: impl Fn(&G::Affine) -> G::Affine,
+) {
+    let g = G::generator();
+    assert_eq!(endomorphism(&g), g.mul(lambda));
+
+    let g = g.into_affine();
+    assert_eq!(endomorphism_affine(&g), g.mul(lambda).into_affine());
+
+    assert!(endomorphism(&G::zero()).is_zero());
 }
 
-pub fn glv_projective<P: GLVConfig>() {
-    // check that glv_mul indeed computes the scalar multiplication
+/// Checks that `glv_mul` agrees with the reference scalar multiplication
+/// `mul` on random scalars.
+fn check_glv_mul<G: Copy + PartialEq + Debug, F: PrimeField>(
+    g: G,
+    glv_mul: impl Fn(G, F) -> G,
+    mul: impl Fn(G, F) -> G,
+) {
     let mut rng = ark_std::test_rng();
-
-    let g = Projective::generator();
     for _i in 0..100 {
-        let k = P::ScalarField::rand(&mut rng);
-
-        let k_g = <P as GLVConfig>::glv_mul_projective(g, k);
-        let k_g_2 = sw_double_and_add_projective(&g, k.into_bigint());
-        assert_eq!(k_g, k_g_2);
+        let k = F::rand(&mut rng);
+        assert_eq!(glv_mul(g, k), mul(g, k));
     }
 }
 
+pub fn glv_scalar_decomposition<P: GLVConfig>() {
+    check_scalar_decomposition(P::LAMBDA, <P as GLVConfig>::scalar_decomposition);
+}
+
+pub fn glv_endomorphism_eigenvalue<P: GLVConfig>() {
+    check_endomorphism::<Projective<P>>(
+        P::LAMBDA,
+        <P as GLVConfig>::endomorphism,
+        <P as GLVConfig>::endomorphism_affine,
+    );
+}
+
+pub fn glv_projective<P: GLVConfig>() {
+    check_glv_mul(
+        Projective::<P>::generator(),
+        <P as GLVConfig>::glv_mul_projective,
+        |g, k| sw_double_and_add_projective(&g, k.into_bigint()),
+    );
+}
+
 pub fn glv_affine<P: GLVConfig>() {
-    // check that glv_mul indeed computes the scalar multiplication
+    check_glv_mul(
+        Affine::<P>::generator(),
+        <P as GLVConfig>::glv_mul_affine,
+        |g, k| sw_double_and_add_affine(&g, k.into_bigint()).into_affine(),
+    );
+}
+
+pub fn glv_msm<P: GLVConfig>() {
+    // check that the GLV MSM agrees with the default MSM
     let mut rng = ark_std::test_rng();
 
-    let g = Affine::generator();
-    for _i in 0..100 {
-        let k = P::ScalarField::rand(&mut rng);
+    for size in [0, 1, 2, 10, 100] {
+        let mut bases: Vec<_> = (0..size).map(|_| Projective::<P>::rand(&mut rng)).collect();
+        let mut scalars: Vec<_> = (0..size).map(|_| P::ScalarField::rand(&mut rng)).collect();
+        if size >= 10 {
+            // Include zero bases and scalars, and small positive and negative scalars.
+            bases[0] = Projective::zero();
+            bases[1] = bases[2];
+            scalars[3] = P::ScalarField::zero();
+            scalars[4] = P::ScalarField::from(7u64);
+            scalars[5] = -P::ScalarField::from(1u64);
+            scalars[6] = -P::ScalarField::from(u64::MAX);
+            scalars[7] = P::LAMBDA;
+        }
+        let bases = Projective::normalize_batch(&bases);
 
-        let k_g = <P as GLVConfig>::glv_mul_affine(g, k);
-        let k_g_2 = sw_double_and_add_affine(&g, k.into_bigint()).into_affine();
-        assert_eq!(k_g, k_g_2);
+        let expected = Projective::<P>::msm_unchecked(&bases, &scalars);
+        assert_eq!(msm_glv::<P>(&bases, &scalars), expected);
+        assert_eq!(P::glv_msm(&bases, &scalars), Ok(expected));
+        assert_eq!(Projective::<P>::msm(&bases, &scalars), Ok(expected));
     }
+
+    // `SWCurveConfig::msm` must agree with scalar multiplication also for
+    // points outside of the prime-order subgroup, on which the endomorphism
+    // does not act as multiplication by `LAMBDA`.
+    let bases: Vec<_> = (0..10)
+        .map(|_| loop {
+            let x = P::BaseField::rand(&mut rng);
+            if let Some(p) = Affine::<P>::get_point_from_x_unchecked(x, false) {
+                break p;
+            }
+        })
+        .collect();
+    let scalars: Vec<_> = (0..bases.len())
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect();
+    let expected: Projective<P> = bases
+        .iter()
+        .zip(&scalars)
+        .map(|(base, scalar)| base.into_group() * scalar)
+        .sum();
+    assert_eq!(Projective::<P>::msm(&bases, &scalars), Ok(expected));
+
+    let bases = [Affine::<P>::generator(); 3];
+    let scalars = [P::ScalarField::from(2u64); 2];
+    assert_eq!(P::glv_msm(&bases, &scalars), Err(2));
+    assert_eq!(
+        msm_glv::<P>(&bases, &scalars),
+        Projective::<P>::msm_unchecked(&bases, &scalars)
+    );
+}
+
+pub fn te_glv_scalar_decomposition<P: TEGLVConfig>() {
+    check_scalar_decomposition(P::LAMBDA, <P as TEGLVConfig>::scalar_decomposition);
+}
+
+pub fn te_glv_endomorphism_eigenvalue<P: TEGLVConfig>() {
+    check_endomorphism::<te::Projective<P>>(
+        P::LAMBDA,
+        <P as TEGLVConfig>::endomorphism,
+        <P as TEGLVConfig>::endomorphism_affine,
+    );
+}
+
+pub fn te_glv_projective<P: TEGLVConfig>() {
+    check_glv_mul(
+        te::Projective::<P>::generator(),
+        <P as TEGLVConfig>::glv_mul_projective,
+        |g, k| g.mul_bigint(k.into_bigint()),
+    );
+}
+
+pub fn te_glv_affine<P: TEGLVConfig>() {
+    check_glv_mul(
+        te::Affine::<P>::generator(),
+        <P as TEGLVConfig>::glv_mul_affine,
+        |g, k| g.mul_bigint(k.into_bigint()).into_affine(),
+    );
 }
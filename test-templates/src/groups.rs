@@ -202,6 +202,11 @@ macro_rules! __test_group {
             $crate::msm::test_var_base_msm_specialized::<$group>();
         }
 
+        #[test]
+        fn test_var_base_msm_batch() {
+            $crate::msm::test_var_base_msm_batch::<$group>();
+        }
+
         #[test]
         fn test_chunked_pippenger() {
             $crate::msm::test_chunked_pippenger::<$group>();
@@ -219,6 +224,16 @@ macro_rules! __test_group {
         type Config = <$group as CurveGroup>::Config;
         type BaseField = <$group as CurveGroup>::BaseField;
 
+        #[test]
+        fn test_fixed_base_msm() {
+            $crate::msm::test_fixed_base_msm::<$group>();
+        }
+
+        #[test]
+        fn test_msm_from_reader() {
+            $crate::msm::test_msm_from_reader::<$group>();
+        }
+
         #[test]
         fn test_affine_conversion() {
             let mut rng = &mut ark_std::test_rng();
@@ -282,6 +297,81 @@ macro_rules! __test_group {
                 assert_eq!(a + b, a_group + b, "a + b failed on input {a}, {b}");
             }
         }
+
+        $crate::__if_subtle! {
+            #[test]
+            fn test_constant_time_ops() {
+                use $crate::subtle::{
+                    Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq,
+                };
+                let rng = &mut ark_std::test_rng();
+                let zero = <$group>::zero();
+                assert!(bool::from(zero.ct_eq(&zero)));
+                assert!(bool::from(Affine::zero().ct_eq(&Affine::zero())));
+
+                for _ in 0..ITERATIONS {
+                    let a = <$group>::rand(rng);
+                    let b = <$group>::rand(rng);
+
+                    assert!(bool::from(a.ct_eq(&a.into_affine().into_group())));
+                    assert!(bool::from((a + a).ct_eq(&a.double())));
+                    assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+                    assert_eq!(bool::from(a.ct_eq(&zero)), a.is_zero());
+                    assert_eq!(bool::from(zero.ct_eq(&a)), a.is_zero());
+                    assert_eq!(<$group>::conditional_select(&a, &b, Choice::from(0)), a);
+                    assert_eq!(<$group>::conditional_select(&a, &b, Choice::from(1)), b);
+                    let mut c = a;
+                    c.conditional_negate(Choice::from(1));
+                    assert_eq!(c, -a);
+
+                    let (a, b) = (a.into_affine(), b.into_affine());
+                    assert!(bool::from(a.ct_eq(&a)));
+                    assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+                    assert_eq!(bool::from(a.ct_eq(&Affine::zero())), a.is_zero());
+                    assert_eq!(Affine::conditional_select(&a, &b, Choice::from(0)), a);
+                    assert_eq!(Affine::conditional_select(&a, &b, Choice::from(1)), b);
+                    assert_eq!(Affine::conditional_select(&a, &Affine::zero(), Choice::from(1)), Affine::zero());
+                    let mut c = a;
+                    c.conditional_negate(Choice::from(1));
+                    assert_eq!(c, -a);
+                }
+            }
+        }
+
+        $crate::__if_subtle! {
+            #[test]
+            fn test_ct_from_random_bytes() {
+                use ark_ec::CtAffine;
+                let rng = &mut ark_std::test_rng();
+                let num_bytes = BaseField::zero().uncompressed_size();
+                for _ in 0..ITERATIONS {
+                    let bytes = (0..num_bytes).map(|_| rng.gen()).collect::<Vec<u8>>();
+                    let expected = Affine::from_random_bytes(&bytes);
+                    let actual: Option<Affine> = Affine::ct_from_random_bytes(&bytes).into();
+                    assert_eq!(actual, expected);
+                }
+            }
+        }
+
+        $crate::__if_subtle! {
+            #[test]
+            fn test_mul_ct() {
+                use ark_ec::CtCurveGroup;
+                let rng = &mut ark_std::test_rng();
+                let zero = <$group>::zero();
+                let a = <$group>::rand(rng);
+                assert_eq!(a.mul_ct(&ScalarField::zero()), zero);
+                assert_eq!(a.mul_ct(&ScalarField::one()), a);
+                assert_eq!(a.mul_ct(&-ScalarField::one()), -a);
+                assert_eq!(zero.mul_ct(&ScalarField::rand(rng)), zero);
+
+                for _ in 0..ITERATIONS {
+                    let a = <$group>::rand(rng);
+                    let s = ScalarField::rand(rng);
+                    assert_eq!(a.mul_ct(&s), a.mul_bigint(s.into_bigint()));
+                }
+            }
+        }
     };
     ($group:ty; sw) => {
         $crate::__test_group!($group; curve);
@@ -358,6 +448,54 @@ macro_rules! __test_group {
                 }
             }
         }
+
+        #[test]
+        fn test_batch_affine_msm() {
+            $crate::msm::test_batch_affine_msm::<Config>();
+        }
+    };
+    ($group:ty; complete) => {
+        $crate::__test_group!($group; curve);
+
+        #[test]
+        fn test_complete_properties() {
+            use ark_ec::short_weierstrass::{self, CompleteSWCurveConfig};
+            type Jacobian = short_weierstrass::Projective<Config>;
+            let rng = &mut ark_std::test_rng();
+            let zero = <$group>::zero();
+
+            for _ in 0..ITERATIONS {
+                let f = BaseField::rand(rng);
+                assert_eq!(<Config as CompleteSWCurveConfig>::mul_by_b3(f), f * BaseField::from(3u8) * <Config as SWCurveConfig>::COEFF_B);
+            }
+
+            for _ in 0..ITERATIONS {
+                let a = Jacobian::rand(rng);
+                let b = Jacobian::rand(rng);
+                let (a_complete, b_complete) = (<$group>::from(a), <$group>::from(b));
+
+                // Conversions to and from Jacobian coordinates.
+                assert_eq!(Jacobian::from(a_complete), a);
+                assert_eq!(a_complete.into_affine().0, a.into_affine());
+                assert_eq!(<$group>::from(a.into_affine()), a_complete);
+                assert_eq!(<$group>::from(a + b), a_complete + b_complete);
+                assert_eq!(<$group>::from(a.double()), a_complete.double());
+
+                // The formulae have no exceptional cases.
+                assert_eq!(a_complete + a_complete, a_complete.double());
+                assert_eq!(a_complete + a_complete.into_affine(), a_complete.double());
+                assert_eq!(a_complete - a_complete, zero);
+                assert_eq!(a_complete - a_complete.into_affine(), zero);
+                assert_eq!(zero + a_complete, a_complete);
+                assert_eq!(zero + a_complete.into_affine(), a_complete);
+                assert_eq!(a_complete + Affine::zero(), a_complete);
+            }
+            assert_eq!(zero + zero, zero);
+            assert_eq!(zero.double(), zero);
+            assert_eq!(zero + Affine::zero(), zero);
+            assert_eq!(Jacobian::from(zero), Jacobian::zero());
+            assert_eq!(<$group>::from(Jacobian::zero()), zero);
+        }
     };
     ($group:ty; te) => {
         $crate::__test_group!($group; curve);
@@ -431,6 +569,30 @@ macro_rules! __test_group {
             $crate::glv::glv_projective::<Config>();
             $crate::glv::glv_affine::<Config>();
         }
+
+        #[test]
+        fn test_glv_msm() {
+            $crate::glv::glv_msm::<Config>();
+        }
+    };
+    ($group:ty; te_glv) => {
+        type Config = <$group as CurveGroup>::Config;
+
+        #[test]
+        fn test_scalar_decomposition() {
+            $crate::glv::te_glv_scalar_decomposition::<Config>();
+        }
+
+        #[test]
+        fn test_endomorphism_eigenvalue() {
+            $crate::glv::te_glv_endomorphism_eigenvalue::<Config>();
+        }
+
+        #[test]
+        fn test_glv_mul() {
+            $crate::glv::te_glv_projective::<Config>();
+            $crate::glv::te_glv_affine::<Config>();
+        }
     }
 }
 
@@ -8,15 +8,75 @@ pub use sha2::Sha256;
 
 #[macro_export]
 macro_rules! test_h2c {
+    // Tests a named suite `$hasher` over the field `$field`, whose vectors
+    // are in `$test_path`, in a file named after `$suite_id`. `$point`
+    // converts the coordinates of the vectors to the expected output of
+    // `$hasher`.
+    ($mod_name: ident; $test_path: literal; suite = $suite_id: expr; hasher = $hasher: ty; field_hasher = $field_hasher: ty; field = $field: ty; count = $count: literal; point = |$x: ident, $y: ident| $point: expr) => {
+        mod $mod_name {
+            extern crate std;
+            use ark_ec::hashing::HashToCurve;
+            use ark_ff::{field_hashers::HashToField, Field, PrimeField};
+            use ark_std::{format, vec::*};
+            use std::{fs::File, io::BufReader};
+            use $crate::{decode, json::SuiteVector};
+
+            /// Reads an element of `$field` given by the comma-separated
+            /// coordinates over its base prime field.
+            fn read_field(input: &str) -> $field {
+                let elems = input.split(',').map(|f| {
+                    <<$field as Field>::BasePrimeField>::from_be_bytes_mod_order(
+                        &decode(f.trim_start_matches("0x")).unwrap(),
+                    )
+                });
+                <$field>::from_base_prime_field_elems(elems).unwrap()
+            }
+
+            #[test]
+            fn test_h2c() {
+                let filename = format!("{}/{}.json", $test_path, $suite_id.replace(':', "-"));
+                let file = File::open(filename).unwrap();
+                let data: SuiteVector = $crate::from_reader(BufReader::new(file)).unwrap();
+                assert_eq!(data.ciphersuite, $suite_id);
+                assert_eq!(data.random_oracle, $count == 2);
+
+                let dst = data.dst.as_bytes();
+                let field_hasher = <$field_hasher as HashToField<$field>>::new(dst);
+                let hasher = <$hasher>::new(dst).unwrap();
+                for v in data.vectors.iter() {
+                    let got: [$field; $count] = field_hasher.hash_to_field(v.msg.as_bytes());
+                    let want: Vec<$field> = v.u.iter().map(|u| read_field(u)).collect();
+                    assert_eq!(got[..], *want);
+
+                    let ($x, $y) = (read_field(&v.p.x), read_field(&v.p.y));
+                    let got = hasher.hash(v.msg.as_bytes()).unwrap();
+                    assert!(got.is_on_curve());
+                    assert_eq!(got, $point);
+                }
+            }
+        }
+    };
     ($mod_name: ident; $test_path: literal; $test_name: literal; $group: ty; $field: ty; $base_prime_field: ty; $m: literal) => {
+        $crate::test_h2c!($mod_name; $test_path; $test_name; $group; $field; $base_prime_field; $m; ro);
+    };
+    ($mod_name: ident; $test_path: literal; $test_name: literal; $group: ty; $field: ty; $base_prime_field: ty; $m: literal; ro) => {
+        $crate::__test_h2c!($mod_name; $test_path; $test_name; $group; $field; $base_prime_field; $m; "RO"; MapToCurveBasedHasher; 2);
+    };
+    ($mod_name: ident; $test_path: literal; $test_name: literal; $group: ty; $field: ty; $base_prime_field: ty; $m: literal; nu) => {
+        $crate::__test_h2c!($mod_name; $test_path; $test_name; $group; $field; $base_prime_field; $m; "NU"; MapToCurveBasedEncoder; 1);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __test_h2c {
+    ($mod_name: ident; $test_path: literal; $test_name: literal; $group: ty; $field: ty; $base_prime_field: ty; $m: literal; $mode: literal; $hasher: ident; $count: literal) => {
         mod $mod_name {
             use ark_ff::PrimeField;
 
             extern crate std;
             use ark_ec::{
-                hashing::{
-                    curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve,
-                },
+                hashing::{curve_maps::wb::WBMap, map_to_curve_hasher::$hasher, HashToCurve},
                 short_weierstrass::{Affine, Projective},
             };
             use ark_ff::{
@@ -34,15 +94,19 @@ macro_rules! test_h2c {
             use $crate::json::SuiteVector;
             #[test]
             fn test_h2c() {
-                let filename = format!("{}/{}_XMD-SHA-256_SSWU_RO_.json", $test_path, $test_name);
+                let filename = format!(
+                    "{}/{}_XMD-SHA-256_SSWU_{}_.json",
+                    $test_path, $test_name, $mode
+                );
 
                 let file = File::open(filename).unwrap();
                 let data: SuiteVector = $crate::from_reader(BufReader::new(file)).unwrap();
 
                 assert_eq!(data.hash, "sha256");
+                assert_eq!(data.random_oracle, $mode == "RO");
                 let dst = data.dst.as_bytes();
                 let hasher;
-                let g1_mapper = MapToCurveBasedHasher::<
+                let g1_mapper = $hasher::<
                     Projective<$group>,
                     DefaultFieldHasher<Sha256, 128>,
                     WBMap<$group>,
@@ -52,7 +116,7 @@ macro_rules! test_h2c {
 
                 for v in data.vectors.iter() {
                     // first, hash-to-field tests
-                    let got: [$base_prime_field; { 2 * $m }] =
+                    let got: [$base_prime_field; { $count * $m }] =
                         hasher.hash_to_field(&v.msg.as_bytes());
                     let want: Vec<$base_prime_field> =
                         v.u.iter().map(read_fq_vec).flatten().collect();
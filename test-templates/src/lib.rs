@@ -2,6 +2,8 @@
 pub mod groups;
 #[macro_use]
 pub mod fields;
+#[macro_use]
+pub mod cycle;
 pub mod glv;
 pub mod msm;
 #[macro_use]
@@ -13,3 +15,25 @@ pub use h2c::*;
 pub use num_bigint;
 pub use num_integer;
 pub use num_traits;
+#[cfg(feature = "subtle")]
+pub use subtle;
+
+/// Expands to its input if the `subtle` feature is enabled, and to nothing
+/// otherwise.
+#[cfg(feature = "subtle")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_subtle {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+/// Expands to its input if the `subtle` feature is enabled, and to nothing
+/// otherwise.
+#[cfg(not(feature = "subtle"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __if_subtle {
+    ($($item:item)*) => {};
+}
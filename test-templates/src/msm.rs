@@ -1,8 +1,15 @@
 use ark_ec::{
-    scalar_mul::variable_base::{ChunkedPippenger, HashMapPippenger, VariableBaseMSM},
-    ScalarMul,
+    scalar_mul::{
+        fixed_base::FixedBaseMSM,
+        variable_base::{
+            msm_batch_affine, msm_from_reader, ChunkedPippenger, HashMapPippenger, VariableBaseMSM,
+        },
+    },
+    short_weierstrass::{Affine, Projective, SWCurveConfig},
+    AffineRepr, CurveGroup, ScalarMul,
 };
 use ark_ff::{PrimeField, UniformRand};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
 use ark_std::{rand::seq::SliceRandom, vec::*};
 
 fn naive_var_base_msm<G: ScalarMul>(bases: &[G::MulBase], scalars: &[G::ScalarField]) -> G {
@@ -109,6 +116,49 @@ pub fn test_var_base_msm_specialized<G: VariableBaseMSM>() {
     assert_eq!(naive, fast);
 }
 
+pub fn test_var_base_msm_batch<G: VariableBaseMSM>() {
+    const SAMPLES: usize = 1 << 8;
+
+    let mut rng = ark_std::test_rng();
+
+    let g = (0..SAMPLES).map(|_| G::rand(&mut rng)).collect::<Vec<_>>();
+    let g = G::batch_convert_to_mul_base(&g);
+
+    // Scalar vectors of various sizes and lengths, including ones shorter and
+    // longer than the bases.
+    let mut v = Vec::new();
+    for len in [SAMPLES, 0, 1, 31, SAMPLES / 2, SAMPLES + 3] {
+        v.push(
+            (0..len)
+                .map(|_| G::ScalarField::rand(&mut rng))
+                .collect::<Vec<_>>(),
+        );
+    }
+    v.push(vec![F::<G>::from(0u8); SAMPLES]);
+    v.push(vec![-F::<G>::from(1u8); SAMPLES]);
+    v.push(
+        (0..SAMPLES)
+            .map(|_| F::<G>::from(u16::rand(&mut rng)))
+            .collect(),
+    );
+    v.push(
+        (0..SAMPLES)
+            .map(|_| -F::<G>::from(u64::rand(&mut rng)))
+            .collect(),
+    );
+
+    let batch = G::msm_batch(&g, &v);
+    assert_eq!(batch.len(), v.len());
+    for (result, scalars) in batch.iter().zip(&v) {
+        assert_eq!(*result, G::msm_unchecked(&g, scalars));
+    }
+
+    let batch = G::msm_batch(&g[..SAMPLES / 4], &v[..1]);
+    assert_eq!(batch, vec![G::msm_unchecked(&g[..SAMPLES / 4], &v[0])]);
+    assert!(G::msm_batch::<Vec<_>>(&g, &[]).is_empty());
+    assert_eq!(G::msm_batch(&[], &v[..2]), vec![G::zero(); 2]);
+}
+
 pub fn test_chunked_pippenger<G: VariableBaseMSM>() {
     const SAMPLES: usize = 1 << 10;
 
@@ -155,3 +205,158 @@ pub fn test_hashmap_pippenger<G: VariableBaseMSM>() {
     let mine = p.finalize();
     assert_eq!(arkworks, mine);
 }
+
+pub fn test_batch_affine_msm<P: SWCurveConfig>() {
+    const SAMPLES: usize = 1 << 10;
+
+    let mut rng = ark_std::test_rng();
+
+    let check = |g: &[Affine<P>], v: &[P::ScalarField]| {
+        let v = v.iter().map(|s| s.into_bigint()).collect::<Vec<_>>();
+        let expected = Projective::<P>::msm_bigint(g, &v);
+        assert_eq!(msm_batch_affine(g, &v), expected);
+    };
+
+    // Random inputs of various sizes.
+    for size in [0, 1, 2, 31, 32, SAMPLES] {
+        let v = (0..size)
+            .map(|_| P::ScalarField::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let g = (0..size)
+            .map(|_| Projective::<P>::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let g = Projective::normalize_batch(&g);
+        check(&g, &v);
+    }
+
+    // Repeated bases, opposite bases and the identity, which make additions
+    // into a bucket collide, double, or cancel out.
+    let base = Affine::<P>::rand(&mut rng);
+    let mut g = vec![base; SAMPLES / 4];
+    g.extend(vec![-base; SAMPLES / 4]);
+    g.extend(vec![Affine::<P>::zero(); 4]);
+    g.extend((g.len()..SAMPLES).map(|_| Affine::<P>::rand(&mut rng)));
+    g.shuffle(&mut rng);
+    for v in [
+        vec![P::ScalarField::from(3u8); SAMPLES],
+        (0..SAMPLES)
+            .map(|i| P::ScalarField::from((i % 5) as u8))
+            .collect(),
+        (0..SAMPLES)
+            .map(|_| P::ScalarField::rand(&mut rng))
+            .collect(),
+    ] {
+        check(&g, &v);
+    }
+
+    // Mismatched lengths are truncated.
+    let v = (0..SAMPLES / 2)
+        .map(|_| P::ScalarField::rand(&mut rng))
+        .collect::<Vec<_>>();
+    check(&g[..SAMPLES / 2], &v);
+    let v = v.iter().map(|s| s.into_bigint()).collect::<Vec<_>>();
+    assert_eq!(
+        msm_batch_affine(&g, &v),
+        msm_batch_affine(&g[..SAMPLES / 2], &v)
+    );
+}
+
+pub fn test_fixed_base_msm<G: CurveGroup>() {
+    const SAMPLES: usize = 1 << 8;
+
+    let mut rng = ark_std::test_rng();
+
+    let v = (0..SAMPLES)
+        .map(|_| G::ScalarField::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES).map(|_| G::rand(&mut rng)).collect::<Vec<_>>();
+    let g = G::normalize_batch(&g);
+
+    let num_bits = G::ScalarField::MODULUS_BIT_SIZE as usize;
+    for multiples_per_base in [1, 2, 5, num_bits] {
+        let table = FixedBaseMSM::<G>::new(&g, multiples_per_base);
+        assert_eq!(table.num_bases(), SAMPLES);
+        assert!(table.multiples_per_base() <= multiples_per_base);
+        assert!(table.num_windows() * table.multiples_per_base() * table.window_size() >= num_bits);
+        assert_eq!(table.msm(&v), G::msm(&g, &v));
+
+        // Fewer scalars than bases.
+        for size in [0, 1, 33] {
+            assert_eq!(table.msm(&v[..size]), Err(size));
+            assert_eq!(
+                table.msm_unchecked(&v[..size]),
+                G::msm_unchecked(&g, &v[..size])
+            );
+        }
+    }
+    assert_eq!(FixedBaseMSM::<G>::new(&g, num_bits).num_windows(), 1);
+
+    for (window_size, multiples_per_base) in [(1, 1), (4, 3), (16, 2)] {
+        let table = FixedBaseMSM::<G>::with_window_size(&g, window_size, multiples_per_base);
+        assert_eq!(table.window_size(), window_size);
+        assert_eq!(table.msm(&v), G::msm(&g, &v));
+    }
+
+    // Tables round-trip through serialization, and inconsistent shapes are rejected.
+    let table = FixedBaseMSM::<G>::new(&g[..10], 3);
+    for compress in [Compress::Yes, Compress::No] {
+        for validate in [Validate::Yes, Validate::No] {
+            let mut bytes = Vec::new();
+            table.serialize_with_mode(&mut bytes, compress).unwrap();
+            assert_eq!(bytes.len(), table.serialized_size(compress));
+            let deserialized =
+                FixedBaseMSM::<G>::deserialize_with_mode(&bytes[..], compress, validate).unwrap();
+            assert_eq!(deserialized, table);
+            assert_eq!(deserialized.msm(&v[..10]), G::msm(&g[..10], &v[..10]));
+
+            let multiples = vec![G::Affine::generator(); table.multiples_per_base() + 1];
+            for malformed in [
+                (0usize, table.num_windows(), multiples[..1].to_vec()),
+                (table.window_size(), 0, multiples[..1].to_vec()),
+                (table.window_size(), num_bits + 1, multiples[..1].to_vec()),
+                (table.window_size(), table.num_windows(), multiples.clone()),
+            ] {
+                let mut bytes = Vec::new();
+                malformed.serialize_with_mode(&mut bytes, compress).unwrap();
+                assert!(
+                    FixedBaseMSM::<G>::deserialize_with_mode(&bytes[..], compress, validate)
+                        .is_err()
+                );
+            }
+        }
+    }
+}
+
+pub fn test_msm_from_reader<G: CurveGroup>() {
+    const SAMPLES: usize = 1 << 8;
+
+    let mut rng = ark_std::test_rng();
+
+    let v = (0..SAMPLES)
+        .map(|_| G::ScalarField::rand(&mut rng).into_bigint())
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES).map(|_| G::rand(&mut rng)).collect::<Vec<_>>();
+    let g = G::normalize_batch(&g);
+
+    for compress in [Compress::Yes, Compress::No] {
+        let mut srs = Vec::new();
+        for base in &g {
+            base.serialize_with_mode(&mut srs, compress).unwrap();
+        }
+        for validate in [Validate::Yes, Validate::No] {
+            for size in [0, 1, 31, SAMPLES] {
+                let expected = G::msm_bigint(&g[..size], &v[..size]);
+                for chunk_size in [0, 1, 100, SAMPLES, 2 * SAMPLES] {
+                    let result: G =
+                        msm_from_reader(&srs[..], &v[..size], compress, validate, chunk_size)
+                            .unwrap();
+                    assert_eq!(result, expected);
+                }
+            }
+
+            // A reader that runs out of bases is an error.
+            let truncated = &srs[..srs.len() - 1];
+            assert!(msm_from_reader::<G, _>(truncated, &v, compress, validate, 100).is_err());
+        }
+    }
+}